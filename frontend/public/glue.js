@@ -1,4 +1,22 @@
 const invoke = window.__TAURI_INTERNALS__?.invoke;
+const listen = window.__TAURI__?.event?.listen;
+const Channel = window.__TAURI__?.core?.Channel;
+
+/**
+ * 
+ * @returns {boolean}
+ */
+export function isNativeApp() {
+    return invoke !== undefined;
+}
+
+/**
+ * 
+ * @returns {string} label of the window running this page, "main" in the browser
+ */
+export function currentWindowLabel() {
+    return window.__TAURI_INTERNALS__?.metadata?.currentWindow?.label ?? "main";
+}
 
 /**
  * 
@@ -8,13 +26,353 @@ export async function invokeGetGameCode() {
     return await invoke?.("get_game_code");
 }
 
+/**
+ * 
+ * @returns {number}
+ * @throws {object} command error
+ */
+export async function invokeRegenerateGameCode() {
+    return await invoke?.("regenerate_game_code");
+}
+
 /**
  * 
  * @param {string} gameCode
+ * @param {number[]} simulatedItems
+ * @returns {ArrayBuffer | undefined} MessagePack encoded `Realtime`
+ * @throws {object} command error
+ */
+export async function invokeGetRealtimeGame(gameCode, simulatedItems) {
+    return await invoke?.("get_realtime_game", { gameCode, simulatedItems });
+}
+
+/**
+ * 
+ * @returns {string | undefined}
+ */
+export async function invokeGetBackendUrl() {
+    return await invoke?.("get_backend_url");
+}
+
+/**
+ * 
+ * @param {string} backendUrl
+ * @returns {string}
+ */
+export async function invokeSetBackendUrl(backendUrl) {
+    return await invoke?.("set_backend_url", { backendUrl });
+}
+
+/**
+ * 
+ * @param {number} gameCode
+ * @returns {void}
+ */
+export async function invokeStartPolling(gameCode) {
+    return await invoke?.("start_polling", { gameCode });
+}
+
+/**
+ * 
+ * @returns {void}
+ */
+export async function invokeStopPolling() {
+    return await invoke?.("stop_polling");
+}
+
+/**
+ * 
+ * @returns {object | undefined} connection state
+ */
+export async function invokeGetConnectionState() {
+    return await invoke?.("get_connection_state");
+}
+
+/**
+ * 
+ * @returns {object | null | undefined} adapted poll rate
+ */
+export async function invokeGetPollRate() {
+    return await invoke?.("get_poll_rate");
+}
+
+/**
+ * 
+ * @param {object} polling poll rate bounds
+ * @returns {object} saved bounds
+ */
+export async function invokeSetPollingSettings(polling) {
+    return await invoke?.("set_polling_settings", { polling });
+}
+
+/**
+ * 
+ * @returns {void}
+ */
+export async function invokePausePolling() {
+    return await invoke?.("pause_polling");
+}
+
+/**
+ * 
+ * @returns {void}
+ */
+export async function invokeResumePolling() {
+    return await invoke?.("resume_polling");
+}
+
+/**
+ * 
+ * @returns {boolean | undefined}
+ */
+export async function invokeGetRecording() {
+    return await invoke?.("get_recording");
+}
+
+/**
+ * 
+ * @param {boolean} enabled
+ * @returns {boolean}
+ */
+export async function invokeSetRecording(enabled) {
+    return await invoke?.("set_recording", { enabled });
+}
+
+/**
+ * 
+ * @returns {object | undefined} map of champion ids to item ids
+ */
+export async function invokeGetSimulatedItems() {
+    return await invoke?.("get_simulated_items");
+}
+
+/**
+ * 
+ * @param {string} championId
+ * @param {number[]} items empty to restore the default
+ * @returns {number[] | undefined} Items now compared for the champion
+ */
+export async function invokeSetSimulatedItems(championId, items) {
+    return await invoke?.("set_simulated_items", { championId, items });
+}
+
+/**
+ * 
+ * @returns {object[] | undefined} recordings
+ */
+export async function invokeListRecordings() {
+    return await invoke?.("list_recordings");
+}
+
+/**
+ * 
+ * @param {string} name
+ * @param {number} offset
+ * @param {number} limit
+ * @returns {ArrayBuffer} MessagePack encoded `ReplayPage`
+ */
+export async function invokeLoadRecording(name, offset, limit) {
+    return await invoke?.("load_recording", { name, offset, limit });
+}
+
+/**
+ * 
+ * @returns {void}
+ */
+export async function invokeToggleOverlay() {
+    return await invoke?.("toggle_overlay");
+}
+
+/**
+ * 
+ * @param {boolean} locked
+ * @returns {void}
+ */
+export async function invokeSetOverlayLocked(locked) {
+    return await invoke?.("set_overlay_locked", { locked });
+}
+
+/**
+ * 
+ * @param {boolean} enabled
+ * @returns {void}
+ */
+export async function invokeSetOverlayBaseTable(enabled) {
+    return await invoke?.("set_overlay_base_table", { enabled });
+}
+
+/**
+ * 
+ * @returns {object | undefined} overlay state
+ */
+export async function invokeGetOverlayState() {
+    return await invoke?.("get_overlay_state");
+}
+
+/**
+ * 
+ * @returns {object[] | undefined} stacked damage instances
+ */
+export async function invokeGetStack() {
+    return await invoke?.("get_stack");
+}
+
+/**
+ * 
+ * @param {object[]} stack stacked damage instances
+ * @returns {void}
+ */
+export async function invokeSetStack(stack) {
+    return await invoke?.("set_stack", { stack });
+}
+
+/**
+ * 
+ * @param {number} level 1 for trace up to 5 for error
+ * @param {string} message
+ * @param {string} location module that logged the message
+ * @returns {void}
+ */
+export async function forwardLog(level, message, location) {
+    return await invoke?.("plugin:log|log", { level, message, location });
+}
+
+/**
+ * 
+ * @returns {string} path of the written zip
+ */
+export async function invokeExportDiagnostics() {
+    return await invoke?.("export_diagnostics");
+}
+
+/**
+ * 
+ * @returns {object[]} result of every probe
+ */
+export async function invokeRunDiagnostics() {
+    return await invoke?.("run_diagnostics");
+}
+
+/**
+ * 
+ * @returns {object | undefined} every saved setting
+ */
+export async function invokeGetSettings() {
+    return await invoke?.("get_settings");
+}
+
+/**
+ * 
+ * @param {object} windowSettings main window settings
+ * @returns {object} saved settings
+ */
+export async function invokeSetWindowSettings(windowSettings) {
+    return await invoke?.("set_window_settings", { windowSettings });
+}
+
+/**
+ * 
+ * @param {number} page
+ * @returns {void}
+ */
+export async function invokeSetSelectedPage(page) {
+    return await invoke?.("set_selected_page", { page });
+}
+
+/**
+ * 
+ * @param {number[]} hiddenEnemies enemy indices
  * @returns {void}
  */
-export async function invokeGetRealtimeGame(gameCode) {
-    return await invoke?.("get_realtime_game", { gameCode });
+export async function invokeSetHiddenEnemies(hiddenEnemies) {
+    return await invoke?.("set_hidden_enemies", { hiddenEnemies });
+}
+
+/**
+ * 
+ * @returns {object | undefined} local server status
+ */
+export async function invokeGetServerStatus() {
+    return await invoke?.("get_server_status");
+}
+
+/**
+ * 
+ * @param {object} serverSettings local server settings
+ * @returns {object} local server status
+ */
+export async function invokeSetServerSettings(serverSettings) {
+    return await invoke?.("set_server_settings", { serverSettings });
+}
+
+/**
+ * 
+ * @returns {object | undefined} map of actions to bindings
+ */
+export async function invokeGetHotkeys() {
+    return await invoke?.("get_hotkeys");
+}
+
+/**
+ * 
+ * @param {string} action
+ * @param {string} binding
+ * @returns {string} binding as it was saved
+ */
+export async function invokeSetHotkey(action, binding) {
+    return await invoke?.("set_hotkey", { action, binding });
+}
+
+/**
+ * 
+ * @returns {object | undefined} kill alert settings
+ */
+export async function invokeGetKillAlerts() {
+    return await invoke?.("get_kill_alerts");
+}
+
+/**
+ * 
+ * @param {object} killAlerts kill alert settings
+ * @returns {object} saved settings
+ */
+export async function invokeSetKillAlerts(killAlerts) {
+    return await invoke?.("set_kill_alerts", { killAlerts });
+}
+
+/**
+ * Hands every `Realtime` fetched by the native poller to `handler`, encoded
+ * as MessagePack
+ * 
+ * @param {(payload: Uint8Array) => void} handler
+ * @returns {number | undefined} id to unsubscribe with
+ */
+export async function subscribeRealtime(handler) {
+    if (Channel === undefined) {
+        return undefined;
+    }
+    const channel = new Channel();
+    channel.onmessage = (message) => handler(new Uint8Array(message));
+    return await invoke("subscribe_realtime", { onData: channel });
+}
+
+/**
+ * 
+ * @param {number} id
+ */
+export async function unsubscribeRealtime(id) {
+    await invoke?.("unsubscribe_realtime", { id });
+}
+
+/**
+ * Subscribes to a Tauri event, handing its payload to `handler`
+ * 
+ * @param {string} eventName
+ * @param {(payload: any) => void} handler
+ * @returns {(() => void) | undefined} unlisten function
+ */
+export async function listenEvent(eventName, handler) {
+    return await listen?.(eventName, (event) => handler(event.payload));
 }
 
 /**
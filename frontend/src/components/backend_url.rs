@@ -0,0 +1,69 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::{DEFAULT_BACKEND_URL, context::core::CoreContext};
+
+#[function_component(BackendUrlInput)]
+pub fn backend_url_input() -> Html {
+    let context = use_context::<CoreContext>().expect("CoreContext not found");
+    let draft = use_state(|| context.get_backend_url().to_string());
+
+    {
+        let draft = draft.clone();
+        use_effect_with(context.backend_url.clone(), move |backend_url| {
+            draft.set(backend_url.to_string());
+        });
+    }
+
+    let oninput = {
+        let draft = draft.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            draft.set(input.value());
+        })
+    };
+
+    let onsubmit = {
+        let context = context.clone();
+        let draft = draft.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            context.change_backend_url(draft.trim().to_string());
+        })
+    };
+
+    let onreset = {
+        let context = context.clone();
+        Callback::from(move |_: MouseEvent| {
+            context.change_backend_url(DEFAULT_BACKEND_URL.to_string());
+        })
+    };
+
+    html! {
+        <form class={"flex flex-col gap-2 w-fit"} {onsubmit}>
+            <span class={"font-semibold text-emerald-300"}>{ "Backend server" }</span>
+            <div class={"flex items-center gap-2"}>
+                <input
+                    type="url"
+                    placeholder={DEFAULT_BACKEND_URL}
+                    value={draft.to_string()}
+                    {oninput}
+                    class={"w-96 px-4 h-10 rounded-lg text-indigo-300 bg-indigo-900/30 focus:outline-none tracking-wide"}
+                />
+                <button
+                    type="submit"
+                    class={"cursor-pointer h-10 px-4 rounded-lg font-semibold bg-emerald-950"}
+                >
+                    { "Apply" }
+                </button>
+                <button
+                    type="button"
+                    onclick={onreset}
+                    class={"cursor-pointer h-10 px-4 rounded-lg font-semibold bg-zinc-900"}
+                >
+                    { "Reset" }
+                </button>
+            </div>
+        </form>
+    }
+}
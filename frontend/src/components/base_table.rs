@@ -1,5 +1,5 @@
 use crate::{
-    BACKEND_URL,
+    backend_url,
     model::{
         realtime::DamageLike,
         traits::{CurrentPlayerLike, EnemyLike},
@@ -12,7 +12,7 @@ pub fn champion_td(champion_id: &str) -> Html {
     html! {
         <td>
             <img
-                src={format!("{}/cdn/champions/{}.png", BACKEND_URL, champion_id)}
+                src={format!("{}/cdn/champions/{}.png", backend_url(), champion_id)}
                 alt="Champion"
             />
         </td>
@@ -25,18 +25,18 @@ pub fn create_image(keyname: &str, champion_id: Option<String>, instance_name: &
     let is_attack_related = first_char == 'C' || first_char == 'A';
     let img_path = if instance_name == "abilities" {
         if is_attack_related {
-            format!("{}/cdn/{}/{}.png", BACKEND_URL, instance_name, first_char)
+            format!("{}/cdn/{}/{}.png", backend_url(), instance_name, first_char)
         } else {
             format!(
                 "{}/cdn/{}/{}{}.png",
-                BACKEND_URL,
+                backend_url(),
                 instance_name,
                 champion_id.unwrap_or_default(),
                 first_char
             )
         }
     } else {
-        format!("{}/cdn/{}/{}.png", BACKEND_URL, instance_name, keyname)
+        format!("{}/cdn/{}/{}.png", backend_url(), instance_name, keyname)
     };
     let text_content = if instance_name == "abilities" && !is_attack_related {
         if !remaining.is_empty() {
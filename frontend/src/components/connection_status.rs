@@ -0,0 +1,73 @@
+use yew::prelude::*;
+
+use crate::model::connection::{ConnectionState, PollRate};
+
+#[derive(PartialEq, Properties)]
+pub struct ConnectionStatusProps {
+    pub state: ConnectionState,
+    #[prop_or_default]
+    pub paused: bool,
+    /// Rate of the native poller, shown while the game is live
+    #[prop_or_default]
+    pub rate: Option<PollRate>,
+}
+
+/// Badge with the state of the realtime feed and what it is doing about it.
+#[function_component(ConnectionStatus)]
+pub fn connection_status(props: &ConnectionStatusProps) -> Html {
+    let (label, detail, dot) = match &props.state {
+        _ if props.paused => ("Paused", String::new(), "bg-sky-400"),
+        ConnectionState::Idle => ("Idle", String::new(), "bg-zinc-500"),
+        ConnectionState::WaitingForGame => (
+            "Waiting for game",
+            "Updates start once the game has loaded".to_string(),
+            "bg-amber-400 animate-pulse",
+        ),
+        ConnectionState::Live => (
+            "Live",
+            props
+                .rate
+                .map(|rate| rate.description())
+                .unwrap_or_default(),
+            "bg-emerald-400",
+        ),
+        ConnectionState::Reconnecting {
+            attempt,
+            retry_in_ms,
+            error,
+        } => (
+            "Reconnecting",
+            format!(
+                "{}. Attempt {} in {:.1}s",
+                error.description(),
+                attempt,
+                *retry_in_ms as f64 / 1000.0
+            ),
+            "bg-amber-400 animate-pulse",
+        ),
+        ConnectionState::GameEnded => (
+            "Game ended",
+            "The next game will be picked up automatically".to_string(),
+            "bg-indigo-400",
+        ),
+        ConnectionState::Error { error, retry_in_ms } => (
+            "Error",
+            format!(
+                "{}. Retrying in {:.0}s",
+                error.description(),
+                *retry_in_ms as f64 / 1000.0
+            ),
+            "bg-rose-500",
+        ),
+    };
+
+    html! {
+        <div class={"flex items-center gap-2 px-4 py-2 text-sm min-w-0"}>
+            <span class={format!("size-2.5 rounded-full flex-shrink-0 {}", dot)}></span>
+            <span class={"font-semibold text-shadow text-nowrap"}>{ label }</span>
+            if !detail.is_empty() {
+                <span class={"text-slate-400 truncate"}>{ detail }</span>
+            }
+        </div>
+    }
+}
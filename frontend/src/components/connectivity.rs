@@ -0,0 +1,133 @@
+use yew::prelude::*;
+
+use crate::{
+    externcalls::invokers::{is_native_app, run_diagnostics},
+    model::{
+        connectivity::{ProbeResult, TlsResult},
+        error::AppError,
+    },
+};
+
+fn tls_cell(tls: &TlsResult) -> Html {
+    match tls {
+        TlsResult::NotUsed => html! { <span class={"text-zinc-400"}>{ "http" }</span> },
+        TlsResult::Established => html! { <span class={"text-emerald-300"}>{ "ok" }</span> },
+        TlsResult::Failed(e) => {
+            html! { <span class={"text-rose-400"} title={e.clone()}>{ "failed" }</span> }
+        }
+        TlsResult::NotReached => html! { <span class={"text-zinc-400"}>{ "-" }</span> },
+    }
+}
+
+fn probe_row(probe: &ProbeResult) -> Html {
+    let status_class = match probe.status {
+        Some(status) if (200..300).contains(&status) => "text-emerald-300",
+        Some(_) => "text-rose-400",
+        None => "text-zinc-400",
+    };
+    html! {
+        <tr class={"border-t border-zinc-800 align-top"}>
+            <td class={"py-2 pr-4"}>
+                <div class={"flex flex-col"}>
+                    <span class={if probe.passed() { "text-emerald-300" } else { "text-rose-400" }}>
+                        { &probe.name }
+                    </span>
+                    <span class={"text-xs text-zinc-500"}>{ &probe.url }</span>
+                </div>
+            </td>
+            <td class={"py-2 pr-4 text-zinc-300"}>
+                { probe.latency_ms.map(|ms| format!("{} ms", ms)).unwrap_or("-".to_string()) }
+            </td>
+            <td class={classes!("py-2", "pr-4", status_class)}>
+                { probe.status.map(|status| status.to_string()).unwrap_or("-".to_string()) }
+            </td>
+            <td class={"py-2 pr-4"}>{ tls_cell(&probe.tls) }</td>
+            <td class={"py-2 flex flex-col gap-1"}>
+                {
+                    for probe.checks.iter().map(|check| match check.error.as_ref() {
+                        None => html! {
+                            <span class={"text-emerald-300"}>{ format!("{} ok", check.target) }</span>
+                        },
+                        Some(e) => html! {
+                            <span class={"text-rose-400"}>{ format!("{}: {}", check.target, e) }</span>
+                        },
+                    })
+                }
+                if let Some(error) = probe.error.as_ref() {
+                    <span class={"text-zinc-400"}>{ error }</span>
+                }
+            </td>
+        </tr>
+    }
+}
+
+/// Probes the live client and every backend endpoint the app depends on,
+/// showing how each answered. Only runs on demand, since probing game
+/// creation registers a code on the backend and the realtime probe uploads
+/// the running game like polling does.
+#[function_component(ConnectivityPanel)]
+pub fn connectivity_panel() -> Html {
+    let results = use_state(|| Option::<Result<Vec<ProbeResult>, AppError>>::None);
+    let running = use_state(|| false);
+
+    let run = {
+        let results = results.clone();
+        let running = running.clone();
+        Callback::from(move |_: ()| {
+            let results = results.clone();
+            let running = running.clone();
+            running.set(true);
+            run_diagnostics(Callback::from(move |probed| {
+                results.set(Some(probed));
+                running.set(false);
+            }));
+        })
+    };
+
+    if !is_native_app() {
+        return html! {};
+    }
+
+    html! {
+        <div class={"flex flex-col gap-3"}>
+            <div class={"flex items-center gap-3"}>
+                <span class={"font-semibold text-emerald-300"}>{ "Connectivity" }</span>
+                <button
+                    onclick={move |_| run.emit(())}
+                    disabled={*running}
+                    class={"cursor-pointer px-4 py-1 rounded-md font-semibold bg-zinc-900 disabled:opacity-50"}
+                >
+                    {
+                        match (*running, results.is_some()) {
+                            (true, _) => "Running...",
+                            (false, true) => "Run again",
+                            (false, false) => "Run",
+                        }
+                    }
+                </button>
+            </div>
+            {
+                match results.as_ref() {
+                    Some(Ok(probes)) => html! {
+                        <table class={"text-sm text-left w-fit"}>
+                            <thead class={"text-zinc-400"}>
+                                <tr>
+                                    <th class={"pb-2 pr-4 font-normal"}>{ "Endpoint" }</th>
+                                    <th class={"pb-2 pr-4 font-normal"}>{ "Latency" }</th>
+                                    <th class={"pb-2 pr-4 font-normal"}>{ "Status" }</th>
+                                    <th class={"pb-2 pr-4 font-normal"}>{ "TLS" }</th>
+                                    <th class={"pb-2 font-normal"}>{ "Response" }</th>
+                                </tr>
+                            </thead>
+                            <tbody>{ for probes.iter().map(probe_row) }</tbody>
+                        </table>
+                    },
+                    Some(Err(e)) => html! {
+                        <span class={"text-sm text-rose-400"}>{ e.description() }</span>
+                    },
+                    None => html! {},
+                }
+            }
+        </div>
+    }
+}
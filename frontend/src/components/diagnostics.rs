@@ -0,0 +1,52 @@
+use yew::prelude::*;
+
+use crate::{
+    externcalls::invokers::{export_diagnostics, is_native_app},
+    model::error::AppError,
+};
+
+/// Bundles the logs, settings and recent payloads into a zip to attach to
+/// bug reports, then shows where it was saved.
+#[function_component(DiagnosticsExport)]
+pub fn diagnostics_export() -> Html {
+    let result = use_state(|| Option::<Result<String, AppError>>::None);
+
+    let onclick = {
+        let result = result.clone();
+        Callback::from(move |_: MouseEvent| {
+            let result = result.clone();
+            export_diagnostics(Callback::from(move |exported| result.set(Some(exported))));
+        })
+    };
+
+    if !is_native_app() {
+        return html! {};
+    }
+
+    html! {
+        <div class={"flex flex-col gap-2"}>
+            <div class={"flex items-center gap-3"}>
+                <button
+                    {onclick}
+                    class={"cursor-pointer h-10 px-4 rounded-lg font-semibold bg-zinc-900 w-fit"}
+                >
+                    { "Export diagnostics" }
+                </button>
+                <span class={"text-zinc-400"}>
+                    { "Saves logs, settings without private values and the last recorded payloads" }
+                </span>
+            </div>
+            {
+                match result.as_ref() {
+                    Some(Ok(path)) => html! {
+                        <span class={"text-sm text-emerald-300"}>{ format!("Saved to {}", path) }</span>
+                    },
+                    Some(Err(e)) => html! {
+                        <span class={"text-sm text-rose-400"}>{ e.description() }</span>
+                    },
+                    None => html! {},
+                }
+            }
+        </div>
+    }
+}
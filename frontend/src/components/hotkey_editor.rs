@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::{
+    externcalls::invokers::{get_hotkeys, is_native_app, set_hotkey},
+    model::{error::AppError, hotkey::HotkeyAction},
+};
+
+/// One row per hotkey action. Bindings are written like "Ctrl+Shift+D" and
+/// left empty to disable the action. The backend rejects invalid and
+/// conflicting bindings, and the reason is shown next to the row.
+#[function_component(HotkeyEditor)]
+pub fn hotkey_editor() -> Html {
+    let hotkeys = use_state(HashMap::<HotkeyAction, String>::new);
+    let errors = use_state(HashMap::<HotkeyAction, String>::new);
+
+    {
+        let hotkeys = hotkeys.clone();
+        use_effect_with((), move |_| {
+            get_hotkeys(Callback::from(move |saved| hotkeys.set(saved)));
+        });
+    }
+
+    if !is_native_app() {
+        return html! {};
+    }
+
+    html! {
+        <div class={"flex flex-col gap-2"}>
+            <span class={"font-semibold text-emerald-300"}>{ "Hotkeys" }</span>
+            <table class={"w-fit text-sm"}>
+                <tbody>
+                    {
+                        HotkeyAction::ALL.iter().map(|&action| {
+                            let onchange = {
+                                let hotkeys = hotkeys.clone();
+                                let errors = errors.clone();
+                                Callback::from(move |e: Event| {
+                                    let input: HtmlInputElement = e.target_unchecked_into();
+                                    let hotkeys = hotkeys.clone();
+                                    let errors = errors.clone();
+                                    set_hotkey(action, input.value(), Callback::from(move |result: Result<String, AppError>| {
+                                        let mut changed_errors = (*errors).clone();
+                                        match result {
+                                            Ok(binding) => {
+                                                let mut changed = (*hotkeys).clone();
+                                                changed.insert(action, binding);
+                                                hotkeys.set(changed);
+                                                changed_errors.remove(&action);
+                                            }
+                                            Err(e) => {
+                                                changed_errors.insert(action, e.description());
+                                            }
+                                        }
+                                        errors.set(changed_errors);
+                                    }));
+                                })
+                            };
+                            html! {
+                                <tr>
+                                    <td class={"pr-6 py-1 text-zinc-300"}>{ action.description() }</td>
+                                    <td class={"py-1"}>
+                                        <input
+                                            type="text"
+                                            placeholder={"Disabled"}
+                                            value={hotkeys.get(&action).cloned().unwrap_or_default()}
+                                            {onchange}
+                                            class={"w-48 px-2 py-1 rounded-md font-mono text-indigo-300 bg-indigo-900/30 focus:outline-none"}
+                                        />
+                                    </td>
+                                    <td class={"pl-4 py-1 text-rose-400"}>
+                                        { errors.get(&action).cloned().unwrap_or_default() }
+                                    </td>
+                                </tr>
+                            }
+                        }).collect::<Html>()
+                    }
+                </tbody>
+            </table>
+        </div>
+    }
+}
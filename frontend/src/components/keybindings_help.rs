@@ -0,0 +1,62 @@
+use yew::prelude::*;
+
+use crate::{
+    externcalls::invokers::HELP_EVENT, hooks::tauri_event::use_tauri_event,
+    model::hotkey::KeybindingHelp,
+};
+
+/// Dialog listing the configured hotkeys, opened from the tray Help item.
+/// Stays hidden until the first `show-help` event.
+#[function_component(KeybindingsHelp)]
+pub fn keybindings_help() -> Html {
+    let keybindings = use_state(|| Option::<Vec<KeybindingHelp>>::None);
+
+    {
+        let keybindings = keybindings.clone();
+        use_tauri_event(
+            HELP_EVENT,
+            Callback::from(move |list: Vec<KeybindingHelp>| keybindings.set(Some(list))),
+        );
+    }
+
+    let Some(list) = keybindings.as_ref() else {
+        return html! {};
+    };
+
+    let dismiss = {
+        let keybindings = keybindings.clone();
+        Callback::from(move |_: MouseEvent| keybindings.set(None))
+    };
+
+    html! {
+        <div class={"fixed inset-0 z-50 flex items-center justify-center bg-black/60"}>
+            <div class={"flex flex-col gap-4 p-6 rounded-md bg-zinc-900 min-w-80"}>
+                <h2 class={"font-bold text-xl text-white"}>{ "Keybindings" }</h2>
+                if list.is_empty() {
+                    <span class={"text-sm text-zinc-400"}>{ "No hotkeys are configured." }</span>
+                } else {
+                    <table class={"text-sm"}>
+                        <tbody>
+                            {
+                                list.iter().map(|help| html! {
+                                    <tr>
+                                        <td class={"pr-6 py-1 font-mono text-indigo-300 text-nowrap"}>
+                                            { &help.binding }
+                                        </td>
+                                        <td class={"py-1 text-zinc-300"}>{ &help.description }</td>
+                                    </tr>
+                                }).collect::<Html>()
+                            }
+                        </tbody>
+                    </table>
+                }
+                <button
+                    onclick={dismiss}
+                    class={"cursor-pointer self-end px-4 py-1 rounded-md text-sm font-semibold bg-indigo-900"}
+                >
+                    { "Close" }
+                </button>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,109 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::{
+    externcalls::invokers::{get_kill_alerts, is_native_app, set_kill_alerts},
+    model::alerts::KillAlertSettings,
+};
+
+/// Notifications sent when the Realtime damage stack can kill an enemy. The
+/// combo is checked by the native app, so nothing is rendered in the browser.
+#[function_component(KillAlerts)]
+pub fn kill_alerts() -> Html {
+    let settings = use_state(KillAlertSettings::default);
+
+    {
+        let settings = settings.clone();
+        use_effect_with((), move |_| {
+            get_kill_alerts(Callback::from(move |saved| settings.set(saved)));
+        });
+    }
+
+    let save = {
+        let settings = settings.clone();
+        Callback::from(move |changed: KillAlertSettings| {
+            let settings = settings.clone();
+            set_kill_alerts(changed, Callback::from(move |saved| settings.set(saved)));
+        })
+    };
+
+    let on_enabled = {
+        let settings = settings.clone();
+        let save = save.clone();
+        Callback::from(move |_: Event| {
+            save.emit(KillAlertSettings {
+                enabled: !settings.enabled,
+                ..(*settings).clone()
+            })
+        })
+    };
+
+    let on_sound = {
+        let settings = settings.clone();
+        let save = save.clone();
+        Callback::from(move |_: Event| {
+            save.emit(KillAlertSettings {
+                sound: !settings.sound,
+                ..(*settings).clone()
+            })
+        })
+    };
+
+    let on_cooldown = {
+        let settings = settings.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Ok(cooldown_secs) = input.value().parse() {
+                save.emit(KillAlertSettings {
+                    cooldown_secs,
+                    ..(*settings).clone()
+                })
+            }
+        })
+    };
+
+    if !is_native_app() {
+        return html! {};
+    }
+
+    html! {
+        <div class={"flex flex-col gap-3"}>
+            <label class={"flex items-center gap-3 w-fit cursor-pointer"}>
+                <input
+                    type="checkbox"
+                    checked={settings.enabled}
+                    onchange={on_enabled}
+                    class={"size-4 accent-emerald-400 cursor-pointer"}
+                />
+                <span class={"font-semibold text-emerald-300"}>{ "Kill alerts" }</span>
+                <span class={"text-zinc-400"}>
+                    { "Notifies when the stacked combo deals enough damage to kill an enemy" }
+                </span>
+            </label>
+            if settings.enabled {
+                <div class={"flex items-center gap-6 pl-7 text-sm"}>
+                    <label class={"flex items-center gap-2 cursor-pointer"}>
+                        <input
+                            type="checkbox"
+                            checked={settings.sound}
+                            onchange={on_sound}
+                            class={"size-4 accent-emerald-400 cursor-pointer"}
+                        />
+                        <span class={"text-zinc-300"}>{ "Play a sound" }</span>
+                    </label>
+                    <label class={"flex items-center gap-2"}>
+                        <span class={"text-zinc-300"}>{ "Seconds between alerts per enemy" }</span>
+                        <input
+                            type="number"
+                            min="5"
+                            max="600"
+                            value={settings.cooldown_secs.to_string()}
+                            onchange={on_cooldown}
+                            class={"w-20 px-2 py-1 rounded-md bg-zinc-800 text-white"}
+                        />
+                    </label>
+                </div>
+            }
+        </div>
+    }
+}
@@ -0,0 +1,149 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::{
+    externcalls::invokers::{get_server_status, get_settings, is_native_app, set_server_settings},
+    model::{
+        error::AppError,
+        settings::{AppSettings, ServerSettings, ServerStatus},
+    },
+};
+
+/// Local server that shares the realtime feed with second screens and OBS.
+/// The address and port are edited as a draft and only applied together.
+#[function_component(LiveServerSettings)]
+pub fn live_server_settings() -> Html {
+    let draft = use_state(ServerSettings::default);
+    let status = use_state(ServerStatus::default);
+
+    {
+        let draft = draft.clone();
+        let status = status.clone();
+        use_effect_with((), move |_| {
+            get_settings(Callback::from(move |saved: AppSettings| {
+                draft.set(saved.server)
+            }));
+            get_server_status(Callback::from(move |current| status.set(current)));
+        });
+    }
+
+    let apply = {
+        let status = status.clone();
+        Callback::from(move |changed: ServerSettings| {
+            let status = status.clone();
+            set_server_settings(
+                changed,
+                Callback::from(move |result: Result<ServerStatus, AppError>| match result {
+                    Ok(current) => status.set(current),
+                    Err(error) => status.set(ServerStatus {
+                        running: false,
+                        url: None,
+                        error: Some(error.description()),
+                    }),
+                }),
+            );
+        })
+    };
+
+    let on_enabled = {
+        let draft = draft.clone();
+        let apply = apply.clone();
+        Callback::from(move |_: Event| {
+            let changed = ServerSettings {
+                enabled: !draft.enabled,
+                ..(*draft).clone()
+            };
+            draft.set(changed.clone());
+            apply.emit(changed);
+        })
+    };
+
+    let on_address = {
+        let draft = draft.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            draft.set(ServerSettings {
+                address: input.value(),
+                ..(*draft).clone()
+            });
+        })
+    };
+
+    let on_port = {
+        let draft = draft.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Ok(port) = input.value().parse() {
+                draft.set(ServerSettings {
+                    port,
+                    ..(*draft).clone()
+                });
+            }
+        })
+    };
+
+    let onsubmit = {
+        let draft = draft.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            apply.emit((*draft).clone());
+        })
+    };
+
+    if !is_native_app() {
+        return html! {};
+    }
+
+    html! {
+        <form class={"flex flex-col gap-3"} {onsubmit}>
+            <label class={"flex items-center gap-3 w-fit cursor-pointer"}>
+                <input
+                    type="checkbox"
+                    checked={draft.enabled}
+                    onchange={on_enabled}
+                    class={"size-4 accent-emerald-400 cursor-pointer"}
+                />
+                <span class={"font-semibold text-emerald-300"}>{ "Local server" }</span>
+                <span class={"text-zinc-400"}>
+                    { "Shares the realtime data with other screens and serves an overlay page for OBS" }
+                </span>
+            </label>
+            <div class={"flex items-center gap-2 pl-7 text-sm"}>
+                <input
+                    type="text"
+                    value={draft.address.clone()}
+                    oninput={on_address}
+                    class={"w-40 px-2 py-1 rounded-md bg-zinc-800 text-white"}
+                />
+                <span class={"text-zinc-400"}>{ ":" }</span>
+                <input
+                    type="number"
+                    min="1"
+                    max="65535"
+                    value={draft.port.to_string()}
+                    oninput={on_port}
+                    class={"w-24 px-2 py-1 rounded-md bg-zinc-800 text-white"}
+                />
+                <button
+                    type="submit"
+                    class={"cursor-pointer px-4 py-1 rounded-md font-semibold bg-emerald-950"}
+                >
+                    { "Apply" }
+                </button>
+            </div>
+            <div class={"flex flex-col gap-1 pl-7 text-sm"}>
+                if let Some(url) = status.url.as_ref() {
+                    <span class={"text-zinc-300"}>
+                        { format!("Overlay page for OBS: {}/", url) }
+                    </span>
+                    <span class={"text-zinc-300"}>
+                        { format!("Latest data: {}/realtime, live updates: {}/ws", url, url.replacen("http", "ws", 1)) }
+                    </span>
+                }
+                if let Some(error) = status.error.as_ref() {
+                    <span class={"text-rose-400"}>{ error }</span>
+                }
+            </div>
+        </form>
+    }
+}
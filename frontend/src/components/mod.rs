@@ -1,7 +1,21 @@
+pub mod backend_url;
 pub mod base_table;
 pub mod comparison_header;
 pub mod comparison_table;
+pub mod connection_status;
+pub mod connectivity;
+pub mod diagnostics;
+pub mod hotkey_editor;
+pub mod keybindings_help;
+pub mod kill_alerts;
+pub mod live_server;
+pub mod overlay_controls;
+pub mod polling_settings;
+pub mod recorder_toggle;
+pub mod replay;
 pub mod selector;
 pub mod sidebar;
+pub mod simulated_items;
 pub mod stacker;
 pub mod value_cell;
+pub mod window_settings;
@@ -0,0 +1,76 @@
+use yew::prelude::*;
+
+use crate::{
+    externcalls::invokers::{
+        OVERLAY_STATE_EVENT, get_overlay_state, is_native_app, set_overlay_base_table,
+        set_overlay_locked, toggle_overlay,
+    },
+    hooks::tauri_event::use_tauri_event,
+    model::overlay::OverlayState,
+};
+
+/// Opens the in-game overlay and unlocks it for moving. Only the native app
+/// has an overlay window, so nothing is rendered in the browser.
+#[function_component(OverlayControls)]
+pub fn overlay_controls() -> Html {
+    let overlay_state = use_state(OverlayState::default);
+
+    {
+        let overlay_state = overlay_state.clone();
+        use_effect_with((), move |_| {
+            if is_native_app() {
+                get_overlay_state(Callback::from(move |state| overlay_state.set(state)));
+            }
+        });
+    }
+
+    {
+        let overlay_state = overlay_state.clone();
+        use_tauri_event(
+            OVERLAY_STATE_EVENT,
+            Callback::from(move |state: OverlayState| overlay_state.set(state)),
+        );
+    }
+
+    if !is_native_app() {
+        return html! {};
+    }
+
+    let toggle_lock = {
+        let locked = overlay_state.locked;
+        Callback::from(move |_: MouseEvent| set_overlay_locked(!locked))
+    };
+
+    let toggle_base_table = {
+        let show_base_table = overlay_state.show_base_table;
+        Callback::from(move |_: Event| set_overlay_base_table(!show_base_table))
+    };
+
+    html! {
+        <div class={"flex flex-wrap items-center gap-4 px-4 py-3"}>
+            <button
+                onclick={Callback::from(|_: MouseEvent| toggle_overlay())}
+                class={"cursor-pointer px-3 py-1.5 rounded-lg font-semibold text-sm bg-indigo-950"}
+            >
+                { if overlay_state.visible { "Hide Overlay" } else { "Show Overlay" } }
+            </button>
+            if overlay_state.visible {
+                <button
+                    onclick={toggle_lock}
+                    class={"cursor-pointer px-3 py-1.5 rounded-lg font-semibold text-sm bg-sky-950"}
+                >
+                    { if overlay_state.locked { "Move Overlay" } else { "Lock Overlay" } }
+                </button>
+            }
+            <label class={"flex items-center gap-2 cursor-pointer text-sm"}>
+                <input
+                    type="checkbox"
+                    checked={overlay_state.show_base_table}
+                    onchange={toggle_base_table}
+                    class={"size-4 accent-emerald-400 cursor-pointer"}
+                />
+                <span>{ "Damage table in overlay" }</span>
+            </label>
+        </div>
+    }
+}
@@ -0,0 +1,100 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::{
+    externcalls::invokers::{get_settings, is_native_app, set_polling_settings},
+    model::settings::{AppSettings, PollingSettings},
+};
+
+/// Bounds of the adaptive poll rate. The native poller speeds up during
+/// fights and slows down while the player is dead or the window is hidden,
+/// the browser build always polls at the same rate.
+#[function_component(PollingSettingsForm)]
+pub fn polling_settings_form() -> Html {
+    let settings = use_state(PollingSettings::default);
+
+    {
+        let settings = settings.clone();
+        use_effect_with((), move |_| {
+            get_settings(Callback::from(move |saved: AppSettings| {
+                settings.set(saved.polling)
+            }));
+        });
+    }
+
+    let save = {
+        let settings = settings.clone();
+        Callback::from(move |changed: PollingSettings| {
+            let settings = settings.clone();
+            set_polling_settings(changed, Callback::from(move |saved| settings.set(saved)));
+        })
+    };
+
+    let on_min = {
+        let settings = settings.clone();
+        let save = save.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Ok(min_interval_ms) = input.value().parse() {
+                save.emit(PollingSettings {
+                    min_interval_ms,
+                    ..(*settings).clone()
+                })
+            }
+        })
+    };
+
+    let on_max = {
+        let settings = settings.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Ok(max_interval_ms) = input.value().parse() {
+                save.emit(PollingSettings {
+                    max_interval_ms,
+                    ..(*settings).clone()
+                })
+            }
+        })
+    };
+
+    if !is_native_app() {
+        return html! {};
+    }
+
+    html! {
+        <div class={"flex flex-col gap-3"}>
+            <div class={"flex items-center gap-3"}>
+                <span class={"font-semibold text-emerald-300"}>{ "Update rate" }</span>
+                <span class={"text-zinc-400"}>
+                    { "Faster during fights, slower while dead or hidden, paused with the game" }
+                </span>
+            </div>
+            <div class={"flex items-center gap-6 text-sm"}>
+                <label class={"flex items-center gap-2"}>
+                    <span class={"text-zinc-300"}>{ "Fastest (ms)" }</span>
+                    <input
+                        type="number"
+                        min="250"
+                        max="10000"
+                        step="50"
+                        value={settings.min_interval_ms.to_string()}
+                        onchange={on_min}
+                        class={"w-24 px-2 py-1 rounded-md bg-zinc-800 text-white"}
+                    />
+                </label>
+                <label class={"flex items-center gap-2"}>
+                    <span class={"text-zinc-300"}>{ "Slowest (ms)" }</span>
+                    <input
+                        type="number"
+                        min="250"
+                        max="10000"
+                        step="50"
+                        value={settings.max_interval_ms.to_string()}
+                        onchange={on_max}
+                        class={"w-24 px-2 py-1 rounded-md bg-zinc-800 text-white"}
+                    />
+                </label>
+            </div>
+        </div>
+    }
+}
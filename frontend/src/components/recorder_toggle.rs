@@ -0,0 +1,47 @@
+use yew::prelude::*;
+
+use crate::externcalls::invokers::{get_recording, is_native_app, set_recording};
+
+/// Opt-in switch for recording fetched games to disk. Only the native app
+/// can record, so nothing is rendered in the browser.
+#[function_component(RecorderToggle)]
+pub fn recorder_toggle() -> Html {
+    let recording = use_state(|| false);
+
+    {
+        let recording = recording.clone();
+        use_effect_with((), move |_| {
+            get_recording(Callback::from(move |enabled| recording.set(enabled)));
+        });
+    }
+
+    let onchange = {
+        let recording = recording.clone();
+        Callback::from(move |_: Event| {
+            let recording = recording.clone();
+            set_recording(
+                !*recording,
+                Callback::from(move |enabled| recording.set(enabled)),
+            );
+        })
+    };
+
+    if !is_native_app() {
+        return html! {};
+    }
+
+    html! {
+        <label class={"flex items-center gap-3 w-fit cursor-pointer"}>
+            <input
+                type="checkbox"
+                checked={*recording}
+                {onchange}
+                class={"size-4 accent-emerald-400 cursor-pointer"}
+            />
+            <span class={"font-semibold text-emerald-300"}>{ "Record games" }</span>
+            <span class={"text-zinc-400"}>
+                { "Saves every live payload and response to the app data folder" }
+            </span>
+        </label>
+    }
+}
@@ -0,0 +1,271 @@
+use log::error;
+use std::rc::Rc;
+
+use gloo::{file::File, timers::callback::Interval};
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::{
+    externcalls::invokers::{is_native_app, list_recordings, load_recording},
+    model::{
+        realtime::Realtime,
+        replay::{RecordingInfo, Replay},
+    },
+};
+
+const TICK_MS: u32 = 250;
+const SPEEDS: [f64; 6] = [0.5, 1.0, 2.0, 4.0, 8.0, 16.0];
+
+fn format_game_time(game_time: f64) -> String {
+    format!("{}m {:02}s", game_time as i32 / 60, game_time as i32 % 60)
+}
+
+#[derive(PartialEq, Properties)]
+pub struct RecordingPickerProps {
+    pub on_load: Callback<Rc<Replay>>,
+}
+
+/// Lists the games recorded by the native app and accepts a recording file
+/// picked from disk, which also works in the browser.
+#[function_component(RecordingPicker)]
+pub fn recording_picker(props: &RecordingPickerProps) -> Html {
+    let recordings = use_state(Vec::<RecordingInfo>::new);
+
+    {
+        let recordings = recordings.clone();
+        use_effect_with((), move |_| {
+            if is_native_app() {
+                list_recordings(Callback::from(move |list| recordings.set(list)));
+            }
+        });
+    }
+
+    let on_loaded = {
+        let on_load = props.on_load.clone();
+        Callback::from(move |replay: Replay| on_load.emit(Rc::new(replay)))
+    };
+
+    let onchange = {
+        let on_loaded = on_loaded.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let Some(file) = input.files().and_then(|files| files.get(0)) else {
+                return;
+            };
+            let on_loaded = on_loaded.clone();
+            spawn_local(async move {
+                let file = File::from(file);
+                match gloo::file::futures::read_as_text(&file).await {
+                    Ok(contents) => match Replay::from_jsonl(file.name(), &contents) {
+                        Ok(replay) => on_loaded.emit(replay),
+                        Err(e) => error!("{}", e),
+                    },
+                    Err(e) => {
+                        error!("Failed to read recording file: {}", e)
+                    }
+                }
+            });
+        })
+    };
+
+    html! {
+        <div class={"flex flex-col gap-3"}>
+            <h3 class={"flex text-lg font-semibold items-center gap-3 text-white"}>
+                { "Replay a recorded game" }
+            </h3>
+            <div class={"flex flex-wrap gap-2"}>
+                {
+                    recordings.iter().map(|recording| {
+                        let onclick = {
+                            let name = recording.name.clone();
+                            let on_loaded = on_loaded.clone();
+                            Callback::from(move |_: MouseEvent| {
+                                load_recording(name.clone(), on_loaded.clone());
+                            })
+                        };
+                        html! {
+                            <button
+                                {onclick}
+                                class={"cursor-pointer px-3 py-1.5 rounded-lg text-sm bg-indigo-900/30 text-indigo-300"}
+                            >
+                                { format!("{} ({} KB)", recording.name, recording.size / 1024) }
+                            </button>
+                        }
+                    }).collect::<Html>()
+                }
+            </div>
+            <label class={"flex items-center gap-2 w-fit cursor-pointer text-slate-300"}>
+                <span class={"font-semibold text-emerald-300 bg-emerald-900/30 px-2 py-1 rounded-md"}>
+                    { "Open recording file" }
+                </span>
+                <input type="file" accept=".jsonl" class={"hidden"} {onchange} />
+            </label>
+        </div>
+    }
+}
+
+/// Position of the replay in game seconds. Kept in a reducer so the playback
+/// interval always advances from the latest position.
+#[derive(PartialEq)]
+struct Playhead {
+    position: f64,
+    start: f64,
+    end: f64,
+}
+
+enum PlayheadAction {
+    Advance(f64),
+    Seek(f64),
+}
+
+impl Reducible for Playhead {
+    type Action = PlayheadAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let position = match action {
+            PlayheadAction::Advance(seconds) => self.position + seconds,
+            PlayheadAction::Seek(position) => position,
+        };
+        Rc::new(Self {
+            position: position.clamp(self.start, self.end),
+            start: self.start,
+            end: self.end,
+        })
+    }
+}
+
+#[derive(PartialEq, Properties)]
+pub struct ReplayControlsProps {
+    pub replay: Rc<Replay>,
+    pub on_frame: Callback<Rc<Realtime>>,
+    pub on_exit: Callback<MouseEvent>,
+}
+
+/// Play/pause, speed and a scrubber over `game_time`. Every frame reached is
+/// handed to `on_frame`, so the page renders it exactly like live data.
+#[function_component(ReplayControls)]
+pub fn replay_controls(props: &ReplayControlsProps) -> Html {
+    let replay = props.replay.clone();
+    let playhead = use_reducer(|| Playhead {
+        position: replay.start(),
+        start: replay.start(),
+        end: replay.end(),
+    });
+    let playing = use_state(|| false);
+    let speed = use_state(|| 1.0f64);
+    let last_frame = use_mut_ref(|| Option::<usize>::None);
+
+    {
+        let playhead = playhead.clone();
+        use_effect_with((*playing, *speed), move |&(playing, speed)| {
+            let interval = playing.then(|| {
+                Interval::new(TICK_MS, move || {
+                    playhead.dispatch(PlayheadAction::Advance(speed * TICK_MS as f64 / 1000.0));
+                })
+            });
+            move || drop(interval)
+        });
+    }
+
+    {
+        let replay = replay.clone();
+        let on_frame = props.on_frame.clone();
+        let playing = playing.clone();
+        use_effect_with(playhead.position, move |&position| {
+            let index = replay.frame_index_at(position);
+            if *last_frame.borrow() != Some(index) {
+                *last_frame.borrow_mut() = Some(index);
+                on_frame.emit(replay.frames[index].clone());
+            }
+            if position >= replay.end() {
+                playing.set(false);
+            }
+        });
+    }
+
+    let toggle_play = {
+        let playing = playing.clone();
+        let playhead = playhead.clone();
+        Callback::from(move |_: MouseEvent| {
+            if !*playing && playhead.position >= playhead.end {
+                playhead.dispatch(PlayheadAction::Seek(playhead.start));
+            }
+            playing.set(!*playing);
+        })
+    };
+
+    let change_speed = {
+        let speed = speed.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Ok(value) = select.value().parse::<f64>() {
+                speed.set(value);
+            }
+        })
+    };
+
+    let seek = {
+        let playhead = playhead.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Ok(value) = input.value().parse::<f64>() {
+                playhead.dispatch(PlayheadAction::Seek(value));
+            }
+        })
+    };
+
+    html! {
+        <div class={"flex flex-col gap-3 p-4"}>
+            <div class={"flex items-center justify-between gap-4"}>
+                <span class={"font-bold text-sm text-shadow truncate"}>
+                    { format!("Replay - {}", replay.name) }
+                </span>
+                <button
+                    onclick={props.on_exit.clone()}
+                    class={"cursor-pointer px-3 py-1.5 rounded-lg font-semibold text-sm bg-emerald-950"}
+                >
+                    { "Exit Replay" }
+                </button>
+            </div>
+            <input
+                type="range"
+                min={replay.start().to_string()}
+                max={replay.end().to_string()}
+                step="1"
+                value={playhead.position.to_string()}
+                oninput={seek}
+                class={"w-full accent-emerald-400 cursor-pointer"}
+            />
+            <div class={"flex items-center gap-4"}>
+                <button
+                    onclick={toggle_play}
+                    class={"cursor-pointer w-20 py-1.5 rounded-lg font-semibold text-sm bg-sky-950"}
+                >
+                    { if *playing { "Pause" } else { "Play" } }
+                </button>
+                <select
+                    onchange={change_speed}
+                    class={"px-2 py-1.5 rounded-lg text-sm bg-indigo-900/30 text-indigo-300 cursor-pointer"}
+                >
+                    {
+                        SPEEDS.iter().map(|option| html! {
+                            <option value={option.to_string()} selected={*option == *speed}>
+                                { format!("{}x", option) }
+                            </option>
+                        }).collect::<Html>()
+                    }
+                </select>
+                <span class={"text-sm text-slate-300 text-nowrap"}>
+                    {
+                        format!(
+                            "{} / {}",
+                            format_game_time(playhead.position),
+                            format_game_time(playhead.end),
+                        )
+                    }
+                </span>
+            </div>
+        </div>
+    }
+}
@@ -1,6 +1,6 @@
 use yew::prelude::*;
 
-use crate::{BACKEND_URL, img::icons::*};
+use crate::{backend_url, context::core::CoreContext, img::icons::*};
 
 fn make_header_li(
     index: usize,
@@ -33,6 +33,23 @@ fn make_header_li(
     }
 }
 
+fn settings_svg() -> Html {
+    html! {
+        <svg
+            xmlns="http://www.w3.org/2000/svg"
+            viewBox="0 0 24 24"
+            fill="none"
+            stroke="currentColor"
+            stroke-width="2"
+            stroke-linecap="round"
+            stroke-linejoin="round"
+        >
+            <circle cx="12" cy="12" r="3" />
+            <path d="M19.4 15a1.65 1.65 0 0 0 .33 1.82l.06.06a2 2 0 1 1-2.83 2.83l-.06-.06a1.65 1.65 0 0 0-1.82-.33 1.65 1.65 0 0 0-1 1.51V21a2 2 0 1 1-4 0v-.09a1.65 1.65 0 0 0-1.08-1.51 1.65 1.65 0 0 0-1.82.33l-.06.06a2 2 0 1 1-2.83-2.83l.06-.06a1.65 1.65 0 0 0 .33-1.82 1.65 1.65 0 0 0-1.51-1H3a2 2 0 1 1 0-4h.09a1.65 1.65 0 0 0 1.51-1.08 1.65 1.65 0 0 0-.33-1.82l-.06-.06a2 2 0 1 1 2.83-2.83l.06.06a1.65 1.65 0 0 0 1.82.33H9a1.65 1.65 0 0 0 1-1.51V3a2 2 0 1 1 4 0v.09a1.65 1.65 0 0 0 1 1.51 1.65 1.65 0 0 0 1.82-.33l.06-.06a2 2 0 1 1 2.83 2.83l-.06.06a1.65 1.65 0 0 0-.33 1.82V9a1.65 1.65 0 0 0 1.51 1H21a2 2 0 1 1 0 4h-.09a1.65 1.65 0 0 0-1.51 1z" />
+        </svg>
+    }
+}
+
 #[derive(PartialEq, Properties)]
 pub struct SidebarProps {
     pub state_handler: UseStateHandle<usize>,
@@ -40,17 +57,20 @@ pub struct SidebarProps {
 
 #[function_component(Sidebar)]
 pub fn sidebar(props: &SidebarProps) -> Html {
+    // Subscribing to the context re-renders the logos when the backend changes
+    use_context::<CoreContext>().expect("CoreContext not found");
+
     html! {
         <nav style="box-shadow: 5px 0px 10px black;" class={"h-screen bg-zinc-950 w-48"}>
             <div class={"flex items-center gap-3 p-4 mb-6 mt-3 justify-center"}>
                 <img
                     class={"w-8 h-8 flex-shrink-0"}
-                    src={format!("{}/cdn/other/league_logo.svg", BACKEND_URL)}
+                    src={format!("{}/cdn/other/league_logo.svg", backend_url())}
                     alt=""
                 />
                 <img
                     class={"h-10"}
-                    src={format!("{}/cdn/other/league.svg", BACKEND_URL)}
+                    src={format!("{}/cdn/other/league.svg", backend_url())}
                     alt=""
                 />
             </div>
@@ -70,6 +90,7 @@ pub fn sidebar(props: &SidebarProps) -> Html {
                     { make_header_li(3, &props.state_handler, about_svg(), "About") }
                     { make_header_li(4, &props.state_handler, formulas_svg(), "Formulas") }
                     { make_header_li(5, &props.state_handler, github_svg(), "Github") }
+                    { make_header_li(6, &props.state_handler, settings_svg(), "Settings") }
                 </ul>
             </ol>
         </nav>
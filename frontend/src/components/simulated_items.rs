@@ -0,0 +1,83 @@
+use yew::prelude::*;
+
+use crate::{
+    backend_url,
+    components::selector::{SelectionMode, Selector},
+    context::core::CoreContext,
+};
+
+#[derive(PartialEq, Properties)]
+pub struct SimulatedItemsProps {
+    pub champion_id: String,
+    /// Runs after the list was saved, so the feed can fetch again
+    pub on_change: Callback<()>,
+}
+
+/// Items compared in Realtime for the current champion. Items are added from
+/// the selector and removed by clicking them. Choices are saved per champion.
+#[function_component(SimulatedItems)]
+pub fn simulated_items(props: &SimulatedItemsProps) -> Html {
+    let context = use_context::<CoreContext>().expect("CoreContext not found");
+    let items = context.get_simulated_items(&props.champion_id);
+    let all_items = context.get_static_items().clone();
+
+    let change_items = {
+        let context = context.clone();
+        let champion_id = props.champion_id.clone();
+        let on_change = props.on_change.clone();
+        move |items: Vec<usize>| {
+            context.change_simulated_items(champion_id.clone(), items, on_change.clone());
+        }
+    };
+
+    let add_item = {
+        let items = items.clone();
+        let change_items = change_items.clone();
+        Callback::from(move |item_id: usize| {
+            if !items.contains(&item_id) {
+                let mut items = items.clone();
+                items.push(item_id);
+                change_items(items);
+            }
+        })
+    };
+
+    html! {
+        <div class={"flex flex-col gap-2 p-4"}>
+            <span class={"font-bold text-sm text-shadow"}>{ "Compared items" }</span>
+            <div class={"flex flex-wrap gap-2"}>
+                {
+                    items.iter().map(|&item_id| {
+                        let onclick = {
+                            let items = items.clone();
+                            let change_items = change_items.clone();
+                            Callback::from(move |_: MouseEvent| {
+                                change_items(items.iter().copied().filter(|&i| i != item_id).collect());
+                            })
+                        };
+                        html! {
+                            <button
+                                {onclick}
+                                title={all_items.get(&item_id).cloned().unwrap_or(item_id.to_string())}
+                                class={"cursor-pointer flex items-center gap-2 px-2 py-1 rounded-md text-sm bg-indigo-900/30 text-indigo-300"}
+                            >
+                                <img
+                                    class={"h-5 w-5 aspect-square"}
+                                    src={format!("{}/cdn/items/{}.png", backend_url(), item_id)}
+                                    alt="Item"
+                                />
+                                <span>{ "×" }</span>
+                            </button>
+                        }
+                    }).collect::<Html>()
+                }
+            </div>
+            <Selector<usize>
+                source_map={all_items}
+                uri={format!("{}/cdn/items", backend_url())}
+                title={"Add an item to compare"}
+                selection={SelectionMode::Single(add_item)}
+            />
+        </div>
+    }
+}
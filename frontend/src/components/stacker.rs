@@ -1,13 +1,11 @@
 use std::collections::HashMap;
 
+use serde::{Deserialize, Serialize};
+use tutorlolv2_shared::realtime::StackedInstance;
 use uuid::Uuid;
 use yew::prelude::*;
 
-use crate::{
-    BACKEND_URL,
-    components::base_table::create_image,
-    model::{realtime::InstanceDamage, traits::EnemyLike},
-};
+use crate::{backend_url, components::base_table::create_image, model::traits::EnemyLike};
 
 pub fn make_stacker_header(urls: &[String]) -> Html {
     urls.iter()
@@ -24,7 +22,7 @@ pub fn make_stacker_header(urls: &[String]) -> Html {
         .collect::<Html>()
 }
 
-#[derive(PartialEq, Clone)]
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
 pub struct StackInstance {
     id: String,
     pub keyname: String,
@@ -32,6 +30,25 @@ pub struct StackInstance {
     pub is_maximum: bool,
 }
 
+impl StackedInstance for StackInstance {
+    fn source(&self) -> &str {
+        &self.source
+    }
+
+    fn keyname(&self) -> &str {
+        &self.keyname
+    }
+
+    fn is_maximum(&self) -> bool {
+        self.is_maximum
+    }
+}
+
+/// Damage `enemy` takes from every instance in `stack`.
+pub fn stacked_damage<T: EnemyLike>(stack: &[StackInstance], enemy: &T) -> f64 {
+    enemy.get_damages().stacked_damage(stack)
+}
+
 pub fn stacker<T: EnemyLike>(stack: &Vec<StackInstance>, enemies: &Vec<T>) -> Html {
     html! {
         <table>
@@ -42,40 +59,20 @@ pub fn stacker<T: EnemyLike>(stack: &Vec<StackInstance>, enemies: &Vec<T>) -> Ht
                     </th>
                     {
                         make_stacker_header(&[
-                            format!("{}/cdn/other/sigma.svg", BACKEND_URL),
-                            format!("{}/cdn/other/heart.svg", BACKEND_URL),
-                            format!("{}/cdn/other/percent.svg", BACKEND_URL)
+                            format!("{}/cdn/other/sigma.svg", backend_url()),
+                            format!("{}/cdn/other/heart.svg", backend_url()),
+                            format!("{}/cdn/other/percent.svg", backend_url())
                         ])
                     }
                 </tr>
             </thead>
             <tbody>
                 {enemies.iter().map(|enemy| {
-                    let mut total_damage = 0f64;
-                    let damages = enemy.get_damages();
+                    let total_damage = stacked_damage(stack, enemy);
                     let current_stats = enemy.get_current_stats();
                     let enemy_champion_id = enemy.get_champion_id();
                     let enemy_champion_name = enemy.get_champion_name();
 
-                    for instance_value in stack.iter() {
-                        let mut accumulator = |damagelike: &Option<&InstanceDamage>| {
-                            if let Some(instance_damage) = damagelike {
-                                if instance_value.is_maximum {
-                                    total_damage += instance_damage.maximum_damage;
-                                }
-                                else {
-                                    total_damage += instance_damage.minimum_damage;
-                                }
-                            }
-                        };
-                        match instance_value.source.as_str() {
-                            "abilities" => accumulator(&damages.abilities.get(&instance_value.keyname)),
-                            "items" => accumulator(&damages.items.get(&instance_value.keyname)),
-                            "runes" => accumulator(&damages.runes.get(&instance_value.keyname)),
-                            _ => {}
-                        }
-                    }
-
                     let final_health = current_stats.health - total_damage;
                     let final_health_percent = final_health / current_stats.health;
 
@@ -84,7 +81,7 @@ pub fn stacker<T: EnemyLike>(stack: &Vec<StackInstance>, enemies: &Vec<T>) -> Ht
                             <td class={"!justify-items-start"}>
                                 <div class={"flex items-center gap-2"}>
                                     <img
-                                        src={format!("{}/cdn/champions/{}.png", BACKEND_URL, &enemy_champion_id)}
+                                        src={format!("{}/cdn/champions/{}.png", backend_url(), &enemy_champion_id)}
                                         alt="Champion"
                                     />
                                     <span class={"text-sm max-w-24 truncate"}>
@@ -1,13 +1,13 @@
 use yew::prelude::*;
 
-use crate::BACKEND_URL;
+use crate::backend_url;
 
 pub fn value_cell(image_source: &str, value: String, oninput: Callback<InputEvent>) -> Html {
     html! {
         <div class={"grid grid-cols-[auto_1fr] gap-2"}>
             <img
                 class={"h-8 min-w-8 aspect-square"}
-                src={format!("{}/cdn/other/{}", BACKEND_URL, image_source)}
+                src={format!("{}/cdn/other/{}", backend_url(), image_source)}
                 alt="Cell"
             />
             <input
@@ -0,0 +1,135 @@
+use web_sys::{HtmlInputElement, window};
+use yew::prelude::*;
+
+use crate::{
+    externcalls::invokers::{get_settings, is_native_app, set_window_settings},
+    model::settings::{AppSettings, WindowSettings},
+};
+
+/// Sets the alpha of the page background, which lets the game show through
+/// the transparent window. `bg-zinc-950` is the opaque color.
+pub fn apply_background_opacity(opacity: f64) {
+    if let Some(body) = window().and_then(|w| w.document()).and_then(|d| d.body()) {
+        let _ = body.set_attribute(
+            "style",
+            &format!("background-color: rgba(9, 9, 11, {})", opacity),
+        );
+    }
+}
+
+fn checkbox(label: &str, checked: bool, onchange: Callback<Event>) -> Html {
+    html! {
+        <label class={"flex items-center gap-2 cursor-pointer"}>
+            <input
+                type="checkbox"
+                {checked}
+                {onchange}
+                class={"size-4 accent-emerald-400 cursor-pointer"}
+            />
+            <span class={"text-zinc-300"}>{ label.to_string() }</span>
+        </label>
+    }
+}
+
+/// Options of the main window. Size and flags apply right away, the position
+/// is saved when the window is closed.
+#[function_component(WindowSettingsForm)]
+pub fn window_settings_form() -> Html {
+    let settings = use_state(WindowSettings::default);
+
+    {
+        let settings = settings.clone();
+        use_effect_with((), move |_| {
+            get_settings(Callback::from(move |saved: AppSettings| {
+                settings.set(saved.window)
+            }));
+        });
+    }
+
+    let save = {
+        let settings = settings.clone();
+        Callback::from(move |changed: WindowSettings| {
+            let settings = settings.clone();
+            set_window_settings(
+                changed,
+                Callback::from(move |saved: WindowSettings| {
+                    apply_background_opacity(saved.background_opacity);
+                    settings.set(saved);
+                }),
+            );
+        })
+    };
+
+    let toggle = |update: fn(&mut WindowSettings)| {
+        let settings = settings.clone();
+        let save = save.clone();
+        Callback::from(move |_: Event| {
+            let mut changed = (*settings).clone();
+            update(&mut changed);
+            save.emit(changed);
+        })
+    };
+
+    let number = |update: fn(&mut WindowSettings, f64)| {
+        let settings = settings.clone();
+        let save = save.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Ok(value) = input.value().parse() {
+                let mut changed = (*settings).clone();
+                update(&mut changed, value);
+                save.emit(changed);
+            }
+        })
+    };
+
+    if !is_native_app() {
+        return html! {};
+    }
+
+    html! {
+        <div class={"flex flex-col gap-3"}>
+            <span class={"font-semibold text-emerald-300"}>{ "Window" }</span>
+            <div class={"flex items-center gap-6 text-sm"}>
+                <label class={"flex items-center gap-2"}>
+                    <span class={"text-zinc-300"}>{ "Width" }</span>
+                    <input
+                        type="number"
+                        min="640"
+                        value={settings.width.to_string()}
+                        onchange={number(|s, value| s.width = value)}
+                        class={"w-24 px-2 py-1 rounded-md bg-zinc-800 text-white"}
+                    />
+                </label>
+                <label class={"flex items-center gap-2"}>
+                    <span class={"text-zinc-300"}>{ "Height" }</span>
+                    <input
+                        type="number"
+                        min="360"
+                        value={settings.height.to_string()}
+                        onchange={number(|s, value| s.height = value)}
+                        class={"w-24 px-2 py-1 rounded-md bg-zinc-800 text-white"}
+                    />
+                </label>
+                <label class={"flex items-center gap-2"}>
+                    <span class={"text-zinc-300"}>{ "Background opacity" }</span>
+                    <input
+                        type="range"
+                        min="0.2"
+                        max="1"
+                        step="0.05"
+                        value={settings.background_opacity.to_string()}
+                        onchange={number(|s, value| s.background_opacity = value)}
+                        class={"accent-emerald-400 cursor-pointer"}
+                    />
+                </label>
+            </div>
+            <div class={"flex items-center gap-6 text-sm"}>
+                { checkbox("Always on top", settings.always_on_top, toggle(|s| s.always_on_top = !s.always_on_top)) }
+                { checkbox("Title bar", settings.decorations, toggle(|s| s.decorations = !s.decorations)) }
+                { checkbox("Hide from taskbar", settings.skip_taskbar, toggle(|s| s.skip_taskbar = !s.skip_taskbar)) }
+                { checkbox("Resizable", settings.resizable, toggle(|s| s.resizable = !s.resizable)) }
+            </div>
+        </div>
+    }
+}
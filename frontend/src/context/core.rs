@@ -1,3 +1,4 @@
+use log::error;
 use reqwasm::http::Request;
 use serde::Deserialize;
 use serde::de::DeserializeOwned;
@@ -6,23 +7,77 @@ use std::hash::Hash;
 use std::rc::Rc;
 use std::{cell::RefCell, collections::HashMap};
 use wasm_bindgen_futures::spawn_local;
-use web_sys::console;
 use yew::{html::ChildrenProps, prelude::*};
 
-use crate::BACKEND_URL;
+use crate::externcalls::invokers::{
+    get_backend_url, get_simulated_items, set_backend_url, set_simulated_items,
+};
+use crate::{
+    DEFAULT_BACKEND_URL, backend_url, simulated_items_for, store_backend_url, store_simulated_items,
+};
 use crate::{model::server::ServerResponse, pages::formulas::APIFormulas};
 
 type RcState<T> = UseStateHandle<Rc<T>>;
 
 #[derive(Clone, PartialEq)]
 pub struct CoreContext {
+    pub backend_url: UseStateHandle<String>,
     pub static_formulas: Rc<RefCell<HashMap<String, APIFormulas>>>,
     pub static_champions: RcState<HashMap<String, String>>,
     pub static_items: RcState<HashMap<usize, String>>,
     pub static_runes: RcState<HashMap<usize, String>>,
+    pub simulated_items: RcState<HashMap<String, Vec<usize>>>,
 }
 
 impl CoreContext {
+    pub fn get_backend_url(&self) -> &str {
+        &self.backend_url
+    }
+
+    /// Validates and persists a new backend url. Components consuming this
+    /// context re-render with the new url and static data is fetched again.
+    pub fn change_backend_url(&self, url: String) {
+        let backend_url = self.backend_url.clone();
+        set_backend_url(
+            url,
+            Callback::from(move |url: String| {
+                store_backend_url(url.clone());
+                backend_url.set(url);
+            }),
+        );
+    }
+
+    /// Items compared in Realtime for `champion_id`.
+    pub fn get_simulated_items(&self, champion_id: &str) -> Vec<usize> {
+        simulated_items_for(Some(champion_id))
+    }
+
+    /// Persists the items compared for `champion_id`. An empty list restores
+    /// the default. `on_changed` runs once the new list is in effect.
+    pub fn change_simulated_items(
+        &self,
+        champion_id: String,
+        items: Vec<usize>,
+        on_changed: Callback<()>,
+    ) {
+        let simulated_items = self.simulated_items.clone();
+        let mut updated = (**simulated_items).clone();
+        if items.is_empty() {
+            updated.remove(&champion_id);
+        } else {
+            updated.insert(champion_id.clone(), items.clone());
+        }
+        set_simulated_items(
+            champion_id,
+            items,
+            Callback::from(move |_| {
+                store_simulated_items(updated.clone());
+                simulated_items.set(Rc::new(updated.clone()));
+                on_changed.emit(());
+            }),
+        );
+    }
+
     pub fn get_formulas(&self) -> &Rc<RefCell<HashMap<String, APIFormulas>>> {
         &self.static_formulas
     }
@@ -44,7 +99,7 @@ async fn get_static_instance<T>(path_name: &str, state_handle: RcState<T>)
 where
     T: DeserializeOwned,
 {
-    match Request::get(&format!("{}/api/static/{}", BACKEND_URL, path_name))
+    match Request::get(&format!("{}/api/static/{}", backend_url(), path_name))
         .send()
         .await
     {
@@ -52,34 +107,53 @@ where
             Ok(ServerResponse { data, .. }) => {
                 state_handle.set(Rc::new(data));
             }
-            Err(e) => console::log_1(
-                &format!(
-                    "Error decoding {} at get_static_instance: {:#?}",
-                    path_name, e
-                )
-                .into(),
+            Err(e) => error!(
+                "Error decoding {} at get_static_instance: {:?}",
+                path_name, e
             ),
         },
         Err(e) => {
-            console::log_1(&format!("Error sending request for {}: {:#?}", path_name, e).into())
+            error!("Error sending request for {}: {:?}", path_name, e)
         }
     }
 }
 
 #[function_component(CoreProvider)]
 pub fn core_provider(props: &ChildrenProps) -> Html {
+    let backend_url = use_state(|| DEFAULT_BACKEND_URL.to_string());
     let static_champions = use_state(|| Rc::<HashMap<String, String>>::new(HashMap::new()));
     let static_items = use_state(|| Rc::<HashMap<usize, String>>::new(HashMap::new()));
     let static_runes = use_state(|| Rc::<HashMap<usize, String>>::new(HashMap::new()));
+    let simulated_items = use_state(|| Rc::<HashMap<String, Vec<usize>>>::new(HashMap::new()));
     let formulas_cell =
         Rc::<RefCell<HashMap<String, APIFormulas>>>::new(RefCell::new(HashMap::new()));
 
+    {
+        let backend_url = backend_url.clone();
+        use_effect_with((), move |_| {
+            get_backend_url(Callback::from(move |url: String| {
+                store_backend_url(url.clone());
+                backend_url.set(url);
+            }));
+        });
+    }
+
+    {
+        let simulated_items = simulated_items.clone();
+        use_effect_with((), move |_| {
+            get_simulated_items(Callback::from(move |items: HashMap<String, Vec<usize>>| {
+                store_simulated_items(items.clone());
+                simulated_items.set(Rc::new(items));
+            }));
+        });
+    }
+
     {
         let static_champions = static_champions.clone();
         let static_items = static_items.clone();
         let static_runes = static_runes.clone();
 
-        use_effect_with((), move |_| {
+        use_effect_with(backend_url.clone(), move |_| {
             spawn_local(async move {
                 get_static_instance("champions", static_champions).await;
                 get_static_instance("items", static_items).await;
@@ -91,10 +165,12 @@ pub fn core_provider(props: &ChildrenProps) -> Html {
 
     html! {
         <ContextProvider<CoreContext> context={CoreContext {
+            backend_url,
             static_formulas: formulas_cell,
             static_champions,
             static_items,
             static_runes,
+            simulated_items,
         }}>
             { props.children.clone() }
         </ContextProvider<CoreContext>>
@@ -1,117 +1,296 @@
-use std::{cell::RefCell, ops::Deref, rc::Rc};
+use log::{error, info};
+use std::{cell::RefCell, collections::HashMap, ops::Deref, rc::Rc};
 
-use gloo::timers::callback::Interval;
+use gloo::{
+    storage::{LocalStorage, Storage},
+    timers::callback::Interval,
+};
 use reqwasm::http::Request;
-use serde_json::json;
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::{Value, json};
+use serde_wasm_bindgen::Serializer;
+use tutorlolv2_shared::ipc;
 use wasm_bindgen::{JsValue, prelude::wasm_bindgen};
 use wasm_bindgen_futures::spawn_local;
-use web_sys::console;
 use yew::prelude::*;
 
 use crate::{
-    BACKEND_URL, MAX_FAILURES,
-    model::{realtime::Realtime, server::ServerResponse},
+    backend_url,
+    components::stacker::StackInstance,
+    model::{
+        alerts::KillAlertSettings,
+        connection::{ConnectionState, PollRate},
+        connectivity::ProbeResult,
+        error::{self, AppError},
+        hotkey::HotkeyAction,
+        overlay::OverlayState,
+        realtime::Realtime,
+        replay::{RecordingInfo, Replay, ReplayPage},
+        server::ServerResponse,
+        settings::{AppSettings, PollingSettings, ServerSettings, ServerStatus, WindowSettings},
+    },
 };
 
+pub const REALTIME_UPDATE_EVENT: &str = "realtime-update";
+pub const GAME_SESSION_EVENT: &str = "game-session";
+pub const HOTKEY_EVENT: &str = "hotkey";
+pub const OVERLAY_STATE_EVENT: &str = "overlay-state";
+pub const STACK_UPDATE_EVENT: &str = "stack-update";
+pub const NAVIGATE_EVENT: &str = "navigate";
+pub const HELP_EVENT: &str = "show-help";
+pub const OVERLAY_LABEL: &str = "overlay";
+/// Browser builds keep the simulated items in local storage
+const SIMULATED_ITEMS_KEY: &str = "simulated_items";
+/// Frames of a recording read per `load_recording` call
+const REPLAY_PAGE_SIZE: usize = 100;
+
 #[wasm_bindgen(module = "/public/glue.js")]
 unsafe extern "C" {
+    #[wasm_bindgen(js_name = isNativeApp)]
+    pub fn is_native_app() -> bool;
+
+    #[wasm_bindgen(js_name = currentWindowLabel)]
+    pub fn current_window_label() -> String;
+
     #[wasm_bindgen(js_name = invokeGetGameCode)]
     pub async fn invoke_get_game_code() -> JsValue;
 
+    #[wasm_bindgen(js_name = invokeRegenerateGameCode, catch)]
+    pub async fn invoke_regenerate_game_code() -> Result<JsValue, JsValue>;
+
     #[wasm_bindgen(js_name = invokeGetRealtimeGame, catch)]
-    pub async fn invoke_get_realtime_game(game_code: usize) -> Result<JsValue, JsValue>;
+    pub async fn invoke_get_realtime_game(
+        game_code: usize,
+        simulated_items: JsValue,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = invokeGetBackendUrl)]
+    pub async fn invoke_get_backend_url() -> JsValue;
+
+    #[wasm_bindgen(js_name = invokeSetBackendUrl, catch)]
+    pub async fn invoke_set_backend_url(backend_url: &str) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = invokeGetConnectionState)]
+    pub async fn invoke_get_connection_state() -> JsValue;
+
+    #[wasm_bindgen(js_name = invokeGetPollRate)]
+    pub async fn invoke_get_poll_rate() -> JsValue;
+
+    #[wasm_bindgen(js_name = invokeSetPollingSettings, catch)]
+    pub async fn invoke_set_polling_settings(polling: JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = invokeStartPolling, catch)]
+    pub async fn invoke_start_polling(game_code: usize) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = invokeStopPolling, catch)]
+    pub async fn invoke_stop_polling() -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = invokePausePolling, catch)]
+    pub async fn invoke_pause_polling() -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = invokeResumePolling, catch)]
+    pub async fn invoke_resume_polling() -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = invokeGetRecording)]
+    pub async fn invoke_get_recording() -> JsValue;
+
+    #[wasm_bindgen(js_name = invokeSetRecording, catch)]
+    pub async fn invoke_set_recording(enabled: bool) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = invokeGetSimulatedItems)]
+    pub async fn invoke_get_simulated_items() -> JsValue;
+
+    #[wasm_bindgen(js_name = invokeSetSimulatedItems, catch)]
+    pub async fn invoke_set_simulated_items(
+        champion_id: &str,
+        items: JsValue,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = invokeListRecordings, catch)]
+    pub async fn invoke_list_recordings() -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = invokeLoadRecording, catch)]
+    pub async fn invoke_load_recording(
+        name: &str,
+        offset: usize,
+        limit: usize,
+    ) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = invokeToggleOverlay, catch)]
+    pub async fn invoke_toggle_overlay() -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = invokeSetOverlayLocked, catch)]
+    pub async fn invoke_set_overlay_locked(locked: bool) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = invokeSetOverlayBaseTable, catch)]
+    pub async fn invoke_set_overlay_base_table(enabled: bool) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = invokeGetOverlayState)]
+    pub async fn invoke_get_overlay_state() -> JsValue;
+
+    #[wasm_bindgen(js_name = invokeGetStack)]
+    pub async fn invoke_get_stack() -> JsValue;
+
+    #[wasm_bindgen(js_name = invokeSetStack, catch)]
+    pub async fn invoke_set_stack(stack: JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = forwardLog)]
+    pub async fn forward_log(level: u8, message: &str, location: &str);
+
+    #[wasm_bindgen(js_name = invokeExportDiagnostics, catch)]
+    pub async fn invoke_export_diagnostics() -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = invokeRunDiagnostics, catch)]
+    pub async fn invoke_run_diagnostics() -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = invokeGetSettings)]
+    pub async fn invoke_get_settings() -> JsValue;
+
+    #[wasm_bindgen(js_name = invokeSetWindowSettings, catch)]
+    pub async fn invoke_set_window_settings(window_settings: JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = invokeSetSelectedPage, catch)]
+    pub async fn invoke_set_selected_page(page: usize) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = invokeSetHiddenEnemies, catch)]
+    pub async fn invoke_set_hidden_enemies(hidden_enemies: JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = invokeGetServerStatus)]
+    pub async fn invoke_get_server_status() -> JsValue;
+
+    #[wasm_bindgen(js_name = invokeSetServerSettings, catch)]
+    pub async fn invoke_set_server_settings(server_settings: JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = invokeGetHotkeys)]
+    pub async fn invoke_get_hotkeys() -> JsValue;
+
+    #[wasm_bindgen(js_name = invokeSetHotkey, catch)]
+    pub async fn invoke_set_hotkey(action: &str, binding: &str) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = invokeGetKillAlerts)]
+    pub async fn invoke_get_kill_alerts() -> JsValue;
+
+    #[wasm_bindgen(js_name = invokeSetKillAlerts, catch)]
+    pub async fn invoke_set_kill_alerts(kill_alerts: JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = listenEvent)]
+    pub async fn listen_event(event_name: &str, handler: &js_sys::Function) -> JsValue;
+
+    #[wasm_bindgen(js_name = subscribeRealtime)]
+    pub async fn subscribe_realtime(handler: &js_sys::Function) -> JsValue;
+
+    #[wasm_bindgen(js_name = unsubscribeRealtime)]
+    pub async fn unsubscribe_realtime(id: u32);
 }
 
+/// Arguments cross the IPC bridge as JSON, so maps are sent as plain
+/// objects rather than `Map`s.
+fn to_js<T: Serialize + ?Sized>(value: &T) -> Result<JsValue, serde_wasm_bindgen::Error> {
+    value.serialize(&Serializer::json_compatible())
+}
+
+/// Value returned by a command, or `None` outside of the native app where
+/// `invoke` resolves to `undefined`.
+fn from_invoked<T: DeserializeOwned>(value: JsValue, what: &str) -> Option<T> {
+    if value.is_undefined() {
+        return None;
+    }
+    serde_wasm_bindgen::from_value(value)
+        .inspect_err(|e| error!("Failed to parse {}: {}", what, e))
+        .ok()
+}
+
+/// Fetches the game from the backend directly. Used outside of Tauri.
+async fn get_realtime_game_by_code(
+    game_code: usize,
+    simulated_items: &[usize],
+) -> Result<Realtime, AppError> {
+    let api_response = Request::post(&format!("{}/api/games/get_by_code", backend_url()))
+        .header("Content-Type", "application/json")
+        .body(
+            json!({
+                "game_code": game_code,
+                "simulated_items": simulated_items
+            })
+            .to_string(),
+        )
+        .send()
+        .await
+        .map_err(|_| AppError::BackendUnreachable)?;
+
+    let server_response = api_response
+        .json::<ServerResponse<Option<Realtime>>>()
+        .await
+        .map_err(|e| AppError::ParseError(e.to_string()))?;
+
+    server_response.data.ok_or_else(|| {
+        AppError::BackendRejected(
+            server_response
+                .message
+                .unwrap_or("Unknown error".to_string()),
+        )
+    })
+}
+
+/// Fetches the game once, through the native app when available and over
+/// HTTP otherwise.
 pub fn get_realtime_game(
     game_code: usize,
-    game_data: UseStateHandle<Option<Rc<Realtime>>>,
-    counter: Rc<RefCell<usize>>,
+    simulated_items: Vec<usize>,
+    on_result: Callback<Result<Realtime, AppError>>,
 ) {
     spawn_local(async move {
-        match invoke_get_realtime_game(game_code).await {
-            Ok(value) => {
-                if let Some(json_string) = value.as_string() {
-                    if !json_string.is_empty() {
-                        match serde_json::from_str(&json_string) {
-                            Ok(realtime_data) => {
-                                game_data.set(Some(Rc::new(realtime_data)));
-                                *counter.borrow_mut() = 0;
-                            }
-                            Err(e) => {
-                                console::log_1(
-                                    &format!("invoke_get_realtime_game parsing error: {}", e)
-                                        .into(),
-                                );
-                                *counter.borrow_mut() = MAX_FAILURES;
-                            }
-                        }
-                    } else {
-                        console::log_1(
-                            &"Empty string received from Tauri realtime invoker. An unexpected error ocurred".into(),
-                        );
-                        *counter.borrow_mut() = MAX_FAILURES;
-                    }
-                } else {
-                    console::log_1(
-                        &"Empty string received from realtime invoker. Probably user is not using Tauri application".into(),
-                    );
-                    match Request::post(&format!("{}/api/games/get_by_code", BACKEND_URL))
-                        .header("Content-Type", "application/json")
-                        .body(
-                            json!({
-                                "game_code": game_code,
-                                "simulated_items": [3115]
-                            })
-                            .to_string(),
-                        )
-                        .send()
-                        .await
-                    {
-                        Ok(api_response) => match api_response
-                            .json::<ServerResponse<Option<Realtime>>>()
-                            .await
-                        {
-                            Ok(server_response) => {
-                                if let Some(realtime_data) = server_response.data {
-                                    game_data.set(Some(Rc::new(realtime_data)));
-                                    *counter.borrow_mut() = 0;
-                                } else {
-                                    console::log_1(
-                                        &format!(
-                                            "Realtime game not found. Message: {}",
-                                            server_response
-                                                .message
-                                                .unwrap_or("Unknown error".to_string())
-                                        )
-                                        .into(),
-                                    );
-                                    *counter.borrow_mut() = MAX_FAILURES;
-                                }
-                            }
-                            Err(e) => {
-                                console::log_1(&format!("Error while parsing server response at realtime invoker: {:#?}", e).into());
-                                *counter.borrow_mut() = MAX_FAILURES;
-                            }
-                        },
-                        Err(e) => {
-                            console::log_1(
-                                &format!(
-                                    "error when trying to get realtime game by code directly: {}",
-                                    e
-                                )
-                                .into(),
-                            );
-                            *counter.borrow_mut() = MAX_FAILURES;
-                        }
-                    }
-                }
-            }
-            Err(e) => {
-                console::log_1(
-                    &format!("unsafe extern 'C' from WASM bindgen failed: {:#?}", e).into(),
-                );
-                *counter.borrow_mut() = MAX_FAILURES;
+        let Ok(items) = to_js(&simulated_items) else {
+            return;
+        };
+        let result = match invoke_get_realtime_game(game_code, items).await {
+            Ok(value) if value.is_undefined() => {
+                get_realtime_game_by_code(game_code, &simulated_items).await
             }
+            Ok(value) => ipc::decode::<Realtime>(&js_sys::Uint8Array::new(&value).to_vec())
+                .map_err(|e| AppError::ParseError(e.to_string())),
+            Err(e) => Err(error::from_invoke(e)),
+        };
+        on_result.emit(result);
+    });
+}
+
+/// State of the native poller, used to catch up after the page remounts.
+pub fn get_connection_state(on_resolved: Callback<ConnectionState>) {
+    spawn_local(async move {
+        if let Some(state) =
+            from_invoked(invoke_get_connection_state().await, "the connection state")
+        {
+            on_resolved.emit(state);
+        }
+    });
+}
+
+/// Rate of the native poller while a game is live. Nothing is emitted in
+/// the browser.
+pub fn get_poll_rate(on_resolved: Callback<Option<PollRate>>) {
+    spawn_local(async move {
+        if let Some(rate) = from_invoked(invoke_get_poll_rate().await, "the poll rate") {
+            on_resolved.emit(rate);
+        }
+    });
+}
+
+pub fn set_polling_settings(polling: PollingSettings, on_saved: Callback<PollingSettings>) {
+    let Ok(polling) = to_js(&polling) else {
+        return;
+    };
+    spawn_local(async move {
+        let result = invoke_set_polling_settings(polling)
+            .await
+            .map_err(error::from_invoke)
+            .and_then(|value| {
+                serde_wasm_bindgen::from_value(value)
+                    .map_err(|e| AppError::ParseError(e.to_string()))
+            });
+        match result {
+            Ok(saved) => on_saved.emit(saved),
+            Err(e) => error!("Failed to save the poll rate bounds: {}", e),
         }
     });
 }
@@ -120,9 +299,441 @@ pub fn get_code(game_code: UseStateHandle<usize>) {
     spawn_local(async move {
         let code = invoke_get_game_code().await;
         if code.is_undefined() {
-            console::log_1(&"O aplicativo nativo não está em uso".into());
+            info!("The native app is not in use");
         } else {
             game_code.set(code.as_f64().unwrap_or_default() as usize);
         }
     });
 }
+
+/// Asks the native app for a fresh game code. The new code arrives through
+/// the `game-session` event like any other code change.
+pub fn regenerate_game_code() {
+    spawn_local(async move {
+        if let Err(e) = invoke_regenerate_game_code().await {
+            error!(
+                "Failed to regenerate the game code: {}",
+                error::from_invoke(e)
+            );
+        }
+    });
+}
+
+/// Asks the native app which backend it resolved at startup. Outside of Tauri
+/// the default url is kept.
+pub fn get_backend_url(on_resolved: Callback<String>) {
+    spawn_local(async move {
+        if let Some(url) = invoke_get_backend_url().await.as_string() {
+            on_resolved.emit(url);
+        }
+    });
+}
+
+/// Persists a new backend url through the native app. The browser build has
+/// nowhere to persist it, so the url is used as typed for this session only.
+pub fn set_backend_url(backend_url: String, on_changed: Callback<String>) {
+    spawn_local(async move {
+        match invoke_set_backend_url(&backend_url).await {
+            Ok(value) => on_changed.emit(value.as_string().unwrap_or(backend_url)),
+            Err(e) => error!(
+                "Failed to change backend url to {}: {}",
+                backend_url,
+                error::from_invoke(e)
+            ),
+        }
+    });
+}
+
+/// Hands polling over to the native app, which emits `realtime-update` events
+/// for every response instead of being asked once per tick.
+pub fn start_polling(game_code: usize) {
+    spawn_local(async move {
+        if let Err(e) = invoke_start_polling(game_code).await {
+            error!(
+                "Failed to start polling game {}: {}",
+                game_code,
+                error::from_invoke(e)
+            );
+        }
+    });
+}
+
+pub fn stop_polling() {
+    spawn_local(async move {
+        if let Err(e) = invoke_stop_polling().await {
+            error!("Failed to stop polling: {}", error::from_invoke(e));
+        }
+    });
+}
+
+pub fn set_polling_paused(paused: bool) {
+    spawn_local(async move {
+        let result = if paused {
+            invoke_pause_polling().await
+        } else {
+            invoke_resume_polling().await
+        };
+        if let Err(e) = result {
+            error!(
+                "Failed to change polling pause to {}: {}",
+                paused,
+                error::from_invoke(e)
+            );
+        }
+    });
+}
+
+/// Whether the native app records fetched games to disk. Nothing is emitted
+/// outside of Tauri.
+pub fn get_recording(on_resolved: Callback<bool>) {
+    spawn_local(async move {
+        if let Some(enabled) = invoke_get_recording().await.as_bool() {
+            on_resolved.emit(enabled);
+        }
+    });
+}
+
+pub fn set_recording(enabled: bool, on_changed: Callback<bool>) {
+    spawn_local(async move {
+        match invoke_set_recording(enabled).await {
+            Ok(value) => on_changed.emit(value.as_bool().unwrap_or(enabled)),
+            Err(e) => error!(
+                "Failed to change recording to {}: {}",
+                enabled,
+                error::from_invoke(e)
+            ),
+        }
+    });
+}
+
+/// Items compared in Realtime, keyed by champion id. Read from the native
+/// settings or from local storage in the browser.
+pub fn get_simulated_items(on_resolved: Callback<HashMap<String, Vec<usize>>>) {
+    spawn_local(async move {
+        if !is_native_app() {
+            on_resolved.emit(LocalStorage::get(SIMULATED_ITEMS_KEY).unwrap_or_default());
+            return;
+        }
+        if let Some(simulated_items) =
+            from_invoked(invoke_get_simulated_items().await, "the simulated items")
+        {
+            on_resolved.emit(simulated_items);
+        }
+    });
+}
+
+/// Saves the items compared for `champion_id`. An empty list restores the
+/// default. `on_saved` runs once the change was persisted.
+pub fn set_simulated_items(champion_id: String, items: Vec<usize>, on_saved: Callback<()>) {
+    spawn_local(async move {
+        if !is_native_app() {
+            let mut simulated_items: HashMap<String, Vec<usize>> =
+                LocalStorage::get(SIMULATED_ITEMS_KEY).unwrap_or_default();
+            if items.is_empty() {
+                simulated_items.remove(&champion_id);
+            } else {
+                simulated_items.insert(champion_id, items);
+            }
+            match LocalStorage::set(SIMULATED_ITEMS_KEY, simulated_items) {
+                Ok(()) => on_saved.emit(()),
+                Err(e) => {
+                    error!("Failed to store the simulated items: {}", e)
+                }
+            }
+            return;
+        }
+        let Ok(items) = to_js(&items) else {
+            return;
+        };
+        match invoke_set_simulated_items(&champion_id, items).await {
+            Ok(_) => on_saved.emit(()),
+            Err(e) => error!(
+                "Failed to change the simulated items of {}: {}",
+                champion_id,
+                error::from_invoke(e)
+            ),
+        }
+    });
+}
+
+pub fn list_recordings(on_listed: Callback<Vec<RecordingInfo>>) {
+    spawn_local(async move {
+        match invoke_list_recordings().await {
+            Ok(value) => {
+                if let Some(recordings) = from_invoked(value, "the recordings list") {
+                    on_listed.emit(recordings);
+                }
+            }
+            Err(e) => error!("Failed to list recordings: {}", error::from_invoke(e)),
+        }
+    });
+}
+
+/// Reads the frames of a recording from the app data folder a page at a
+/// time and builds a replay from them.
+pub fn load_recording(name: String, on_loaded: Callback<Replay>) {
+    spawn_local(async move {
+        let mut frames = Vec::new();
+        let mut offset = Some(0);
+        while let Some(page_offset) = offset {
+            let page = match invoke_load_recording(&name, page_offset, REPLAY_PAGE_SIZE).await {
+                Ok(value) => ipc::decode::<ReplayPage>(&js_sys::Uint8Array::new(&value).to_vec()),
+                Err(e) => {
+                    error!(
+                        "Failed to load recording {}: {}",
+                        name,
+                        error::from_invoke(e)
+                    );
+                    return;
+                }
+            };
+            match page {
+                Ok(page) => {
+                    frames.extend(page.frames);
+                    offset = page.next_offset;
+                }
+                Err(e) => {
+                    error!("Failed to decode recording {}: {}", name, e);
+                    return;
+                }
+            }
+        }
+        match Replay::from_frames(name, frames) {
+            Ok(replay) => on_loaded.emit(replay),
+            Err(e) => error!("{}", e),
+        }
+    });
+}
+
+/// Whether this page runs inside the overlay window of the native app.
+pub fn is_overlay_window() -> bool {
+    current_window_label() == OVERLAY_LABEL
+}
+
+pub fn toggle_overlay() {
+    spawn_local(async move {
+        if let Err(e) = invoke_toggle_overlay().await {
+            error!("Failed to toggle the overlay: {}", error::from_invoke(e));
+        }
+    });
+}
+
+pub fn set_overlay_locked(locked: bool) {
+    spawn_local(async move {
+        if let Err(e) = invoke_set_overlay_locked(locked).await {
+            error!(
+                "Failed to change the overlay lock: {}",
+                error::from_invoke(e)
+            );
+        }
+    });
+}
+
+pub fn set_overlay_base_table(enabled: bool) {
+    spawn_local(async move {
+        if let Err(e) = invoke_set_overlay_base_table(enabled).await {
+            error!(
+                "Failed to change the overlay table: {}",
+                error::from_invoke(e)
+            );
+        }
+    });
+}
+
+/// Current overlay state. Changes afterwards arrive through `overlay-state`.
+pub fn get_overlay_state(on_resolved: Callback<OverlayState>) {
+    spawn_local(async move {
+        if let Some(state) = from_invoked(invoke_get_overlay_state().await, "the overlay state") {
+            on_resolved.emit(state);
+        }
+    });
+}
+
+/// Damage stack last shared by the Realtime page. Changes afterwards arrive
+/// through `stack-update`.
+pub fn get_stack(on_resolved: Callback<Vec<StackInstance>>) {
+    spawn_local(async move {
+        if let Some(stack) = from_invoked(invoke_get_stack().await, "the shared stack") {
+            on_resolved.emit(stack);
+        }
+    });
+}
+
+pub fn share_stack(stack: &[StackInstance]) {
+    let Ok(stack) = to_js(stack) else {
+        return;
+    };
+    spawn_local(async move {
+        if let Err(e) = invoke_set_stack(stack).await {
+            error!("Failed to share the stack: {}", error::from_invoke(e));
+        }
+    });
+}
+
+/// Sends where the diagnostics zip was written, or why it failed.
+pub fn export_diagnostics(on_result: Callback<Result<String, AppError>>) {
+    spawn_local(async move {
+        let result = invoke_export_diagnostics()
+            .await
+            .map(|value| value.as_string().unwrap_or_default())
+            .map_err(error::from_invoke);
+        if let Err(e) = &result {
+            error!("Failed to export diagnostics: {}", e);
+        }
+        on_result.emit(result);
+    });
+}
+
+/// Sends one result per probed endpoint, or why the probes could not run.
+pub fn run_diagnostics(on_result: Callback<Result<Vec<ProbeResult>, AppError>>) {
+    spawn_local(async move {
+        let result = invoke_run_diagnostics()
+            .await
+            .map_err(error::from_invoke)
+            .and_then(|value| {
+                serde_wasm_bindgen::from_value::<Vec<ProbeResult>>(value)
+                    .map_err(|e| AppError::ParseError(e.to_string()))
+            });
+        if let Err(e) = &result {
+            error!("Failed to run diagnostics: {}", e);
+        }
+        on_result.emit(result);
+    });
+}
+
+/// Saved settings of the native app. Nothing is emitted in the browser, where
+/// the defaults apply.
+pub fn get_settings(on_resolved: Callback<AppSettings>) {
+    spawn_local(async move {
+        if let Some(settings) = from_invoked(invoke_get_settings().await, "the settings") {
+            on_resolved.emit(settings);
+        }
+    });
+}
+
+pub fn set_window_settings(window_settings: WindowSettings, on_saved: Callback<WindowSettings>) {
+    let Ok(window_settings) = to_js(&window_settings) else {
+        return;
+    };
+    spawn_local(async move {
+        let result = invoke_set_window_settings(window_settings)
+            .await
+            .map_err(error::from_invoke)
+            .and_then(|value| {
+                serde_wasm_bindgen::from_value(value)
+                    .map_err(|e| AppError::ParseError(e.to_string()))
+            });
+        match result {
+            Ok(saved) => on_saved.emit(saved),
+            Err(e) => error!("Failed to save the window settings: {}", e),
+        }
+    });
+}
+
+pub fn set_selected_page(page: usize) {
+    spawn_local(async move {
+        if let Err(e) = invoke_set_selected_page(page).await {
+            error!(
+                "Failed to save the selected page: {}",
+                error::from_invoke(e)
+            );
+        }
+    });
+}
+
+pub fn set_hidden_enemies(hidden_enemies: &[usize]) {
+    let Ok(hidden_enemies) = to_js(hidden_enemies) else {
+        return;
+    };
+    spawn_local(async move {
+        if let Err(e) = invoke_set_hidden_enemies(hidden_enemies).await {
+            error!(
+                "Failed to save the hidden enemies: {}",
+                error::from_invoke(e)
+            );
+        }
+    });
+}
+
+pub fn get_server_status(on_resolved: Callback<ServerStatus>) {
+    spawn_local(async move {
+        if let Some(status) = from_invoked(invoke_get_server_status().await, "the server status") {
+            on_resolved.emit(status);
+        }
+    });
+}
+
+/// Sends the status of the restarted server, or why it could not start.
+pub fn set_server_settings(
+    server_settings: ServerSettings,
+    on_result: Callback<Result<ServerStatus, AppError>>,
+) {
+    let Ok(server_settings) = to_js(&server_settings) else {
+        return;
+    };
+    spawn_local(async move {
+        let result = invoke_set_server_settings(server_settings)
+            .await
+            .map_err(error::from_invoke)
+            .and_then(|value| {
+                serde_wasm_bindgen::from_value(value)
+                    .map_err(|e| AppError::ParseError(e.to_string()))
+            });
+        on_result.emit(result);
+    });
+}
+
+/// Configured hotkeys, keyed by action. Nothing is emitted in the browser.
+pub fn get_hotkeys(on_resolved: Callback<HashMap<HotkeyAction, String>>) {
+    spawn_local(async move {
+        if let Some(hotkeys) = from_invoked(invoke_get_hotkeys().await, "the hotkeys") {
+            on_resolved.emit(hotkeys);
+        }
+    });
+}
+
+/// Sends the binding as it was saved, or the reason it was rejected.
+pub fn set_hotkey(
+    action: HotkeyAction,
+    binding: String,
+    on_result: Callback<Result<String, AppError>>,
+) {
+    let Ok(Value::String(action)) = serde_json::to_value(action) else {
+        return;
+    };
+    spawn_local(async move {
+        let result = invoke_set_hotkey(&action, &binding)
+            .await
+            .map(|value| value.as_string().unwrap_or_default())
+            .map_err(error::from_invoke);
+        on_result.emit(result);
+    });
+}
+
+/// Kill alerts only exist in the native app, nothing is emitted elsewhere.
+pub fn get_kill_alerts(on_resolved: Callback<KillAlertSettings>) {
+    spawn_local(async move {
+        if let Some(settings) = from_invoked(invoke_get_kill_alerts().await, "the kill alerts") {
+            on_resolved.emit(settings);
+        }
+    });
+}
+
+pub fn set_kill_alerts(kill_alerts: KillAlertSettings, on_saved: Callback<KillAlertSettings>) {
+    let Ok(kill_alerts) = to_js(&kill_alerts) else {
+        return;
+    };
+    spawn_local(async move {
+        let result = invoke_set_kill_alerts(kill_alerts)
+            .await
+            .map_err(error::from_invoke)
+            .and_then(|value| {
+                serde_wasm_bindgen::from_value(value)
+                    .map_err(|e| AppError::ParseError(e.to_string()))
+            });
+        match result {
+            Ok(saved) => on_saved.emit(saved),
+            Err(e) => error!("Failed to save the kill alerts: {}", e),
+        }
+    });
+}
@@ -1 +1,4 @@
 pub mod mouseout_click;
+pub mod realtime_channel;
+pub mod realtime_feed;
+pub mod tauri_event;
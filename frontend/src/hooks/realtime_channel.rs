@@ -0,0 +1,82 @@
+use log::error;
+use std::{cell::RefCell, rc::Rc};
+
+use js_sys::Uint8Array;
+use tutorlolv2_shared::ipc;
+use wasm_bindgen::{JsCast, prelude::Closure};
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+use crate::{
+    externcalls::invokers::{subscribe_realtime, unsubscribe_realtime},
+    model::realtime::Realtime,
+};
+
+struct Subscription {
+    handler: Option<Closure<dyn FnMut(Uint8Array)>>,
+    id: Option<u32>,
+    disposed: bool,
+}
+
+impl Subscription {
+    /// The handler is dropped only after the native side stopped sending,
+    /// so a message already on its way never reaches a freed closure.
+    fn close(&mut self) {
+        let (Some(id), Some(handler)) = (self.id.take(), self.handler.take()) else {
+            return;
+        };
+        spawn_local(async move {
+            unsubscribe_realtime(id).await;
+            drop(handler);
+        });
+    }
+}
+
+/// Hook receiving every `Realtime` fetched by the native poller while the
+/// component is mounted. The payloads arrive as MessagePack and are decoded
+/// straight into `Realtime`. Outside of the native app nothing is received.
+#[hook]
+pub fn use_realtime_channel(callback: Callback<Realtime>) {
+    // Always dispatch to the callback from the latest render
+    let latest_callback = use_mut_ref(|| callback.clone());
+    *latest_callback.borrow_mut() = callback;
+
+    use_effect_with((), move |_| {
+        let handler = Closure::<dyn FnMut(Uint8Array)>::new(move |payload: Uint8Array| {
+            match ipc::decode::<Realtime>(&payload.to_vec()) {
+                Ok(realtime) => latest_callback.borrow().emit(realtime),
+                Err(e) => error!("Failed to decode a realtime update: {}", e),
+            }
+        });
+        let handler_fn = handler.as_ref().unchecked_ref::<js_sys::Function>().clone();
+
+        let subscription = Rc::new(RefCell::new(Subscription {
+            handler: Some(handler),
+            id: None,
+            disposed: false,
+        }));
+
+        {
+            let subscription = subscription.clone();
+            spawn_local(async move {
+                let id = subscribe_realtime(&handler_fn).await.as_f64();
+                let mut subscription = subscription.borrow_mut();
+                match id {
+                    Some(id) => {
+                        subscription.id = Some(id as u32);
+                        if subscription.disposed {
+                            subscription.close();
+                        }
+                    }
+                    None => subscription.handler = None,
+                }
+            });
+        }
+
+        move || {
+            let mut subscription = subscription.borrow_mut();
+            subscription.disposed = true;
+            subscription.close();
+        }
+    });
+}
@@ -0,0 +1,214 @@
+use std::{cell::RefCell, rc::Rc};
+
+use gloo::timers::callback::Timeout;
+use yew::prelude::*;
+
+use crate::{
+    externcalls::invokers::{
+        REALTIME_UPDATE_EVENT, get_connection_state, get_poll_rate, get_realtime_game,
+        is_native_app, set_polling_paused, start_polling, stop_polling,
+    },
+    hooks::{realtime_channel::use_realtime_channel, tauri_event::use_tauri_event},
+    model::{
+        connection::{ConnectionState, PollRate},
+        realtime::{Realtime, RealtimeUpdate},
+    },
+    simulated_items_for,
+};
+
+pub const BROWSER_POLL_INTERVAL_MS: u64 = 1000;
+
+/// Timer chain used outside of Tauri. Bumping the generation makes any
+/// request still in flight drop its result instead of scheduling another.
+#[derive(Default)]
+struct BrowserPoller {
+    generation: u64,
+    timer: Option<Timeout>,
+    /// Champion of the last response, used to pick the simulated items
+    champion_id: Option<String>,
+}
+
+/// Latest `Realtime` of a game together with the state of the connection
+/// that feeds it.
+#[derive(Clone)]
+pub struct RealtimeFeed {
+    pub data: UseStateHandle<Option<Rc<Realtime>>>,
+    pub connection: UseStateHandle<ConnectionState>,
+    pub paused: UseStateHandle<bool>,
+    /// Adapted rate of the native poller while the game is live
+    pub rate: UseStateHandle<Option<PollRate>>,
+    game_code: Rc<RefCell<usize>>,
+    browser: Rc<RefCell<BrowserPoller>>,
+}
+
+impl RealtimeFeed {
+    pub fn is_running(&self) -> bool {
+        !self.connection.is_stopped()
+    }
+
+    /// Inside the native app the poller runs on the Rust side and pushes
+    /// its responses through a channel and its state through
+    /// `realtime-update`. The browser build polls over HTTP.
+    pub fn start(&self, game_code: usize) {
+        if game_code.to_string().len() != 6 {
+            return;
+        }
+        *self.game_code.borrow_mut() = game_code;
+        self.browser.borrow_mut().champion_id = None;
+        self.paused.set(false);
+        self.connection.set(ConnectionState::WaitingForGame);
+        if is_native_app() {
+            start_polling(game_code);
+        } else {
+            self.schedule(ConnectionState::WaitingForGame, 0);
+        }
+    }
+
+    pub fn stop(&self) {
+        self.connection.set(ConnectionState::Idle);
+        if is_native_app() {
+            stop_polling();
+        } else {
+            self.cancel_browser_poller();
+        }
+    }
+
+    pub fn set_paused(&self, paused: bool) {
+        self.paused.set(paused);
+        if is_native_app() {
+            set_polling_paused(paused);
+        } else if paused {
+            self.cancel_browser_poller();
+        } else if self.is_running() {
+            self.schedule((*self.connection).clone(), 0);
+        }
+    }
+
+    /// Fetches again right away, such as after the simulated items changed.
+    /// The native poller is refreshed by the command that changed them.
+    pub fn refresh(&self) {
+        if !is_native_app() && self.is_running() && !*self.paused {
+            self.schedule((*self.connection).clone(), 0);
+        }
+    }
+
+    /// Shows a frame that did not come from the feed, such as a replay.
+    pub fn show(&self, frame: Option<Rc<Realtime>>) {
+        self.data.set(frame);
+    }
+
+    fn cancel_browser_poller(&self) {
+        let mut browser = self.browser.borrow_mut();
+        browser.generation += 1;
+        browser.timer = None;
+    }
+
+    fn schedule(&self, state: ConnectionState, delay_ms: u64) {
+        let generation = {
+            let mut browser = self.browser.borrow_mut();
+            browser.generation += 1;
+            browser.generation
+        };
+        self.schedule_next(generation, state, delay_ms);
+    }
+
+    fn schedule_next(&self, generation: u64, state: ConnectionState, delay_ms: u64) {
+        let feed = self.clone();
+        let game_code = *self.game_code.borrow();
+        let timeout = Timeout::new(delay_ms as u32, move || {
+            let feed = feed.clone();
+            let simulated_items = simulated_items_for(feed.browser.borrow().champion_id.as_deref());
+            get_realtime_game(
+                game_code,
+                simulated_items,
+                Callback::from(move |result: Result<Realtime, _>| {
+                    if feed.browser.borrow().generation != generation {
+                        return;
+                    }
+                    let (next_state, delay_ms) =
+                        state.next(result.as_ref().map(|_| ()), BROWSER_POLL_INTERVAL_MS);
+                    if let Ok(realtime_data) = result {
+                        feed.browser.borrow_mut().champion_id =
+                            Some(realtime_data.current_player.champion_id.clone());
+                        feed.data.set(Some(Rc::new(realtime_data)));
+                    }
+                    feed.connection.set(next_state.clone());
+                    feed.schedule_next(generation, next_state, delay_ms);
+                }),
+            );
+        });
+        self.browser.borrow_mut().timer = Some(timeout);
+    }
+}
+
+/// Hook owning the realtime feed of a component. Polling is stopped when the
+/// component unmounts.
+#[hook]
+pub fn use_realtime_feed() -> RealtimeFeed {
+    use_feed(true)
+}
+
+/// Hook showing the feed owned by another window, such as the overlay
+/// following the Realtime page. It never starts or stops the poller.
+#[hook]
+pub fn use_followed_realtime_feed() -> RealtimeFeed {
+    use_feed(false)
+}
+
+#[hook]
+fn use_feed(owns_poller: bool) -> RealtimeFeed {
+    let feed = RealtimeFeed {
+        data: use_state(|| Option::<Rc<Realtime>>::None),
+        connection: use_state(|| ConnectionState::Idle),
+        paused: use_state(|| false),
+        rate: use_state(|| Option::<PollRate>::None),
+        game_code: use_mut_ref(|| 0usize),
+        browser: use_mut_ref(BrowserPoller::default),
+    };
+
+    {
+        let feed = feed.clone();
+        use_realtime_channel(Callback::from(move |realtime_data: Realtime| {
+            // A late update must not overwrite a stopped feed or a replay
+            if feed.is_running() {
+                feed.data.set(Some(Rc::new(realtime_data)));
+            }
+        }));
+    }
+
+    {
+        let feed = feed.clone();
+        use_tauri_event(
+            REALTIME_UPDATE_EVENT,
+            Callback::from(move |update: RealtimeUpdate| match update {
+                RealtimeUpdate::State(state) => feed.connection.set(state),
+                RealtimeUpdate::Paused(paused) => feed.paused.set(paused),
+                RealtimeUpdate::Rate(rate) => feed.rate.set(rate),
+            }),
+        );
+    }
+
+    {
+        let feed = feed.clone();
+        use_effect_with((), move |_| {
+            if is_native_app() {
+                let connection = feed.connection.clone();
+                get_connection_state(Callback::from(move |state| connection.set(state)));
+                let rate = feed.rate.clone();
+                get_poll_rate(Callback::from(move |current| rate.set(current)));
+            }
+            move || {
+                if !owns_poller {
+                    return;
+                }
+                if is_native_app() {
+                    stop_polling();
+                } else {
+                    feed.cancel_browser_poller();
+                }
+            }
+        });
+    }
+
+    feed
+}
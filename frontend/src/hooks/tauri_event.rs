@@ -0,0 +1,68 @@
+use log::error;
+use std::{cell::RefCell, rc::Rc};
+
+use serde::de::DeserializeOwned;
+use wasm_bindgen::{JsCast, JsValue, prelude::Closure};
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+use crate::externcalls::invokers::listen_event;
+
+struct Listener {
+    handler: Option<Closure<dyn FnMut(JsValue)>>,
+    unlisten: Option<js_sys::Function>,
+    disposed: bool,
+}
+
+/// Hook that listens to an event emitted by Tauri while the component is mounted.
+/// Outside of the native app no event is ever received.
+#[hook]
+pub fn use_tauri_event<T>(event_name: &'static str, callback: Callback<T>)
+where
+    T: DeserializeOwned + 'static,
+{
+    // Always dispatch to the callback from the latest render
+    let latest_callback = use_mut_ref(|| callback.clone());
+    *latest_callback.borrow_mut() = callback;
+
+    use_effect_with(event_name, move |&event_name| {
+        let handler = Closure::<dyn FnMut(JsValue)>::new(move |payload: JsValue| {
+            match serde_wasm_bindgen::from_value::<T>(payload) {
+                Ok(value) => latest_callback.borrow().emit(value),
+                Err(e) => error!("Failed to parse payload of event {}: {}", event_name, e),
+            }
+        });
+        let handler_fn = handler.as_ref().unchecked_ref::<js_sys::Function>().clone();
+
+        let listener = Rc::new(RefCell::new(Listener {
+            handler: Some(handler),
+            unlisten: None,
+            disposed: false,
+        }));
+
+        {
+            let listener = listener.clone();
+            spawn_local(async move {
+                let unlisten = listen_event(event_name, &handler_fn).await;
+                let mut listener = listener.borrow_mut();
+                match unlisten.dyn_into::<js_sys::Function>() {
+                    Ok(unlisten) if listener.disposed => {
+                        let _ = unlisten.call0(&JsValue::NULL);
+                        listener.handler = None;
+                    }
+                    Ok(unlisten) => listener.unlisten = Some(unlisten),
+                    Err(_) => listener.handler = None,
+                }
+            });
+        }
+
+        move || {
+            let mut listener = listener.borrow_mut();
+            listener.disposed = true;
+            if let Some(unlisten) = listener.unlisten.take() {
+                let _ = unlisten.call0(&JsValue::NULL);
+                listener.handler = None;
+            }
+        }
+    });
+}
@@ -0,0 +1,50 @@
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use wasm_bindgen_futures::spawn_local;
+use web_sys::console;
+
+use crate::externcalls::invokers::{forward_log, is_native_app};
+
+/// Writes to the browser console and, inside the native app, forwards every
+/// record to the backend so it ends up in the same log file.
+struct Logger;
+
+static LOGGER: Logger = Logger;
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let message = record.args().to_string();
+        let line = format!("[{}] {}: {}", record.level(), record.target(), message);
+        match record.level() {
+            Level::Error => console::error_1(&line.into()),
+            Level::Warn => console::warn_1(&line.into()),
+            _ => console::log_1(&line.into()),
+        }
+
+        if is_native_app() {
+            // The backend numbers levels from trace (1) up to error (5)
+            let level = 6 - record.level() as u8;
+            let location = record.target().to_string();
+            spawn_local(async move { forward_log(level, &message, &location).await });
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+pub fn init() {
+    let level = if cfg!(debug_assertions) {
+        LevelFilter::Debug
+    } else {
+        LevelFilter::Info
+    };
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(level);
+    }
+}
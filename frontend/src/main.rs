@@ -6,46 +6,154 @@ mod context;
 mod externcalls;
 mod hooks;
 mod img;
+mod logger;
 mod macros;
 mod model;
 mod pages;
 
-use std::{ops::Deref, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, ops::Deref, rc::Rc};
+
+use log::{info, warn};
 
 use context::core::CoreProvider;
 use pages::{
     about::about, calculator::CalculatorDisplay, formulas::Formulas, github::github,
-    realtime::RealtimeDisplay,
+    overlay::Overlay, realtime::RealtimeDisplay, settings::settings,
 };
 
-use components::sidebar::Sidebar;
-use externcalls::invokers::get_code;
-use model::realtime::Realtime;
+use components::{
+    keybindings_help::KeybindingsHelp, sidebar::Sidebar,
+    window_settings::apply_background_opacity,
+};
+use externcalls::invokers::{
+    GAME_SESSION_EVENT, HOTKEY_EVENT, NAVIGATE_EVENT, get_code, get_settings, is_overlay_window,
+    set_selected_page,
+};
+use hooks::tauri_event::use_tauri_event;
+use model::{
+    hotkey::HotkeyAction, realtime::Realtime, session::SessionEvent, settings::AppSettings,
+};
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
 use crate::pages::dashboard::dashboard;
 
-// pub const BACKEND_URL: &str = "http://localhost:8082";
-pub const BACKEND_URL: &str = "https://v2.tutorlol.com";
-pub const MAX_FAILURES: usize = 10;
+pub const DEFAULT_BACKEND_URL: &str = "https://v2.tutorlol.com";
+/// Pages rendered by `App`, cycled through by the switch page hotkey
+pub const PAGE_COUNT: usize = 7;
+pub const SETTINGS_PAGE: usize = 6;
+/// Nashor's Tooth, compared when no items were chosen for the champion
+pub const DEFAULT_SIMULATED_ITEMS: [usize; 1] = [3115];
+
+thread_local! {
+    static BACKEND_URL: RefCell<String> = RefCell::new(DEFAULT_BACKEND_URL.to_string());
+    static SIMULATED_ITEMS: RefCell<HashMap<String, Vec<usize>>> = RefCell::new(HashMap::new());
+}
+
+/// Base url of the TutorLoL server. Resolved at runtime by the native app and
+/// kept in sync with `CoreContext::backend_url`.
+pub fn backend_url() -> String {
+    BACKEND_URL.with_borrow(|url| url.clone())
+}
+
+pub fn store_backend_url(url: String) {
+    BACKEND_URL.set(url);
+}
+
+/// Items compared for `champion_id`, kept in sync with
+/// `CoreContext::simulated_items`. Falls back to the default list while the
+/// champion is unknown or has none chosen.
+pub fn simulated_items_for(champion_id: Option<&str>) -> Vec<usize> {
+    SIMULATED_ITEMS.with_borrow(|simulated_items| {
+        champion_id
+            .and_then(|champion_id| simulated_items.get(champion_id))
+            .filter(|items| !items.is_empty())
+            .cloned()
+            .unwrap_or_else(|| DEFAULT_SIMULATED_ITEMS.to_vec())
+    })
+}
+
+pub fn store_simulated_items(simulated_items: HashMap<String, Vec<usize>>) {
+    SIMULATED_ITEMS.set(simulated_items);
+}
 
 #[function_component(App)]
 fn app() -> Html {
     let game_code = use_state(|| 0usize);
     let selected_page = use_state(|| 1usize);
+    // The page is only saved once the one from the last session was restored
+    let settings_loaded = use_mut_ref(|| false);
 
     {
         let game_code = game_code.clone();
+        let selected_page = selected_page.clone();
+        let settings_loaded = settings_loaded.clone();
         use_effect_with((), move |_| {
             get_code(game_code);
+            get_settings(Callback::from(move |settings: AppSettings| {
+                *settings_loaded.borrow_mut() = true;
+                apply_background_opacity(settings.window.background_opacity);
+                if settings.ui.selected_page < PAGE_COUNT {
+                    selected_page.set(settings.ui.selected_page);
+                }
+            }));
         })
     };
 
+    use_effect_with(*selected_page, move |&page| {
+        if *settings_loaded.borrow() {
+            set_selected_page(page);
+        }
+    });
+
+    {
+        // A code typed in to watch someone else's game is left alone
+        let game_code = game_code.clone();
+        use_tauri_event(
+            GAME_SESSION_EVENT,
+            Callback::from(move |event: SessionEvent| {
+                info!("Game session: {:?}", event);
+                match event {
+                    SessionEvent::CodeChanged {
+                        game_code: new_code,
+                        previous,
+                    } if *game_code == 0 || Some(*game_code) == previous => {
+                        game_code.set(new_code)
+                    }
+                    _ => {}
+                }
+            }),
+        );
+    }
+
+    {
+        let selected_page = selected_page.clone();
+        use_tauri_event(
+            HOTKEY_EVENT,
+            Callback::from(move |action: HotkeyAction| {
+                if action == HotkeyAction::SwitchPage {
+                    selected_page.set((*selected_page + 1) % PAGE_COUNT);
+                }
+            }),
+        );
+    }
+
+    {
+        let selected_page = selected_page.clone();
+        use_tauri_event(
+            NAVIGATE_EVENT,
+            Callback::from(move |page: String| match page.as_str() {
+                "settings" => selected_page.set(SETTINGS_PAGE),
+                _ => warn!("Unknown page: {}", page),
+            }),
+        );
+    }
+
     html! {
         <CoreProvider>
             <div class={"grid grid-cols-[auto_1fr]"}>
                 <Sidebar state_handler={selected_page.clone()} />
+                <KeybindingsHelp />
                 {
                     match *selected_page {
                         0 => html! { dashboard() },
@@ -54,6 +162,7 @@ fn app() -> Html {
                         3 => html! { about() },
                         4 => html! { <Formulas /> },
                         5 => html! { github() },
+                        6 => html! { settings() },
                         _ => html! {
                             <div class={"flex flex-col gap-12 max-h-screen overflow-y-auto p-12"}>
                                 <h1 class={"font-bold text-4xl text-white"}>{ "Invalid page [404]" }</h1>
@@ -66,6 +175,21 @@ fn app() -> Html {
     }
 }
 
+/// Root of the overlay window, which shares the frontend with the main one.
+#[function_component(OverlayApp)]
+fn overlay_app() -> Html {
+    html! {
+        <CoreProvider>
+            <Overlay />
+        </CoreProvider>
+    }
+}
+
 fn main() {
-    yew::Renderer::<App>::new().render();
+    logger::init();
+    if is_overlay_window() {
+        yew::Renderer::<OverlayApp>::new().render();
+    } else {
+        yew::Renderer::<App>::new().render();
+    }
 }
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// Mirrored from `KillAlertSettings` in `src-tauri/src/settings.rs`.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct KillAlertSettings {
+    pub enabled: bool,
+    pub sound: bool,
+    pub cooldown_secs: u64,
+}
+
+impl Default for KillAlertSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            sound: true,
+            cooldown_secs: 30,
+        }
+    }
+}
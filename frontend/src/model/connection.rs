@@ -0,0 +1,35 @@
+use serde::Deserialize;
+
+pub use tutorlolv2_shared::connection::ConnectionState;
+
+/// Why the native poller runs at its current rate, mirrored from
+/// `src-tauri/src/pacing.rs`.
+#[derive(Debug, PartialEq, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Pace {
+    Normal,
+    Fighting,
+    Dead,
+    Hidden,
+    GamePaused,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Deserialize)]
+pub struct PollRate {
+    pub pace: Pace,
+    pub interval_ms: u64,
+}
+
+impl PollRate {
+    pub fn description(&self) -> String {
+        let every = format!("Every {:.1}s", self.interval_ms as f64 / 1000.0);
+        let reason = match self.pace {
+            Pace::Normal => return every,
+            Pace::Fighting => "fight in progress",
+            Pace::Dead => "waiting to respawn",
+            Pace::Hidden => "window hidden",
+            Pace::GamePaused => "game paused",
+        };
+        format!("{}, {}", every, reason)
+    }
+}
@@ -0,0 +1,39 @@
+use serde::Deserialize;
+
+/// Mirrored from `src-tauri/src/connectivity.rs`.
+#[derive(Debug, PartialEq, Clone, Deserialize)]
+#[serde(tag = "result", content = "message", rename_all = "snake_case")]
+pub enum TlsResult {
+    NotUsed,
+    Established,
+    Failed(String),
+    NotReached,
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize)]
+pub struct ParseCheck {
+    pub target: String,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize)]
+pub struct ProbeResult {
+    pub name: String,
+    pub url: String,
+    pub latency_ms: Option<u64>,
+    pub status: Option<u16>,
+    pub tls: TlsResult,
+    pub checks: Vec<ParseCheck>,
+    pub error: Option<String>,
+}
+
+impl ProbeResult {
+    /// Answered with a success status and every body check passed
+    pub fn passed(&self) -> bool {
+        self.error.is_none()
+            && self
+                .status
+                .is_some_and(|status| (200..300).contains(&status))
+            && self.checks.iter().all(|check| check.error.is_none())
+    }
+}
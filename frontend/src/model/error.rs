@@ -0,0 +1,13 @@
+use wasm_bindgen::JsValue;
+
+pub use tutorlolv2_shared::error::AppError;
+
+/// Errors thrown by `invoke` arrive as the serialized `AppError`. Anything
+/// else, such as a missing command, is reported as a parse error.
+pub fn from_invoke(error: JsValue) -> AppError {
+    match error.as_string() {
+        Some(message) => AppError::ParseError(message),
+        None => serde_wasm_bindgen::from_value(error.clone())
+            .unwrap_or_else(|_| AppError::ParseError(format!("{:?}", error))),
+    }
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+/// One entry of the `show-help` event sent by the tray Help item.
+#[derive(Debug, PartialEq, Clone, Deserialize)]
+pub struct KeybindingHelp {
+    pub binding: String,
+    pub description: String,
+}
+
+/// Payload of the `hotkey` event, mirrored from `src-tauri/src/hotkeys.rs`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HotkeyAction {
+    ToggleWindow,
+    ToggleOverlay,
+    TogglePolling,
+    CycleHiddenEnemies,
+    ClearStack,
+    SwitchPage,
+}
+
+impl HotkeyAction {
+    pub const ALL: [HotkeyAction; 6] = [
+        HotkeyAction::ToggleWindow,
+        HotkeyAction::ToggleOverlay,
+        HotkeyAction::TogglePolling,
+        HotkeyAction::CycleHiddenEnemies,
+        HotkeyAction::ClearStack,
+        HotkeyAction::SwitchPage,
+    ];
+
+    pub fn description(self) -> &'static str {
+        match self {
+            HotkeyAction::ToggleWindow => "Toggle window visibility",
+            HotkeyAction::ToggleOverlay => "Toggle the in-game overlay",
+            HotkeyAction::TogglePolling => "Start or stop fetching the game",
+            HotkeyAction::CycleHiddenEnemies => "Focus the next enemy",
+            HotkeyAction::ClearStack => "Clear the damage stack",
+            HotkeyAction::SwitchPage => "Switch to the next page",
+        }
+    }
+}
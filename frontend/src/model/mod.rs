@@ -1,5 +1,14 @@
+pub mod alerts;
 pub mod calculator;
 pub mod calculator_example;
+pub mod connection;
+pub mod connectivity;
+pub mod error;
+pub mod hotkey;
+pub mod overlay;
 pub mod realtime;
+pub mod replay;
 pub mod server;
+pub mod session;
+pub mod settings;
 pub mod traits;
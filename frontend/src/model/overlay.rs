@@ -0,0 +1,9 @@
+use serde::Deserialize;
+
+/// Payload of the `overlay-state` event, mirrored from `src-tauri/src/overlay.rs`.
+#[derive(Debug, PartialEq, Clone, Default, Deserialize)]
+pub struct OverlayState {
+    pub visible: bool,
+    pub locked: bool,
+    pub show_base_table: bool,
+}
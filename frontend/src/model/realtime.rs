@@ -1,151 +1,16 @@
-use std::collections::HashMap;
+use serde::Deserialize;
 
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use crate::model::connection::{ConnectionState, PollRate};
 
-#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
-pub struct Stats {
-    pub ability_power: f64,
-    pub armor: f64,
-    pub armor_penetration_flat: f64,
-    pub armor_penetration_percent: f64,
-    pub attack_damage: f64,
-    pub attack_range: f64,
-    pub attack_speed: f64,
-    pub crit_chance: f64,
-    pub crit_damage: f64,
-    pub current_health: f64,
-    pub magic_penetration_flat: f64,
-    pub magic_penetration_percent: f64,
-    pub magic_resist: f64,
-    pub max_health: f64,
-    pub max_mana: f64,
-    pub current_mana: f64,
-}
-
-#[derive(Default, PartialEq, Clone, Serialize, Deserialize)]
-pub struct BasicStats {
-    pub armor: f64,
-    pub health: f64,
-    pub attack_damage: f64,
-    pub magic_resist: f64,
-    pub mana: f64,
-}
-
-#[derive(PartialEq, Clone, Deserialize)]
-pub struct CurrentPlayer {
-    pub damaging_abilities: HashMap<String, String>,
-    pub damaging_items: HashMap<String, String>,
-    pub damaging_runes: HashMap<String, String>,
-    pub riot_id: String,
-    pub level: usize,
-    pub team: String,
-    pub position: String,
-    pub champion_name: String,
-    pub champion_id: String,
-    pub base_stats: BasicStats,
-    pub bonus_stats: BasicStats,
-    pub current_stats: Stats,
-}
-
-#[derive(PartialEq, Clone, Deserialize)]
-pub struct GameInformation {
-    pub game_time: f64,
-    pub map_number: usize,
-}
-
-#[derive(PartialEq, Clone, Deserialize)]
-pub struct InstanceDamage {
-    pub minimum_damage: f64,
-    pub maximum_damage: f64,
-    pub damage_type: String,
-    pub damages_in_area: bool,
-    pub damages_onhit: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub min_dmg_change: Option<f64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_dmg_change: Option<f64>,
-}
-
-pub type DamageLike = HashMap<String, InstanceDamage>;
-
-#[derive(PartialEq, Clone, Deserialize)]
-pub struct Damages {
-    pub abilities: DamageLike,
-    pub items: DamageLike,
-    pub runes: DamageLike,
-    pub compared_items: HashMap<String, SimulatedDamages>,
-}
-
-#[derive(PartialEq, Clone, Deserialize)]
-pub struct ComparedDamage {
-    pub total: f64,
-    pub change: f64,
-    pub damages: DamageLike,
-}
-
-#[derive(PartialEq, Clone, Deserialize)]
-pub struct SimulatedDamages {
-    pub abilities: ComparedDamage,
-    pub items: ComparedDamage,
-    pub runes: ComparedDamage,
-}
-
-#[derive(PartialEq, Clone, Deserialize)]
-pub struct DragonMultipliers {
-    pub earth: f64,
-    pub fire: f64,
-    pub chemtech: f64,
-}
-
-#[derive(PartialEq, Clone, Deserialize)]
-pub struct Enemy {
-    pub champion_id: String,
-    pub champion_name: String,
-    pub riot_id: String,
-    pub team: String,
-    pub level: usize,
-    pub position: String,
-    pub damages: Damages,
-    pub base_stats: BasicStats,
-    pub bonus_stats: BasicStats,
-    pub current_stats: BasicStats,
-}
-
-#[derive(PartialEq, Clone, Deserialize)]
-pub struct ComparedItem {
-    pub name: String,
-    pub gold_cost: usize,
-    pub prettified_stats: HashMap<String, Value>,
-}
-
-#[derive(PartialEq, Clone, Deserialize)]
-pub struct RealResists {
-    pub magic_resist: f64,
-    pub armor: f64,
-}
-
-#[derive(PartialEq, Clone, Deserialize)]
-pub struct Scoreboard {
-    pub assists: usize,
-    pub creep_score: usize,
-    pub deaths: usize,
-    pub kills: usize,
-    pub riot_id: String,
-    pub champion_id: Option<String>,
-    pub champion_name: String,
-    pub team: String,
-    pub position: String,
-}
+pub use tutorlolv2_shared::realtime::*;
 
-#[derive(PartialEq, Clone, Deserialize)]
-pub struct Realtime {
-    pub current_player: CurrentPlayer,
-    pub enemies: Vec<Enemy>,
-    pub game_information: GameInformation,
-    pub recommended_items: Vec<usize>,
-    pub compared_items: HashMap<String, ComparedItem>,
-    pub scoreboard: Vec<Scoreboard>,
-    pub enemy_dragon_multipliers: DragonMultipliers,
-    pub ally_dragon_multipliers: DragonMultipliers,
+/// Payload of the `realtime-update` event emitted by the native poller. The
+/// `Realtime` responses come through `use_realtime_channel` instead.
+#[derive(Deserialize)]
+#[serde(tag = "status", content = "payload", rename_all = "snake_case")]
+pub enum RealtimeUpdate {
+    State(ConnectionState),
+    Rate(Option<PollRate>),
+    /// Polling was paused or resumed outside the page, such as from the tray
+    Paused(bool),
 }
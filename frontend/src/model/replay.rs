@@ -0,0 +1,77 @@
+use std::rc::Rc;
+
+use serde::Deserialize;
+use tutorlolv2_shared::replay::{RecordEntry, RecordSource};
+
+use crate::model::realtime::Realtime;
+
+pub use tutorlolv2_shared::replay::ReplayPage;
+
+/// Recording file listed by the native app.
+#[derive(PartialEq, Clone, Deserialize)]
+pub struct RecordingInfo {
+    pub name: String,
+    pub size: u64,
+    pub modified_at: u64,
+}
+
+/// `Realtime` responses of a recorded game, ordered by `game_time`.
+pub struct Replay {
+    pub name: String,
+    pub frames: Vec<Rc<Realtime>>,
+}
+
+impl PartialEq for Replay {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.frames.len() == other.frames.len()
+    }
+}
+
+impl Replay {
+    /// Reads a recording file picked from disk. The raw `allgamedata` lines
+    /// are only useful for debugging and are skipped.
+    pub fn from_jsonl(name: String, contents: &str) -> Result<Self, String> {
+        let frames = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| serde_json::from_str::<RecordEntry>(line).ok())
+            .filter(|entry| entry.source == RecordSource::Realtime)
+            .filter_map(|entry| serde_json::from_value::<Realtime>(entry.payload).ok())
+            .collect();
+        Self::from_frames(name, frames)
+    }
+
+    pub fn from_frames(name: String, mut frames: Vec<Realtime>) -> Result<Self, String> {
+        if frames.is_empty() {
+            return Err(format!("{} has no realtime frames", name));
+        }
+
+        frames.sort_by(|a, b| {
+            a.game_information
+                .game_time
+                .total_cmp(&b.game_information.game_time)
+        });
+        frames.dedup_by(|a, b| a.game_information.game_time == b.game_information.game_time);
+        Ok(Self {
+            name,
+            frames: frames.into_iter().map(Rc::new).collect(),
+        })
+    }
+
+    pub fn start(&self) -> f64 {
+        self.frames[0].game_information.game_time
+    }
+
+    pub fn end(&self) -> f64 {
+        self.frames[self.frames.len() - 1]
+            .game_information
+            .game_time
+    }
+
+    /// Index of the last frame at or before `game_time`.
+    pub fn frame_index_at(&self, game_time: f64) -> usize {
+        self.frames
+            .partition_point(|frame| frame.game_information.game_time <= game_time)
+            .saturating_sub(1)
+    }
+}
@@ -1,8 +1 @@
-use serde::Deserialize;
-
-#[derive(Deserialize)]
-pub struct ServerResponse<T> {
-    pub success: bool,
-    pub message: Option<String>,
-    pub data: T,
-}
+pub use tutorlolv2_shared::ServerResponse;
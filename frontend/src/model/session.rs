@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+/// Payload of the `game-session` event, mirrored from `src-tauri/src/session.rs`.
+#[derive(Debug, PartialEq, Clone, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum SessionEvent {
+    CodeChanged {
+        game_code: usize,
+        previous: Option<usize>,
+    },
+    MatchStarted {
+        game_code: Option<usize>,
+    },
+    MatchEnded {
+        game_code: Option<usize>,
+    },
+}
@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+
+/// Part of the native settings edited from the frontend, mirrored from
+/// `src-tauri/src/settings.rs`.
+#[derive(Debug, PartialEq, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct AppSettings {
+    pub window: WindowSettings,
+    pub ui: UiSettings,
+    pub server: ServerSettings,
+    pub polling: PollingSettings,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WindowSettings {
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+    pub width: f64,
+    pub height: f64,
+    pub always_on_top: bool,
+    pub decorations: bool,
+    pub skip_taskbar: bool,
+    pub resizable: bool,
+    pub background_opacity: f64,
+}
+
+impl Default for WindowSettings {
+    fn default() -> Self {
+        Self {
+            x: None,
+            y: None,
+            width: 1280.0,
+            height: 720.0,
+            always_on_top: true,
+            decorations: true,
+            skip_taskbar: true,
+            resizable: true,
+            background_opacity: 1.0,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServerSettings {
+    pub enabled: bool,
+    pub address: String,
+    pub port: u16,
+}
+
+impl Default for ServerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            address: "127.0.0.1".to_string(),
+            port: 47800,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PollingSettings {
+    pub min_interval_ms: u64,
+    pub max_interval_ms: u64,
+}
+
+impl Default for PollingSettings {
+    fn default() -> Self {
+        Self {
+            min_interval_ms: 500,
+            max_interval_ms: 5000,
+        }
+    }
+}
+
+/// Mirrored from `src-tauri/src/server.rs`.
+#[derive(Debug, PartialEq, Clone, Default, Deserialize)]
+pub struct ServerStatus {
+    pub running: bool,
+    pub url: Option<String>,
+    pub error: Option<String>,
+}
+
+/// The damage stack is restored through `get_stack` instead.
+#[derive(Debug, PartialEq, Clone, Deserialize)]
+#[serde(default)]
+pub struct UiSettings {
+    pub selected_page: usize,
+    pub hidden_enemies: Vec<usize>,
+}
+
+impl Default for UiSettings {
+    fn default() -> Self {
+        Self {
+            selected_page: 1,
+            hidden_enemies: Vec::new(),
+        }
+    }
+}
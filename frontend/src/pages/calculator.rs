@@ -8,7 +8,7 @@ use web_sys::HtmlInputElement;
 use yew::prelude::*;
 
 use crate::{
-    BACKEND_URL, apply_stat,
+    apply_stat, backend_url,
     components::{
         base_table::base_table,
         comparison_header::comparison_header,
@@ -19,7 +19,10 @@ use crate::{
     },
     context::core::CoreContext,
     model::{
-        calculator::{ActivePlayerX, Calculator, CurrentPlayerX, EnemyPlayersX, EnemyX, GameX},
+        calculator::{
+            ActivePlayerX, Calculator, CurrentPlayerX, EnemyPlayersX, EnemyX, GameX,
+            example_active_player, example_enemy_player,
+        },
         server::ServerResponse,
         traits::CurrentPlayerLike,
     },
@@ -143,8 +146,8 @@ impl StatsValue {
 
 #[function_component]
 pub fn CalculatorDisplay() -> Html {
-    let active_player = use_state(|| ActivePlayerX::new());
-    let enemy_players = use_state(|| Vec::<EnemyPlayersX>::from([EnemyPlayersX::new(0)]));
+    let active_player = use_state(|| example_active_player());
+    let enemy_players = use_state(|| Vec::<EnemyPlayersX>::from([example_enemy_player(0)]));
     let active_player_stacks = use_state(|| 0usize);
     let ally_earth_dragons = use_state(|| 0usize);
     let ally_fire_dragons = use_state(|| 0usize);
@@ -200,7 +203,7 @@ pub fn CalculatorDisplay() -> Html {
                 };
 
                 spawn_local(async move {
-                    let res = Request::post(&format!("{}/api/games/calculator", BACKEND_URL))
+                    let res = Request::post(&format!("{}/api/games/calculator", backend_url()))
                         .header("Content-Type", "application/json")
                         .body(
                             json!({
@@ -228,9 +231,9 @@ pub fn CalculatorDisplay() -> Html {
                     <img
                         class={"h-28 img-clipped"}
                         src={if *error_occurred {
-                            format!("{}/cdn/splash/{}_0.jpg", BACKEND_URL, active_player.champion_id)
+                            format!("{}/cdn/splash/{}_0.jpg", backend_url(), active_player.champion_id)
                         } else {
-                            format!("{}/cdn/centered/{}_0.jpg", BACKEND_URL, active_player.champion_id)
+                            format!("{}/cdn/centered/{}_0.jpg", backend_url(), active_player.champion_id)
                         }}
                         onerror={onerror_callback}
                         alt="Banner"
@@ -239,7 +242,7 @@ pub fn CalculatorDisplay() -> Html {
                 <div class={"flex flex-col"}>
                     <Selector<String>
                         source_map={all_champions}
-                        uri={format!("{}/cdn/champions", BACKEND_URL)}
+                        uri={format!("{}/cdn/champions", backend_url())}
                         title={"Champion"}
                         selection={SelectionMode::Single({
                             let active_player = active_player.clone();
@@ -253,7 +256,7 @@ pub fn CalculatorDisplay() -> Html {
                     />
                     <Selector<usize>
                         source_map={all_items}
-                        uri={format!("{}/cdn/items", BACKEND_URL)}
+                        uri={format!("{}/cdn/items", backend_url())}
                         title={"Items"}
                         selection={SelectionMode::Multiple({
                             let active_player = active_player.clone();
@@ -267,7 +270,7 @@ pub fn CalculatorDisplay() -> Html {
                     />
                     <Selector<usize>
                         source_map={all_runes}
-                        uri={format!("{}/cdn/runes", BACKEND_URL)}
+                        uri={format!("{}/cdn/runes", backend_url())}
                         title={"Runes"}
                         selection={SelectionMode::Multiple({
                             let active_player = active_player.clone();
@@ -285,7 +288,7 @@ pub fn CalculatorDisplay() -> Html {
                         ["Q", "W", "E", "R"].into_iter().map(|ability| {
                             let image_url = format!(
                                 "{}/cdn/abilities/{}{}.png",
-                                BACKEND_URL,
+                                backend_url(),
                                 active_player.champion_id,
                                 ability
                             );
@@ -350,67 +353,67 @@ pub fn CalculatorDisplay() -> Html {
                     {[
                         (
                             StatsValue::Level(active_player.level.to_string()),
-                            format!("{}/cdn/stats/Level.png", BACKEND_URL)
+                            format!("{}/cdn/stats/Level.png", backend_url())
                         ),
                         (
                             StatsValue::AttackSpeed(active_player.champion_stats.attack_speed.to_string()),
-                            format!("{}/cdn/stats/AttackSpeed.png", BACKEND_URL)
+                            format!("{}/cdn/stats/AttackSpeed.png", backend_url())
                         ),
                         (
                             StatsValue::AbilityPower(active_player.champion_stats.ability_power.to_string()),
-                            format!("{}/cdn/stats/AbilityPower.png", BACKEND_URL)
+                            format!("{}/cdn/stats/AbilityPower.png", backend_url())
                         ),
                         (
                             StatsValue::AttackDamage(active_player.champion_stats.attack_damage.to_string()),
-                            format!("{}/cdn/stats/AttackDamage.png", BACKEND_URL)
+                            format!("{}/cdn/stats/AttackDamage.png", backend_url())
                         ),
                         (
                             StatsValue::ArmorPenetrationFlat(active_player.champion_stats.armor_penetration_flat.to_string()),
-                            format!("{}/cdn/stats/ArmorPenetration.png", BACKEND_URL)
+                            format!("{}/cdn/stats/ArmorPenetration.png", backend_url())
                         ),
                         (
                             StatsValue::ArmorPenetrationPercent(active_player.champion_stats.armor_penetration_percent.to_string()),
-                            format!("{}/cdn/stats/ArmorPenetration.png", BACKEND_URL)
+                            format!("{}/cdn/stats/ArmorPenetration.png", backend_url())
                         ),
                         (
                             StatsValue::MagicPenetrationFlat(active_player.champion_stats.magic_penetration_flat.to_string()),
-                            format!("{}/cdn/stats/MagicPenetration.png", BACKEND_URL)
+                            format!("{}/cdn/stats/MagicPenetration.png", backend_url())
                         ),
                         (
                             StatsValue::MagicPenetrationPercent(active_player.champion_stats.magic_penetration_percent.to_string()),
-                            format!("{}/cdn/stats/MagicPenetration.png", BACKEND_URL)
+                            format!("{}/cdn/stats/MagicPenetration.png", backend_url())
                         ),
                         (
                             StatsValue::CritChance(active_player.champion_stats.crit_chance.to_string()),
-                            format!("{}/cdn/stats/CriticalStrikeChance.png", BACKEND_URL)
+                            format!("{}/cdn/stats/CriticalStrikeChance.png", backend_url())
                         ),
                         (
                             StatsValue::CritDamage(active_player.champion_stats.crit_damage.to_string()),
-                            format!("{}/cdn/stats/CriticalStrikeDamage.png", BACKEND_URL)
+                            format!("{}/cdn/stats/CriticalStrikeDamage.png", backend_url())
                         ),
                         (
                             StatsValue::MaxHealth(active_player.champion_stats.max_health.to_string()),
-                            format!("{}/cdn/stats/Health.png", BACKEND_URL)
+                            format!("{}/cdn/stats/Health.png", backend_url())
                         ),
                         (
                             StatsValue::CurrentHealth(active_player.champion_stats.current_health.to_string()),
-                            format!("{}/cdn/stats/Health.png", BACKEND_URL)
+                            format!("{}/cdn/stats/Health.png", backend_url())
                         ),
                         (
                             StatsValue::Armor(active_player.champion_stats.armor.to_string()),
-                            format!("{}/cdn/stats/Armor.png", BACKEND_URL)
+                            format!("{}/cdn/stats/Armor.png", backend_url())
                         ),
                         (
                             StatsValue::MagicResist(active_player.champion_stats.magic_resist.to_string()),
-                            format!("{}/cdn/stats/MagicResist.png", BACKEND_URL)
+                            format!("{}/cdn/stats/MagicResist.png", backend_url())
                         ),
                         (
                             StatsValue::MaxMana(active_player.champion_stats.max_mana.to_string()),
-                            format!("{}/cdn/stats/Mana.png", BACKEND_URL)
+                            format!("{}/cdn/stats/Mana.png", backend_url())
                         ),
                         (
                             StatsValue::CurrentMana(active_player.champion_stats.current_mana.to_string()),
-                            format!("{}/cdn/stats/Mana.png", BACKEND_URL)
+                            format!("{}/cdn/stats/Mana.png", backend_url())
                         ),
                     ].into_iter().map(|(label_enum, image_url)| {
                         let oninput = {
@@ -534,7 +537,7 @@ pub fn CalculatorDisplay() -> Html {
                                     enemy_players.set({
                                         let mut enemy_players_vec = (*enemy_players).clone();
                                         let length = enemy_players_vec.len();
-                                        enemy_players_vec.push(EnemyPlayersX::new(length));
+                                        enemy_players_vec.push(example_enemy_player(length));
                                         enemy_players_vec
                                     });
                                 })
@@ -605,14 +608,14 @@ pub fn CalculatorDisplay() -> Html {
                                 <div class={"flex relative"}>
                                     <img
                                         class={"h-28 img-clipped"}
-                                        src={format!("{}/cdn/centered/{}_0.jpg", BACKEND_URL, player.champion_id)}
+                                        src={format!("{}/cdn/centered/{}_0.jpg", backend_url(), player.champion_id)}
                                         alt="Banner"
                                     />
                                 </div>
                                 <div class={"flex flex-col"}>
                                     <Selector<String>
                                         source_map={all_champions}
-                                        uri={format!("{}/cdn/champions", BACKEND_URL)}
+                                        uri={format!("{}/cdn/champions", backend_url())}
                                         title={"Champion"}
                                         selection={SelectionMode::Single({
                                             let enemy_players = enemy_players.clone();
@@ -629,7 +632,7 @@ pub fn CalculatorDisplay() -> Html {
                                     />
                                     <Selector<usize>
                                         source_map={all_items}
-                                        uri={format!("{}/cdn/items", BACKEND_URL)}
+                                        uri={format!("{}/cdn/items", backend_url())}
                                         title={"Items"}
                                         selection={SelectionMode::Multiple({
                                             let enemy_players = enemy_players.clone();
@@ -650,19 +653,19 @@ pub fn CalculatorDisplay() -> Html {
                                     [
                                         (
                                             StatsValue::Level(player.level.to_string()),
-                                            format!("{}/cdn/stats/Level.png", BACKEND_URL)
+                                            format!("{}/cdn/stats/Level.png", backend_url())
                                         ),
                                         (
                                             StatsValue::MaxHealth(player.stats.health.to_string()),
-                                            format!("{}/cdn/stats/Health.png", BACKEND_URL)
+                                            format!("{}/cdn/stats/Health.png", backend_url())
                                         ),
                                         (
                                             StatsValue::Armor(player.stats.armor.to_string()),
-                                            format!("{}/cdn/stats/Armor.png", BACKEND_URL)
+                                            format!("{}/cdn/stats/Armor.png", backend_url())
                                         ),
                                         (
                                             StatsValue::MagicResist(player.stats.magic_resist.to_string()),
-                                            format!("{}/cdn/stats/MagicResist.png", BACKEND_URL)
+                                            format!("{}/cdn/stats/MagicResist.png", backend_url())
                                         ),
                                     ].into_iter().map(|(label_enum, image_url)| {
                                         let oninput = {
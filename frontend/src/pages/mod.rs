@@ -3,4 +3,6 @@ pub mod calculator;
 pub mod dashboard;
 pub mod formulas;
 pub mod github;
+pub mod overlay;
 pub mod realtime;
+pub mod settings;
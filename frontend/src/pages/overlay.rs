@@ -0,0 +1,129 @@
+use crate::{
+    backend_url,
+    components::{
+        base_table::base_table,
+        stacker::{StackInstance, stacked_damage},
+    },
+    context::core::CoreContext,
+    externcalls::invokers::{
+        OVERLAY_STATE_EVENT, STACK_UPDATE_EVENT, get_overlay_state, get_stack, set_overlay_locked,
+    },
+    hooks::{realtime_feed::use_followed_realtime_feed, tauri_event::use_tauri_event},
+    model::{overlay::OverlayState, traits::EnemyLike},
+};
+use web_sys::window;
+use yew::prelude::*;
+
+/// Contents of the overlay window. Shows the damage stacked on the Realtime
+/// page against every enemy, and optionally the damage of each ability, item
+/// and rune. While unlocked it can be dragged around and resized.
+#[function_component(Overlay)]
+pub fn overlay() -> Html {
+    use_context::<CoreContext>().expect("CoreContext not found");
+    let feed = use_followed_realtime_feed();
+    let stack = use_state(Vec::<StackInstance>::new);
+    let overlay_state = use_state(OverlayState::default);
+
+    {
+        let stack = stack.clone();
+        let overlay_state = overlay_state.clone();
+        use_effect_with((), move |_| {
+            // The page background would otherwise cover the game
+            if let Some(body) = window().and_then(|w| w.document()).and_then(|d| d.body()) {
+                body.set_class_name("bg-transparent overflow-hidden");
+            }
+            get_stack(Callback::from(move |shared| stack.set(shared)));
+            get_overlay_state(Callback::from(move |state| overlay_state.set(state)));
+        });
+    }
+
+    {
+        let stack = stack.clone();
+        use_tauri_event(
+            STACK_UPDATE_EVENT,
+            Callback::from(move |shared: Vec<StackInstance>| stack.set(shared)),
+        );
+    }
+
+    {
+        let overlay_state = overlay_state.clone();
+        use_tauri_event(
+            OVERLAY_STATE_EVENT,
+            Callback::from(move |state: OverlayState| overlay_state.set(state)),
+        );
+    }
+
+    let header = if overlay_state.locked {
+        html! {}
+    } else {
+        html! {
+            <div
+                data-tauri-drag-region={"true"}
+                class={"flex items-center justify-between gap-2 px-2 py-1 bg-indigo-950 cursor-move"}
+            >
+                <span data-tauri-drag-region={"true"} class={"text-xs font-semibold"}>
+                    { "Drag to move, resize from the edges" }
+                </span>
+                <button
+                    onclick={Callback::from(|_: MouseEvent| set_overlay_locked(true))}
+                    class={"cursor-pointer px-2 py-0.5 rounded-md text-xs font-semibold bg-emerald-900"}
+                >
+                    { "Lock" }
+                </button>
+            </div>
+        }
+    };
+
+    let Some(game_data) = feed.data.as_ref() else {
+        return html! {
+            <div class={"flex flex-col h-screen bg-zinc-950/70"}>
+                { header }
+                <span class={"p-2 text-sm text-shadow text-slate-300"}>
+                    { "Waiting for the Realtime page to load a game" }
+                </span>
+            </div>
+        };
+    };
+
+    html! {
+        <div class={"flex flex-col h-screen overflow-hidden bg-zinc-950/70 text-sm"}>
+            { header }
+            <div class={"flex flex-col gap-1 p-2"}>
+                {
+                    game_data.enemies.iter().map(|enemy| {
+                        let health = enemy.get_current_stats().health;
+                        let total_damage = stacked_damage(&stack, enemy);
+                        let health_percent = if health > 0.0 {
+                            ((health - total_damage) / health * 100.0).max(0.0)
+                        } else {
+                            0.0
+                        };
+                        html! {
+                            <div class={"grid grid-cols-[auto_1fr_auto_auto] items-center gap-2"}>
+                                <img
+                                    class={"h-6 w-6 aspect-square"}
+                                    src={format!("{}/cdn/champions/{}.png", backend_url(), enemy.get_champion_id())}
+                                    alt="Champion"
+                                />
+                                <span class={"text-shadow truncate"}>{ enemy.get_champion_name() }</span>
+                                <span class={"text-shadow text-nowrap"}>{ format!("{:.0}", total_damage) }</span>
+                                <span class={if health_percent <= 0.0 {
+                                    "text-shadow text-nowrap font-bold text-emerald-400"
+                                } else {
+                                    "text-shadow text-nowrap text-rose-300"
+                                }}>
+                                    { format!("{:.1}%", health_percent) }
+                                </span>
+                            </div>
+                        }
+                    }).collect::<Html>()
+                }
+            </div>
+            if overlay_state.show_base_table {
+                <div class={"overflow-auto"}>
+                    { base_table(&game_data.current_player, &game_data.enemies) }
+                </div>
+            }
+        </div>
+    }
+}
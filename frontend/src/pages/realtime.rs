@@ -1,22 +1,34 @@
 use crate::{
-    BACKEND_URL, MAX_FAILURES,
+    backend_url,
     components::{
         base_table::base_table,
         comparison_header::comparison_header,
         comparison_table::comparison_table,
+        connection_status::ConnectionStatus,
+        overlay_controls::OverlayControls,
+        replay::{RecordingPicker, ReplayControls},
+        simulated_items::SimulatedItems,
         stacker::{StackInstance, stack_dropper, stack_selector, stacker},
     },
-    externcalls::invokers::get_realtime_game,
+    context::core::CoreContext,
+    externcalls::invokers::{
+        HOTKEY_EVENT, get_settings, get_stack, is_native_app, regenerate_game_code,
+        set_hidden_enemies, share_stack,
+    },
+    hooks::{realtime_feed::use_realtime_feed, tauri_event::use_tauri_event},
     img::icons::{about_svg, github_svg, play_svg},
     model::{
+        hotkey::HotkeyAction,
         realtime::{CurrentPlayer, Enemy, Realtime, Scoreboard},
+        replay::Replay,
+        settings::AppSettings,
         traits::CurrentPlayerLike,
     },
 };
-use gloo::timers::callback::Interval;
-use std::{cell::RefCell, ops::Deref, rc::Rc};
+use log::info;
+use std::{ops::Deref, rc::Rc};
 use wasm_bindgen_futures::spawn_local;
-use web_sys::{HtmlInputElement, console, window};
+use web_sys::{HtmlInputElement, window};
 use yew::prelude::*;
 
 fn make_scoreboard(score: &Scoreboard) -> Html {
@@ -25,7 +37,7 @@ fn make_scoreboard(score: &Scoreboard) -> Html {
             <div class={"grid grid-cols-[auto_1fr] items-center gap-1.5"}>
                 <img
                     class={"min-w-8 h-8 aspect-square flex-shrink-0"}
-                    src={format!("{}/cdn/champions/{}.png", BACKEND_URL, score.champion_id.clone().unwrap_or(score.champion_name.clone()))}
+                    src={format!("{}/cdn/champions/{}.png", backend_url(), score.champion_id.clone().unwrap_or(score.champion_name.clone()))}
                     alt="Champion"
                 />
                 <div class={"flex flex-col leading-none overflow-hidden"}>
@@ -49,26 +61,21 @@ fn make_scoreboard(score: &Scoreboard) -> Html {
     }
 }
 
-fn fetch_game(
-    interval_state: &UseStateHandle<Option<Interval>>,
-    failure_counter: &Rc<RefCell<usize>>,
-    game_data: &UseStateHandle<Option<Rc<Realtime>>>,
-    game_code: usize,
-) {
-    let failure_counter = Rc::clone(&failure_counter);
-    let cloned_interval_state = interval_state.clone();
-    let game_data = game_data.clone();
-
-    let interval = Interval::new(1000, move || {
-        get_realtime_game(game_code, game_data.clone(), failure_counter.clone());
-
-        if *failure_counter.borrow() >= (MAX_FAILURES - 1) {
-            web_sys::console::log_1(&"Stopped fetching games due to max failures limit".into());
-            cloned_interval_state.set(None);
-        }
-    });
-
-    interval_state.set(Some(interval));
+/// Enemies to hide so that only the next one stays visible. Starts from
+/// showing everyone and goes back to it after the last enemy.
+fn focus_next_enemy(hidden: &[usize], enemy_count: usize) -> Vec<usize> {
+    let visible = (0..enemy_count)
+        .filter(|index| !hidden.contains(index))
+        .collect::<Vec<_>>();
+    let next = match visible.as_slice() {
+        [focused] => focused + 1,
+        _ => 0,
+    };
+    if next >= enemy_count {
+        Vec::new()
+    } else {
+        (0..enemy_count).filter(|&index| index != next).collect()
+    }
 }
 
 #[derive(PartialEq, Properties)]
@@ -78,37 +85,67 @@ pub struct RealtimeDisplayProps {
 
 #[function_component(RealtimeDisplay)]
 pub fn realtime_display(props: &RealtimeDisplayProps) -> Html {
-    let game_data = use_state(|| Option::<Rc<Realtime>>::None);
+    // Subscribing to the context re-renders images when the backend changes
+    use_context::<CoreContext>().expect("CoreContext not found");
+    let feed = use_realtime_feed();
     let hide_champion_state = use_state(|| Vec::<usize>::new());
     let stack = use_state(|| Vec::<StackInstance>::new());
-    let interval_state = use_state(|| Option::<Interval>::None);
-    let failure_counter = use_mut_ref(|| 0usize);
     let error_occurred = use_state(|| false);
     let game_code = props.game_code_state.deref().clone();
+    let replay = use_state(|| Option::<Rc<Replay>>::None);
 
     let start_game = {
-        let interval_state = interval_state.clone();
-        let failure_counter = failure_counter.clone();
-        let game_data = game_data.clone();
-
-        *failure_counter.borrow_mut() = 0;
-
+        let feed = feed.clone();
         Callback::from(move |_: MouseEvent| {
-            if interval_state.is_none() && game_code.to_string().len() == 6 {
-                fetch_game(&interval_state, &failure_counter, &game_data, game_code);
+            if !feed.is_running() {
+                feed.start(game_code);
             }
         })
     };
 
     let stop_game = {
-        let interval_state = interval_state.clone();
+        let feed = feed.clone();
+        Callback::from(move |_: MouseEvent| {
+            info!("Cancelling game requests");
+            feed.stop();
+        })
+    };
 
+    // Replays take the place of the live feed, so polling stops while one plays
+    let start_replay = {
+        let feed = feed.clone();
+        let replay = replay.clone();
+        Callback::from(move |loaded: Rc<Replay>| {
+            feed.stop();
+            feed.show(Some(loaded.frames[0].clone()));
+            replay.set(Some(loaded));
+        })
+    };
+
+    let show_frame = {
+        let feed = feed.clone();
+        Callback::from(move |frame: Rc<Realtime>| feed.show(Some(frame)))
+    };
+
+    let exit_replay = {
+        let feed = feed.clone();
+        let replay = replay.clone();
         Callback::from(move |_: MouseEvent| {
-            web_sys::console::log_1(&"Code changed. Cancelling game requests".into());
-            interval_state.set(None);
+            replay.set(None);
+            feed.show(None);
         })
     };
 
+    let refresh_feed = {
+        let feed = feed.clone();
+        Callback::from(move |_| feed.refresh())
+    };
+
+    let toggle_pause = {
+        let feed = feed.clone();
+        Callback::from(move |_: MouseEvent| feed.set_paused(!*feed.paused))
+    };
+
     let change_game_code = {
         let game_code = props.game_code_state.clone();
         Callback::from(move |e: InputEvent| {
@@ -123,14 +160,71 @@ pub fn realtime_display(props: &RealtimeDisplayProps) -> Html {
     };
 
     {
-        let game_data = game_data.clone();
-        let game_code = game_code.clone();
-        use_effect_with(props.game_code_state.clone(), move |_| {
-            interval_state.set(None);
-            if interval_state.is_none() && game_code.to_string().len() == 6 {
-                fetch_game(&interval_state, &failure_counter, &game_data, game_code);
+        let feed = feed.clone();
+        use_effect_with(game_code, move |&game_code| feed.start(game_code));
+    }
+
+    // Hidden enemies and the stack are kept across pages and launches. They
+    // are only saved once the previous ones were restored
+    let restored = use_mut_ref(|| false);
+
+    {
+        let hide_champion_state = hide_champion_state.clone();
+        let stack = stack.clone();
+        let restored = restored.clone();
+        use_effect_with((), move |_| {
+            get_settings(Callback::from(move |settings: AppSettings| {
+                let hide_champion_state = hide_champion_state.clone();
+                let stack = stack.clone();
+                let restored = restored.clone();
+                get_stack(Callback::from(move |saved_stack| {
+                    *restored.borrow_mut() = true;
+                    hide_champion_state.set(settings.ui.hidden_enemies.clone());
+                    stack.set(saved_stack);
+                }));
+            }));
+        });
+    }
+
+    // The overlay window shows the totals of this stack
+    {
+        let restored = restored.clone();
+        use_effect_with((*stack).clone(), move |stack| {
+            if is_native_app() && *restored.borrow() {
+                share_stack(stack);
             }
-        })
+        });
+    }
+
+    use_effect_with((*hide_champion_state).clone(), move |hidden_enemies| {
+        if is_native_app() && *restored.borrow() {
+            set_hidden_enemies(hidden_enemies);
+        }
+    });
+
+    {
+        let feed = feed.clone();
+        let hide_champion_state = hide_champion_state.clone();
+        let stack = stack.clone();
+        let replay = replay.clone();
+        use_tauri_event(
+            HOTKEY_EVENT,
+            Callback::from(move |action: HotkeyAction| match action {
+                HotkeyAction::TogglePolling if replay.is_none() => {
+                    if feed.is_running() {
+                        feed.stop();
+                    } else {
+                        feed.start(game_code);
+                    }
+                }
+                HotkeyAction::CycleHiddenEnemies => {
+                    let enemy_count = feed.data.as_ref().map_or(0, |data| data.enemies.len());
+                    hide_champion_state.set(focus_next_enemy(&hide_champion_state, enemy_count));
+                }
+                HotkeyAction::ClearStack => stack.set(Vec::new()),
+                _ => {}
+            }),
+        );
     }
 
     let onerror_callback = {
@@ -140,7 +234,15 @@ pub fn realtime_display(props: &RealtimeDisplayProps) -> Html {
         })
     };
 
-    if let Some(game_data) = game_data.deref().clone() {
+    let status = html! {
+        <ConnectionStatus
+            state={(*feed.connection).clone()}
+            paused={*feed.paused}
+            rate={*feed.rate}
+        />
+    };
+
+    if let Some(game_data) = feed.data.deref().clone() {
         let current_player = &game_data.current_player;
         let enemies = &game_data
             .enemies
@@ -174,9 +276,9 @@ pub fn realtime_display(props: &RealtimeDisplayProps) -> Html {
                         <img
                             class={"img-clipped h-32"}
                             src={if *error_occurred {
-                                format!("{}/cdn/splash/{}_0.jpg", BACKEND_URL, current_player.champion_id)
+                                format!("{}/cdn/splash/{}_0.jpg", backend_url(), current_player.champion_id)
                             } else {
-                                format!("{}/cdn/centered/{}_0.jpg", BACKEND_URL, current_player.champion_id)
+                                format!("{}/cdn/centered/{}_0.jpg", backend_url(), current_player.champion_id)
                             }}
                             onerror={onerror_callback}
                             alt="Champion"
@@ -187,38 +289,56 @@ pub fn realtime_display(props: &RealtimeDisplayProps) -> Html {
                         </div>
                     </div>
                     <div class={"flex flex-col shadow-container bg-custom-900"}>
-                        <div class={"grid grid-cols-2"}>
-                            <button
-                                class={"cursor-pointer flex items-center gap-2 p-4 bg-indigo-950 justify-center"}
-                                onclick={{
-                                    Callback::from(move |_| {
-                                        spawn_local(async move {
-                                            if let Some(window) = window() {
-                                                let _ = window.navigator().clipboard().write_text(&game_code.to_string());
-                                            }
-                                        });
-                                    })
-                                }}
-                            >
-                                <img
-                                    class={"h-4 w-4 aspect-square flex-shrink-0"}
-                                    src={format!("{}/cdn/other/copy.svg", BACKEND_URL)}
-                                    alt="Copy"
-                                />
-                                <span class={"font-bold text-sm text-shadow"}>{format!("Game Code - {}", game_code)}</span>
-                            </button>
-                            <button
-                                onclick={stop_game}
-                                class={"cursor-pointer flex items-center bg-emerald-950 gap-2 p-4 justify-center"}
-                            >
-                                <img
-                                    class={"h-4 w-4 aspect-square flex-shrink-0"}
-                                    src={format!("{}/cdn/other/stop.svg", BACKEND_URL)}
-                                    alt="Stop"
-                                />
-                                <span class={"font-bold text-sm text-shadow"}>{ "Stop Game" }</span>
-                            </button>
-                        </div>
+                        if let Some(replay) = replay.deref().clone() {
+                            <ReplayControls {replay} on_frame={show_frame} on_exit={exit_replay} />
+                        } else {
+                            <div class={"grid grid-cols-3"}>
+                                <button
+                                    class={"cursor-pointer flex items-center gap-2 p-4 bg-indigo-950 justify-center"}
+                                    onclick={{
+                                        Callback::from(move |_| {
+                                            spawn_local(async move {
+                                                if let Some(window) = window() {
+                                                    let _ = window.navigator().clipboard().write_text(&game_code.to_string());
+                                                }
+                                            });
+                                        })
+                                    }}
+                                >
+                                    <img
+                                        class={"h-4 w-4 aspect-square flex-shrink-0"}
+                                        src={format!("{}/cdn/other/copy.svg", backend_url())}
+                                        alt="Copy"
+                                    />
+                                    <span class={"font-bold text-sm text-shadow"}>{format!("Game Code - {}", game_code)}</span>
+                                </button>
+                                <button
+                                    onclick={toggle_pause}
+                                    class={"cursor-pointer flex items-center bg-sky-950 gap-2 p-4 justify-center"}
+                                >
+                                    <span class={"font-bold text-sm text-shadow"}>
+                                        { if *feed.paused { "Resume" } else { "Pause" } }
+                                    </span>
+                                </button>
+                                <button
+                                    onclick={stop_game}
+                                    class={"cursor-pointer flex items-center bg-emerald-950 gap-2 p-4 justify-center"}
+                                >
+                                    <img
+                                        class={"h-4 w-4 aspect-square flex-shrink-0"}
+                                        src={format!("{}/cdn/other/stop.svg", backend_url())}
+                                        alt="Stop"
+                                    />
+                                    <span class={"font-bold text-sm text-shadow"}>{ "Stop Game" }</span>
+                                </button>
+                            </div>
+                            { status.clone() }
+                            <OverlayControls />
+                            <SimulatedItems
+                                champion_id={current_player.champion_id.clone()}
+                                on_change={refresh_feed}
+                            />
+                        }
                         <div class={"grid grid-cols-2"}>
                             <div class={"flex flex-col"}>
                                 <h2 class={"text-center text-shadow text-sm py-2 truncate"}>{ "Your Team" }</h2>
@@ -338,6 +458,7 @@ pub fn realtime_display(props: &RealtimeDisplayProps) -> Html {
                         <span>{ "Load my current game data" }</span>
                     </button>
                 </div>
+                <div class={"rounded-lg bg-custom-900 w-fit"}>{ status }</div>
                 <div class={"grid lg:grid-cols-2 gap-10 leading-8"}>
                     <div class={"w-full flex flex-col gap-4"}>
                         <h3 class={"flex text-lg font-semibold items-center gap-3 mb-3 text-white"}>
@@ -372,42 +493,53 @@ pub fn realtime_display(props: &RealtimeDisplayProps) -> Html {
                                 </svg>
                             </div>
                         </div>
+                        if is_native_app() {
+                            <button
+                                onclick={Callback::from(|_: MouseEvent| regenerate_game_code())}
+                                class={"cursor-pointer w-fit px-4 h-10 rounded-lg font-semibold bg-indigo-950"}
+                            >
+                                { "Generate a new code" }
+                            </button>
+                        }
                     </div>
-                    <div>
-                        <h3 class={"flex text-lg font-semibold items-center gap-3 mb-3 text-white"}>
-                            { "Troubleshooting Tips" }
-                        </h3>
-                        <div class={"flex flex-col gap-2 text-slate-300"}>
-                            <div class={"flex gap-3"}>
-                                <span class={"text-white font-bold"}>{ "1." }</span>
-                                <p>
-                                    { "The champion you're playing might be unsupported or recently broken. If you are a developer, you can check the " }
-                                    <span class={"font-semibold text-white"}>{ "Formulas" }</span>
-                                    { " section and inspect the " }
-                                    <code class={"font-semibold text-yellow-300 bg-yellow-900/30 px-2 py-1 rounded-md"}>{ "json" }</code>{ " and " }
-                                    <code class={"font-semibold text-orange-300 bg-orange-900/30 px-2 py-1 rounded-md"}>{ "rust" }</code>{ " code, or open an issue on " }
-                                    <a
-                                        class={"transition-colors w-fit font-semibold text-purple-300 hover:text-violet-400 hover:bg-violet-900/30 hover:border-violet-700/50 bg-purple-900/30 px-2 py-1 rounded-md"}
-                                        href="https://github.com/LuizGomes56/tutorlolv2/tree/master/src"
-                                        target="_blank"
-                                    >
-                                        { "Github" }
-                                    </a>
-                                </p>
-                            </div>
-                            <div class={"flex gap-3"}>
-                                <span class={"text-white font-bold"}>{ "2." }</span>
-                                <p>{ "My calculator service may be temporarily down." }</p>
-                            </div>
-                            <div class={"flex gap-3"}>
-                                <span class={"text-white font-bold"}>{ "3." }</span>
-                                <p>{ "Riot may have changed their API unexpectedly." }</p>
-                            </div>
-                            <div class={"flex gap-3"}>
-                                <span class={"text-white font-bold"}>{ "4." }</span>
-                                <p>{ "Your game code could have been invalidated." }</p>
+                    <div class={"flex flex-col gap-10"}>
+                        <div>
+                            <h3 class={"flex text-lg font-semibold items-center gap-3 mb-3 text-white"}>
+                                { "Troubleshooting Tips" }
+                            </h3>
+                            <div class={"flex flex-col gap-2 text-slate-300"}>
+                                <div class={"flex gap-3"}>
+                                    <span class={"text-white font-bold"}>{ "1." }</span>
+                                    <p>
+                                        { "The champion you're playing might be unsupported or recently broken. If you are a developer, you can check the " }
+                                        <span class={"font-semibold text-white"}>{ "Formulas" }</span>
+                                        { " section and inspect the " }
+                                        <code class={"font-semibold text-yellow-300 bg-yellow-900/30 px-2 py-1 rounded-md"}>{ "json" }</code>{ " and " }
+                                        <code class={"font-semibold text-orange-300 bg-orange-900/30 px-2 py-1 rounded-md"}>{ "rust" }</code>{ " code, or open an issue on " }
+                                        <a
+                                            class={"transition-colors w-fit font-semibold text-purple-300 hover:text-violet-400 hover:bg-violet-900/30 hover:border-violet-700/50 bg-purple-900/30 px-2 py-1 rounded-md"}
+                                            href="https://github.com/LuizGomes56/tutorlolv2/tree/master/src"
+                                            target="_blank"
+                                        >
+                                            { "Github" }
+                                        </a>
+                                    </p>
+                                </div>
+                                <div class={"flex gap-3"}>
+                                    <span class={"text-white font-bold"}>{ "2." }</span>
+                                    <p>{ "My calculator service may be temporarily down." }</p>
+                                </div>
+                                <div class={"flex gap-3"}>
+                                    <span class={"text-white font-bold"}>{ "3." }</span>
+                                    <p>{ "Riot may have changed their API unexpectedly." }</p>
+                                </div>
+                                <div class={"flex gap-3"}>
+                                    <span class={"text-white font-bold"}>{ "4." }</span>
+                                    <p>{ "Your game code could have been invalidated." }</p>
+                                </div>
                             </div>
                         </div>
+                        <RecordingPicker on_load={start_replay} />
                     </div>
                 </div>
             </div>
@@ -0,0 +1,25 @@
+use yew::prelude::*;
+
+use crate::components::{
+    backend_url::BackendUrlInput, connectivity::ConnectivityPanel, diagnostics::DiagnosticsExport,
+    hotkey_editor::HotkeyEditor, kill_alerts::KillAlerts, live_server::LiveServerSettings,
+    polling_settings::PollingSettingsForm, recorder_toggle::RecorderToggle,
+    window_settings::WindowSettingsForm,
+};
+
+pub fn settings() -> Html {
+    html! {
+        <div class={"flex flex-col gap-12 max-h-screen overflow-y-auto p-12"}>
+            <h1 class={"font-bold text-4xl text-white"}>{ "Settings" }</h1>
+            <BackendUrlInput />
+            <WindowSettingsForm />
+            <PollingSettingsForm />
+            <KillAlerts />
+            <RecorderToggle />
+            <LiveServerSettings />
+            <HotkeyEditor />
+            <ConnectivityPanel />
+            <DiagnosticsExport />
+        </div>
+    }
+}
@@ -0,0 +1,274 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use axum::{
+    extract::{Path as UrlPath, Request, State},
+    http::{StatusCode, Uri},
+    middleware::{self, Next},
+    response::{IntoResponse, Redirect, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tower_http::{cors::CorsLayer, decompression::RequestDecompressionLayer};
+
+use crate::read_json;
+
+pub const DEFAULT_PORT: u16 = 8082;
+
+pub fn default_fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/backend")
+}
+
+/// Canned payloads with the same shape as the real server. `realtime.json`
+/// is a `Realtime` and `calculator.json` a `Calculator`.
+pub struct BackendFixtures {
+    realtime: Value,
+    calculator: Value,
+    champions: Value,
+    items: Value,
+    runes: Value,
+}
+
+impl BackendFixtures {
+    pub fn load(dir: &Path) -> std::io::Result<Self> {
+        Ok(Self {
+            realtime: read_json(dir.join("realtime.json"))?,
+            calculator: read_json(dir.join("calculator.json"))?,
+            champions: read_json(dir.join("champions.json"))?,
+            items: read_json(dir.join("items.json"))?,
+            runes: read_json(dir.join("runes.json"))?,
+        })
+    }
+}
+
+/// Failures and delays injected in front of every `/api` route.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Faults {
+    pub latency_ms: u64,
+    /// Every n-th affected request fails. Zero disables failures.
+    pub fail_every: u64,
+    /// Status of injected failures. With 200 only `success` is set to false.
+    pub fail_status: u16,
+    pub fail_message: String,
+    /// Restricts the faults to paths starting with this prefix.
+    pub only: Option<String>,
+}
+
+impl Default for Faults {
+    fn default() -> Self {
+        Self {
+            latency_ms: 0,
+            fail_every: 0,
+            fail_status: 500,
+            fail_message: "Injected failure".to_string(),
+            only: None,
+        }
+    }
+}
+
+pub struct Backend {
+    fixtures: BackendFixtures,
+    faults: Mutex<Faults>,
+    cdn_url: Option<String>,
+    affected_requests: AtomicU64,
+    next_game_code: AtomicUsize,
+    games: Mutex<HashMap<usize, Value>>,
+}
+
+impl Backend {
+    pub fn new(fixtures: BackendFixtures, faults: Faults, cdn_url: Option<String>) -> Self {
+        Self {
+            fixtures,
+            faults: Mutex::new(faults),
+            cdn_url,
+            affected_requests: AtomicU64::new(0),
+            next_game_code: AtomicUsize::new(100_000),
+            games: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Copies what changes during a game from the posted `allgamedata` into
+    /// the canned `Realtime`, so the app shows the clock and scores moving.
+    fn realtime_from(&self, game_data: &Value) -> Value {
+        let mut realtime = self.fixtures.realtime.clone();
+        realtime["game_information"]["game_time"] = game_data["gameData"]["gameTime"].clone();
+        if let Some(level) = game_data["activePlayer"]["level"].as_u64() {
+            realtime["current_player"]["level"] = json!(level);
+        }
+
+        let players = game_data["allPlayers"]
+            .as_array()
+            .cloned()
+            .unwrap_or_default();
+        if let Some(scoreboard) = realtime["scoreboard"].as_array_mut() {
+            for score in scoreboard {
+                let Some(player) = players.iter().find(|p| p["riotId"] == score["riot_id"]) else {
+                    continue;
+                };
+                let scores = &player["scores"];
+                score["kills"] = scores["kills"].clone();
+                score["deaths"] = scores["deaths"].clone();
+                score["assists"] = scores["assists"].clone();
+                score["creep_score"] = scores["creepScore"].clone();
+            }
+        }
+        realtime
+    }
+}
+
+fn success(data: Value) -> Response {
+    Json(json!({ "success": true, "message": null, "data": data })).into_response()
+}
+
+fn failure(status: StatusCode, message: &str) -> Response {
+    (
+        status,
+        Json(json!({ "success": false, "message": message, "data": null })),
+    )
+        .into_response()
+}
+
+async fn inject_faults(
+    State(backend): State<Arc<Backend>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let faults = backend.faults.lock().unwrap().clone();
+    let affected = faults
+        .only
+        .as_deref()
+        .is_none_or(|prefix| request.uri().path().starts_with(prefix));
+    if !affected {
+        return next.run(request).await;
+    }
+
+    if faults.latency_ms > 0 {
+        tokio::time::sleep(Duration::from_millis(faults.latency_ms)).await;
+    }
+    let count = backend.affected_requests.fetch_add(1, Ordering::SeqCst) + 1;
+    if faults.fail_every > 0 && count % faults.fail_every == 0 {
+        let status = StatusCode::from_u16(faults.fail_status).unwrap_or(StatusCode::OK);
+        return failure(status, &faults.fail_message);
+    }
+    next.run(request).await
+}
+
+async fn create_game(State(backend): State<Arc<Backend>>) -> Response {
+    let game_code = backend.next_game_code.fetch_add(1, Ordering::SeqCst);
+    success(json!({
+        "game_code": game_code,
+        "game_id": format!("mock-{}", game_code),
+    }))
+}
+
+#[derive(Deserialize)]
+struct RealtimeBody {
+    game_code: usize,
+    game_data: String,
+}
+
+async fn realtime(State(backend): State<Arc<Backend>>, Json(body): Json<RealtimeBody>) -> Response {
+    let game_data = match serde_json::from_str::<Value>(&body.game_data) {
+        Ok(game_data) if game_data["gameData"]["gameTime"].is_number() => game_data,
+        _ => return failure(StatusCode::OK, "Invalid game data"),
+    };
+    let realtime = backend.realtime_from(&game_data);
+    backend
+        .games
+        .lock()
+        .unwrap()
+        .insert(body.game_code, realtime.clone());
+    success(realtime)
+}
+
+#[derive(Deserialize)]
+struct GetByCodeBody {
+    game_code: usize,
+}
+
+async fn get_by_code(
+    State(backend): State<Arc<Backend>>,
+    Json(body): Json<GetByCodeBody>,
+) -> Response {
+    let games = backend.games.lock().unwrap();
+    let realtime = games
+        .get(&body.game_code)
+        .unwrap_or(&backend.fixtures.realtime);
+    success(realtime.clone())
+}
+
+async fn calculator(State(backend): State<Arc<Backend>>, Json(body): Json<Value>) -> Response {
+    let mut calculator = backend.fixtures.calculator.clone();
+    let active_player = &body["game"]["active_player"];
+    if active_player["champion_id"].is_string() {
+        calculator["current_player"]["champion_id"] = active_player["champion_id"].clone();
+        calculator["current_player"]["level"] = active_player["level"].clone();
+    }
+    success(calculator)
+}
+
+async fn static_instance(
+    State(backend): State<Arc<Backend>>,
+    UrlPath(instance): UrlPath<String>,
+) -> Response {
+    match instance.as_str() {
+        "champions" => success(backend.fixtures.champions.clone()),
+        "items" => success(backend.fixtures.items.clone()),
+        "runes" => success(backend.fixtures.runes.clone()),
+        _ => failure(StatusCode::NOT_FOUND, "Unknown static instance"),
+    }
+}
+
+async fn cdn(State(backend): State<Arc<Backend>>, uri: Uri) -> Response {
+    match &backend.cdn_url {
+        Some(cdn_url) => Redirect::temporary(&format!("{}{}", cdn_url, uri.path())).into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+async fn get_faults(State(backend): State<Arc<Backend>>) -> Json<Faults> {
+    Json(backend.faults.lock().unwrap().clone())
+}
+
+async fn set_faults(
+    State(backend): State<Arc<Backend>>,
+    Json(faults): Json<Faults>,
+) -> Json<Faults> {
+    backend.affected_requests.store(0, Ordering::SeqCst);
+    *backend.faults.lock().unwrap() = faults.clone();
+    Json(faults)
+}
+
+/// Routes of the TutorLoL server used by the app plus `/mock/faults` to
+/// change the injected failures at runtime. Request bodies may be gzipped,
+/// as the app uploads live client payloads that way.
+pub fn router(backend: Arc<Backend>) -> Router {
+    let api = Router::new()
+        .route("/api/games/create", get(create_game))
+        .route("/api/games/realtime", post(realtime))
+        .route("/api/games/get_by_code", post(get_by_code))
+        .route("/api/games/calculator", post(calculator))
+        .route("/api/static/{instance}", get(static_instance))
+        .route_layer(middleware::from_fn_with_state(
+            backend.clone(),
+            inject_faults,
+        ));
+
+    Router::new()
+        .merge(api)
+        .route("/cdn/{*path}", get(cdn))
+        .route("/mock/faults", get(get_faults).post(set_faults))
+        .layer(RequestDecompressionLayer::new())
+        .layer(CorsLayer::permissive())
+        .with_state(backend)
+}
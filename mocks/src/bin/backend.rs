@@ -0,0 +1,51 @@
+//! Serves canned TutorLoL server responses so the app runs without network.
+//!
+//! ```text
+//! cargo run -p tutorlolv2-mocks --bin backend -- [--port 8082] [--fixtures <dir>]
+//!     [--latency-ms 0] [--fail-every 0] [--fail-status 500] [--fail-message <text>]
+//!     [--only /api/games/realtime] [--cdn-url https://v2.tutorlol.com]
+//! ```
+//!
+//! Point the app at it with `--backend-url http://127.0.0.1:8082`. Faults can
+//! be changed while it runs by posting a new config to `/mock/faults`.
+
+use std::{path::PathBuf, sync::Arc};
+
+use tutorlolv2_mocks::{
+    backend::{default_fixtures_dir, router, Backend, BackendFixtures, Faults, DEFAULT_PORT},
+    cli::Args,
+};
+
+#[tokio::main]
+async fn main() {
+    let args = Args::from_env();
+    let fixtures_dir = args
+        .value::<PathBuf>("--fixtures")
+        .unwrap_or_else(default_fixtures_dir);
+    let fixtures = BackendFixtures::load(&fixtures_dir).expect("Failed to load backend fixtures");
+
+    let defaults = Faults::default();
+    let faults = Faults {
+        latency_ms: args.value("--latency-ms").unwrap_or(defaults.latency_ms),
+        fail_every: args.value("--fail-every").unwrap_or(defaults.fail_every),
+        fail_status: args.value("--fail-status").unwrap_or(defaults.fail_status),
+        fail_message: args
+            .value("--fail-message")
+            .unwrap_or(defaults.fail_message),
+        only: args.value("--only"),
+    };
+    let backend = Backend::new(fixtures, faults, args.value("--cdn-url"));
+
+    let address = args.address(DEFAULT_PORT);
+    let listener = tokio::net::TcpListener::bind(address)
+        .await
+        .expect("Failed to bind backend address");
+    println!(
+        "Serving backend fixtures from {} at http://{}",
+        fixtures_dir.display(),
+        address
+    );
+    axum::serve(listener, router(Arc::new(backend)))
+        .await
+        .unwrap();
+}
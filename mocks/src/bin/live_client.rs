@@ -0,0 +1,51 @@
+//! Serves the Riot Live Client Data API from fixture files so the realtime
+//! flow can run without League of Legends.
+//!
+//! ```text
+//! cargo run -p tutorlolv2-mocks --bin live_client -- [--port 2999] [--fixtures <dir>]
+//!     [--speed 1.0] [--start-at 0] [--loop]
+//! ```
+//!
+//! The mock speaks plain HTTP, so start the app with
+//! `TUTORLOL_LIVE_CLIENT_URL=http://127.0.0.1:2999`.
+
+use std::{path::PathBuf, sync::Arc};
+
+use tutorlolv2_mocks::{
+    cli::Args,
+    live_client::{
+        default_fixtures_dir, router, Fixtures, LiveClient, LiveClientOptions, DEFAULT_PORT,
+    },
+};
+
+#[tokio::main]
+async fn main() {
+    let args = Args::from_env();
+    let fixtures_dir = args
+        .value::<PathBuf>("--fixtures")
+        .unwrap_or_else(default_fixtures_dir);
+    let fixtures = Fixtures::load(&fixtures_dir).expect("Failed to load live client fixtures");
+
+    let defaults = LiveClientOptions::default();
+    let live_client = LiveClient::new(
+        fixtures,
+        LiveClientOptions {
+            speed: args.value("--speed").unwrap_or(defaults.speed),
+            start_at: args.value("--start-at").unwrap_or(defaults.start_at),
+            looping: args.has("--loop"),
+        },
+    );
+
+    let address = args.address(DEFAULT_PORT);
+    let listener = tokio::net::TcpListener::bind(address)
+        .await
+        .expect("Failed to bind live client address");
+    println!(
+        "Serving live client fixtures from {} at http://{}",
+        fixtures_dir.display(),
+        address
+    );
+    axum::serve(listener, router(Arc::new(live_client)))
+        .await
+        .unwrap();
+}
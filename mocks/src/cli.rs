@@ -0,0 +1,30 @@
+use std::{net::SocketAddr, str::FromStr};
+
+/// Minimal `--flag value` parser shared by the mock binaries.
+pub struct Args(Vec<String>);
+
+impl Args {
+    pub fn from_env() -> Self {
+        Self(std::env::args().skip(1).collect())
+    }
+
+    pub fn has(&self, flag: &str) -> bool {
+        self.0.iter().any(|arg| arg == flag)
+    }
+
+    pub fn value<T: FromStr>(&self, flag: &str) -> Option<T> {
+        let prefix = format!("{}=", flag);
+        self.0.iter().enumerate().find_map(|(index, arg)| {
+            if arg == flag {
+                self.0.get(index + 1)?.parse().ok()
+            } else {
+                arg.strip_prefix(&prefix)?.parse().ok()
+            }
+        })
+    }
+
+    pub fn address(&self, default_port: u16) -> SocketAddr {
+        let host = self.value("--host").unwrap_or([127, 0, 0, 1].into());
+        SocketAddr::new(host, self.value("--port").unwrap_or(default_port))
+    }
+}
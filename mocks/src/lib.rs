@@ -0,0 +1,17 @@
+use std::{fs, io, path::PathBuf};
+
+use serde::de::DeserializeOwned;
+
+pub mod backend;
+pub mod cli;
+pub mod live_client;
+
+pub(crate) fn read_json<T: DeserializeOwned>(path: PathBuf) -> io::Result<T> {
+    let contents = fs::read_to_string(&path)?;
+    serde_json::from_str(&contents).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), e),
+        )
+    })
+}
@@ -0,0 +1,348 @@
+use std::{
+    collections::HashMap,
+    io,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::Instant,
+};
+
+use axum::{
+    extract::{Path as UrlPath, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::read_json;
+
+pub const DEFAULT_PORT: u16 = 2999;
+
+pub fn default_fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/live_client")
+}
+
+/// One scripted change of the game. Patches follow JSON merge patch rules and
+/// players are matched by `riotId`. Events get their `EventID` and
+/// `EventTime` filled in when the step is reached.
+#[derive(Deserialize)]
+pub struct TimelineStep {
+    pub at: f64,
+    #[serde(default)]
+    pub active_player: Option<Value>,
+    #[serde(default)]
+    pub players: HashMap<String, Value>,
+    #[serde(default)]
+    pub events: Vec<Value>,
+    #[serde(default)]
+    pub game_data: Option<Value>,
+}
+
+#[derive(Deserialize)]
+pub struct Timeline {
+    pub duration: f64,
+    pub steps: Vec<TimelineStep>,
+}
+
+/// `allgamedata.json` is the state of the game at `gameTime = 0` and
+/// `timeline.json` scripts everything that happens after it.
+pub struct Fixtures {
+    base: Value,
+    timeline: Timeline,
+}
+
+fn merge_patch(target: &mut Value, patch: &Value) {
+    match (target, patch) {
+        (Value::Object(target), Value::Object(patch)) => {
+            for (key, value) in patch {
+                if value.is_null() {
+                    target.remove(key);
+                } else {
+                    merge_patch(target.entry(key.clone()).or_insert(Value::Null), value);
+                }
+            }
+        }
+        (target, patch) => *target = patch.clone(),
+    }
+}
+
+impl Fixtures {
+    pub fn load(dir: &Path) -> io::Result<Self> {
+        let base = read_json(dir.join("allgamedata.json"))?;
+        let mut timeline: Timeline = read_json(dir.join("timeline.json"))?;
+        timeline.steps.sort_by(|a, b| a.at.total_cmp(&b.at));
+        Ok(Self { base, timeline })
+    }
+
+    pub fn duration(&self) -> f64 {
+        self.timeline.duration
+    }
+
+    /// Replays every step up to `game_time` on top of the base payload.
+    pub fn snapshot(&self, game_time: f64) -> Value {
+        let mut data = self.base.clone();
+        let mut event_id = data["events"]["Events"]
+            .as_array()
+            .map(|events| events.len())
+            .unwrap_or_default();
+
+        for step in self.timeline.steps.iter().take_while(|s| s.at <= game_time) {
+            if let Some(patch) = &step.active_player {
+                merge_patch(&mut data["activePlayer"], patch);
+            }
+            if let Some(players) = data["allPlayers"].as_array_mut() {
+                for (riot_id, patch) in &step.players {
+                    if let Some(player) = players.iter_mut().find(|p| p["riotId"] == *riot_id) {
+                        merge_patch(player, patch);
+                    }
+                }
+            }
+            if let Some(events) = data["events"]["Events"].as_array_mut() {
+                for event in &step.events {
+                    let mut event = event.clone();
+                    event["EventID"] = json!(event_id);
+                    event["EventTime"] = json!(step.at);
+                    events.push(event);
+                    event_id += 1;
+                }
+            }
+            if let Some(patch) = &step.game_data {
+                merge_patch(&mut data["gameData"], patch);
+            }
+        }
+
+        data["gameData"]["gameTime"] = json!(game_time);
+        data
+    }
+}
+
+struct Clock {
+    base: f64,
+    started: Instant,
+    speed: f64,
+    paused: bool,
+}
+
+impl Clock {
+    fn now(&self) -> f64 {
+        if self.paused {
+            self.base
+        } else {
+            self.base + self.started.elapsed().as_secs_f64() * self.speed
+        }
+    }
+
+    fn seek(&mut self, game_time: f64) {
+        self.base = game_time;
+        self.started = Instant::now();
+    }
+
+    fn set_paused(&mut self, paused: bool) {
+        let now = self.now();
+        self.paused = paused;
+        self.seek(now);
+    }
+
+    fn set_speed(&mut self, speed: f64) {
+        let now = self.now();
+        self.speed = speed;
+        self.seek(now);
+    }
+}
+
+pub struct LiveClientOptions {
+    /// Game seconds elapsed per real second.
+    pub speed: f64,
+    /// Initial game time. Negative values behave like the loading screen.
+    pub start_at: f64,
+    /// Starts a new game once the timeline ends instead of closing the game.
+    pub looping: bool,
+}
+
+impl Default for LiveClientOptions {
+    fn default() -> Self {
+        Self {
+            speed: 1.0,
+            start_at: 0.0,
+            looping: false,
+        }
+    }
+}
+
+enum Phase {
+    Loading,
+    InGame(f64),
+    Closed,
+}
+
+pub struct LiveClient {
+    fixtures: Fixtures,
+    clock: Mutex<Clock>,
+    looping: bool,
+    closed: Mutex<bool>,
+}
+
+#[derive(Serialize)]
+pub struct MockState {
+    pub game_time: f64,
+    pub duration: f64,
+    pub speed: f64,
+    pub paused: bool,
+    pub closed: bool,
+}
+
+impl LiveClient {
+    pub fn new(fixtures: Fixtures, options: LiveClientOptions) -> Self {
+        Self {
+            fixtures,
+            clock: Mutex::new(Clock {
+                base: options.start_at,
+                started: Instant::now(),
+                speed: options.speed,
+                paused: false,
+            }),
+            looping: options.looping,
+            closed: Mutex::new(false),
+        }
+    }
+
+    fn phase(&self) -> Phase {
+        if *self.closed.lock().unwrap() {
+            return Phase::Closed;
+        }
+        let mut clock = self.clock.lock().unwrap();
+        let mut game_time = clock.now();
+        if game_time > self.fixtures.duration() {
+            if !self.looping {
+                *self.closed.lock().unwrap() = true;
+                return Phase::Closed;
+            }
+            game_time %= self.fixtures.duration();
+            clock.seek(game_time);
+        }
+        if game_time < 0.0 {
+            Phase::Loading
+        } else {
+            Phase::InGame(game_time)
+        }
+    }
+
+    pub fn state(&self) -> MockState {
+        let closed = matches!(self.phase(), Phase::Closed);
+        let clock = self.clock.lock().unwrap();
+        MockState {
+            game_time: clock.now(),
+            duration: self.fixtures.duration(),
+            speed: clock.speed,
+            paused: clock.paused,
+            closed,
+        }
+    }
+
+    pub fn snapshot(&self) -> Option<Value> {
+        match self.phase() {
+            Phase::InGame(game_time) => Some(self.fixtures.snapshot(game_time)),
+            Phase::Loading | Phase::Closed => None,
+        }
+    }
+}
+
+fn not_found() -> Response {
+    (
+        StatusCode::NOT_FOUND,
+        Json(json!({
+            "errorCode": "RESOURCE_NOT_FOUND",
+            "httpStatus": 404,
+            "message": "No game is currently running",
+        })),
+    )
+        .into_response()
+}
+
+fn serve_part(live_client: &LiveClient, part: impl FnOnce(Value) -> Value) -> Response {
+    match live_client.snapshot() {
+        Some(data) => Json(part(data)).into_response(),
+        None => not_found(),
+    }
+}
+
+async fn all_game_data(State(live_client): State<Arc<LiveClient>>) -> Response {
+    serve_part(&live_client, |data| data)
+}
+
+async fn active_player(State(live_client): State<Arc<LiveClient>>) -> Response {
+    serve_part(&live_client, |mut data| data["activePlayer"].take())
+}
+
+async fn player_list(State(live_client): State<Arc<LiveClient>>) -> Response {
+    serve_part(&live_client, |mut data| data["allPlayers"].take())
+}
+
+async fn event_data(State(live_client): State<Arc<LiveClient>>) -> Response {
+    serve_part(&live_client, |mut data| data["events"].take())
+}
+
+async fn game_stats(State(live_client): State<Arc<LiveClient>>) -> Response {
+    serve_part(&live_client, |mut data| data["gameData"].take())
+}
+
+async fn mock_state(State(live_client): State<Arc<LiveClient>>) -> Json<MockState> {
+    Json(live_client.state())
+}
+
+async fn seek(
+    State(live_client): State<Arc<LiveClient>>,
+    UrlPath(game_time): UrlPath<f64>,
+) -> Json<MockState> {
+    live_client.clock.lock().unwrap().seek(game_time);
+    *live_client.closed.lock().unwrap() = false;
+    Json(live_client.state())
+}
+
+async fn speed(
+    State(live_client): State<Arc<LiveClient>>,
+    UrlPath(speed): UrlPath<f64>,
+) -> Json<MockState> {
+    live_client.clock.lock().unwrap().set_speed(speed);
+    Json(live_client.state())
+}
+
+async fn pause(State(live_client): State<Arc<LiveClient>>) -> Json<MockState> {
+    live_client.clock.lock().unwrap().set_paused(true);
+    Json(live_client.state())
+}
+
+async fn resume(State(live_client): State<Arc<LiveClient>>) -> Json<MockState> {
+    live_client.clock.lock().unwrap().set_paused(false);
+    Json(live_client.state())
+}
+
+async fn end(State(live_client): State<Arc<LiveClient>>) -> Json<MockState> {
+    *live_client.closed.lock().unwrap() = true;
+    Json(live_client.state())
+}
+
+async fn restart(State(live_client): State<Arc<LiveClient>>) -> Json<MockState> {
+    seek(State(live_client), UrlPath(0.0)).await
+}
+
+/// Routes of the Live Client Data API plus `/mock/*` routes that let tests
+/// and developers drive the scripted game.
+pub fn router(live_client: Arc<LiveClient>) -> Router {
+    Router::new()
+        .route("/liveclientdata/allgamedata", get(all_game_data))
+        .route("/liveclientdata/activeplayer", get(active_player))
+        .route("/liveclientdata/playerlist", get(player_list))
+        .route("/liveclientdata/eventdata", get(event_data))
+        .route("/liveclientdata/gamestats", get(game_stats))
+        .route("/mock/state", get(mock_state))
+        .route("/mock/seek/{game_time}", post(seek))
+        .route("/mock/speed/{speed}", post(speed))
+        .route("/mock/pause", post(pause))
+        .route("/mock/resume", post(resume))
+        .route("/mock/end", post(end))
+        .route("/mock/restart", post(restart))
+        .with_state(live_client)
+}
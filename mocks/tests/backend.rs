@@ -0,0 +1,182 @@
+use std::{
+    io::Write,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use flate2::{write::GzEncoder, Compression};
+use serde_json::{json, Value};
+use tutorlolv2_mocks::{
+    backend::{self, Backend, BackendFixtures, Faults},
+    live_client::{self, Fixtures, LiveClient, LiveClientOptions},
+};
+
+async fn serve(router: axum::Router) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, router).await });
+    format!("http://{}", address)
+}
+
+async fn spawn_backend(faults: Faults) -> String {
+    let fixtures = BackendFixtures::load(&backend::default_fixtures_dir()).unwrap();
+    serve(backend::router(Arc::new(Backend::new(
+        fixtures, faults, None,
+    ))))
+    .await
+}
+
+async fn post(url: String, body: Value) -> (u16, Value) {
+    let response = reqwest::Client::new()
+        .post(url)
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+    (response.status().as_u16(), response.json().await.unwrap())
+}
+
+async fn get(url: String) -> (u16, Value) {
+    let response = reqwest::get(url).await.unwrap();
+    (response.status().as_u16(), response.json().await.unwrap())
+}
+
+#[tokio::test]
+async fn static_instances_are_wrapped_in_server_responses() {
+    let base = spawn_backend(Faults::default()).await;
+    for instance in ["champions", "items", "runes"] {
+        let (status, response) = get(format!("{}/api/static/{}", base, instance)).await;
+        assert_eq!(status, 200);
+        assert_eq!(response["success"], true);
+        assert!(!response["data"].as_object().unwrap().is_empty());
+    }
+}
+
+#[tokio::test]
+async fn calculator_reflects_the_requested_champion() {
+    let base = spawn_backend(Faults::default()).await;
+    let (_, response) = post(
+        format!("{}/api/games/calculator", base),
+        json!({ "game": { "active_player": { "champion_id": "Ahri", "level": 11 } } }),
+    )
+    .await;
+    assert_eq!(response["data"]["current_player"]["champion_id"], "Ahri");
+    assert_eq!(response["data"]["current_player"]["level"], 11);
+    assert_eq!(response["data"]["enemies"].as_array().unwrap().len(), 5);
+}
+
+#[tokio::test]
+async fn realtime_flow_runs_against_both_mocks() {
+    let live_client = LiveClient::new(
+        Fixtures::load(&live_client::default_fixtures_dir()).unwrap(),
+        LiveClientOptions {
+            speed: 0.0,
+            start_at: 1210.0,
+            ..Default::default()
+        },
+    );
+    let live_base = serve(live_client::router(Arc::new(live_client))).await;
+    let base = spawn_backend(Faults::default()).await;
+
+    let (_, created) = get(format!("{}/api/games/create", base)).await;
+    let game_code = created["data"]["game_code"].as_u64().unwrap();
+    let game_id = created["data"]["game_id"].clone();
+
+    let game_data = reqwest::get(format!("{}/liveclientdata/allgamedata", live_base))
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    let (_, realtime) = post(
+        format!("{}/api/games/realtime", base),
+        json!({
+            "game_id": game_id,
+            "game_code": game_code,
+            "game_data": game_data,
+            "simulated_items": [3115],
+        }),
+    )
+    .await;
+    assert_eq!(realtime["success"], true);
+    assert_eq!(realtime["data"]["game_information"]["game_time"], 1210.0);
+    let tutor = realtime["data"]["scoreboard"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|score| score["riot_id"] == "Tutor#BR1")
+        .unwrap();
+    assert_eq!(tutor["kills"], 3);
+
+    let (_, by_code) = post(
+        format!("{}/api/games/get_by_code", base),
+        json!({ "game_code": game_code, "simulated_items": [3115] }),
+    )
+    .await;
+    assert_eq!(by_code["data"], realtime["data"]);
+}
+
+#[tokio::test]
+async fn invalid_game_data_is_rejected() {
+    let base = spawn_backend(Faults::default()).await;
+    let (status, response) = post(
+        format!("{}/api/games/realtime", base),
+        json!({ "game_code": 1, "game_data": "" }),
+    )
+    .await;
+    assert_eq!(status, 200);
+    assert_eq!(response["success"], false);
+    assert!(response["data"].is_null());
+}
+
+#[tokio::test]
+async fn gzipped_uploads_are_accepted() {
+    let base = spawn_backend(Faults::default()).await;
+    let game_data =
+        std::fs::read_to_string(live_client::default_fixtures_dir().join("allgamedata.json"))
+            .unwrap();
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    serde_json::to_writer(
+        &mut encoder,
+        &json!({ "game_id": "mock-7", "game_code": 7, "game_data": game_data }),
+    )
+    .unwrap();
+    encoder.flush().unwrap();
+
+    let response = reqwest::Client::new()
+        .post(format!("{}/api/games/realtime", base))
+        .header("content-type", "application/json")
+        .header("content-encoding", "gzip")
+        .body(encoder.finish().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    let response: Value = response.json().await.unwrap();
+    assert_eq!(response["success"], true);
+}
+
+#[tokio::test]
+async fn faults_fail_every_nth_request_after_the_latency() {
+    let base = spawn_backend(Faults {
+        latency_ms: 50,
+        fail_every: 2,
+        fail_status: 503,
+        only: Some("/api/games".to_string()),
+        ..Default::default()
+    })
+    .await;
+
+    let started = Instant::now();
+    let (first, _) = get(format!("{}/api/games/create", base)).await;
+    assert!(started.elapsed() >= Duration::from_millis(50));
+    let (second, response) = get(format!("{}/api/games/create", base)).await;
+    let (unaffected, _) = get(format!("{}/api/static/items", base)).await;
+    assert_eq!((first, second, unaffected), (200, 503, 200));
+    assert_eq!(response["message"], "Injected failure");
+
+    let (_, faults) = post(format!("{}/mock/faults", base), json!({})).await;
+    assert_eq!(faults["fail_every"], 0);
+    let (status, _) = get(format!("{}/api/games/create", base)).await;
+    assert_eq!(status, 200);
+}
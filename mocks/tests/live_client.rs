@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+use tutorlolv2_mocks::live_client::{
+    default_fixtures_dir, router, Fixtures, LiveClient, LiveClientOptions,
+};
+
+async fn spawn(options: LiveClientOptions) -> String {
+    let fixtures = Fixtures::load(&default_fixtures_dir()).unwrap();
+    let live_client = Arc::new(LiveClient::new(fixtures, options));
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, router(live_client)).await });
+    format!("http://{}", address)
+}
+
+fn paused_at(start_at: f64) -> LiveClientOptions {
+    LiveClientOptions {
+        speed: 0.0,
+        start_at,
+        ..Default::default()
+    }
+}
+
+async fn get(url: String) -> (u16, Value) {
+    let response = reqwest::get(url).await.unwrap();
+    let status = response.status().as_u16();
+    (status, response.json().await.unwrap())
+}
+
+async fn post(url: String) -> Value {
+    let client = reqwest::Client::new();
+    client.post(url).send().await.unwrap().json().await.unwrap()
+}
+
+fn player<'a>(data: &'a Value, riot_id: &str) -> &'a Value {
+    data["allPlayers"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|p| p["riotId"] == riot_id)
+        .unwrap()
+}
+
+#[tokio::test]
+async fn serves_every_endpoint_from_the_same_snapshot() {
+    let base = spawn(paused_at(30.0)).await;
+
+    let (status, all) = get(format!("{}/liveclientdata/allgamedata", base)).await;
+    assert_eq!(status, 200);
+    assert_eq!(all["gameData"]["gameTime"], 30.0);
+    assert_eq!(all["allPlayers"].as_array().unwrap().len(), 10);
+
+    let (_, active) = get(format!("{}/liveclientdata/activeplayer", base)).await;
+    assert_eq!(active, all["activePlayer"]);
+    let (_, players) = get(format!("{}/liveclientdata/playerlist", base)).await;
+    assert_eq!(players, all["allPlayers"]);
+    let (_, events) = get(format!("{}/liveclientdata/eventdata", base)).await;
+    assert_eq!(events, all["events"]);
+}
+
+#[tokio::test]
+async fn timeline_steps_apply_in_order() {
+    let base = spawn(paused_at(0.0)).await;
+
+    post(format!("{}/mock/seek/400", base)).await;
+    let (_, data) = get(format!("{}/liveclientdata/allgamedata", base)).await;
+    let events = data["events"]["Events"].as_array().unwrap();
+    assert!(events.iter().any(|e| e["EventName"] == "FirstBlood"));
+    assert!(events
+        .windows(2)
+        .all(|w| w[0]["EventID"].as_u64() < w[1]["EventID"].as_u64()));
+    assert_eq!(player(&data, "Tutor#BR1")["scores"]["kills"], 1);
+    assert_eq!(player(&data, "Shadow#BR1")["isDead"], true);
+    assert_eq!(data["activePlayer"]["level"], 4);
+
+    post(format!("{}/mock/seek/1500", base)).await;
+    let (_, data) = get(format!("{}/liveclientdata/allgamedata", base)).await;
+    assert_eq!(player(&data, "Shadow#BR1")["isDead"], false);
+    assert_eq!(player(&data, "Tutor#BR1")["scores"]["kills"], 3);
+    assert_eq!(
+        player(&data, "Tutor#BR1")["items"]
+            .as_array()
+            .unwrap()
+            .len(),
+        6
+    );
+    assert_eq!(data["activePlayer"]["level"], 16);
+}
+
+#[tokio::test]
+async fn loading_screen_and_closed_game_are_not_found() {
+    let base = spawn(paused_at(-5.0)).await;
+    let (status, _) = get(format!("{}/liveclientdata/allgamedata", base)).await;
+    assert_eq!(status, 404);
+
+    post(format!("{}/mock/seek/10", base)).await;
+    let (status, _) = get(format!("{}/liveclientdata/allgamedata", base)).await;
+    assert_eq!(status, 200);
+
+    let state = post(format!("{}/mock/end", base)).await;
+    assert_eq!(state["closed"], true);
+    let (status, _) = get(format!("{}/liveclientdata/allgamedata", base)).await;
+    assert_eq!(status, 404);
+
+    post(format!("{}/mock/restart", base)).await;
+    let (status, data) = get(format!("{}/liveclientdata/allgamedata", base)).await;
+    assert_eq!(status, 200);
+    assert_eq!(data["gameData"]["gameTime"], 0.0);
+}
+
+#[tokio::test]
+async fn game_time_advances_with_speed() {
+    let base = spawn(LiveClientOptions {
+        speed: 600.0,
+        ..Default::default()
+    })
+    .await;
+
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    let state = post(format!("{}/mock/pause", base)).await;
+    let game_time = state["game_time"].as_f64().unwrap();
+    assert!(game_time >= 60.0, "game time was {}", game_time);
+
+    let (_, data) = get(format!("{}/liveclientdata/gamestats", base)).await;
+    assert_eq!(data["gameTime"].as_f64().unwrap(), game_time);
+}
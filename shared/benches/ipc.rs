@@ -0,0 +1,77 @@
+//! Compares the JSON path `get_realtime_game` used to take with the
+//! MessagePack one. Before, the command parsed the backend response into a
+//! `Value`, serialized it into a string for the IPC bridge and the frontend
+//! parsed that string into `Realtime`. Now the response is parsed once, sent
+//! as bytes and decoded straight into `Realtime`.
+//!
+//! Both paths run on the 10 player late game payload below. Run
+//! `cargo bench -p tutorlolv2-shared` for the timings, which also prints
+//! the size of each message.
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use serde_json::Value;
+use tutorlolv2_shared::{ipc, realtime::Realtime};
+
+const REALTIME: &str = include_str!("../../mocks/fixtures/backend/realtime.json");
+
+/// The fixture at 40 minutes, with every player at level 18 and every
+/// enemy compared against each recommended item.
+fn late_game_payload() -> String {
+    let mut payload: Value = serde_json::from_str(REALTIME).unwrap();
+    payload["game_information"]["game_time"] = 2400.0.into();
+    payload["current_player"]["level"] = 18.into();
+
+    let recommended_items = payload["recommended_items"].as_array().unwrap().clone();
+    let compared_item = payload["compared_items"]["3115"].clone();
+    for item_id in &recommended_items {
+        payload["compared_items"][item_id.to_string()] = compared_item.clone();
+    }
+    for enemy in payload["enemies"].as_array_mut().unwrap() {
+        enemy["level"] = 18.into();
+        let simulated = enemy["damages"]["compared_items"]["3115"].clone();
+        for item_id in &recommended_items {
+            enemy["damages"]["compared_items"][item_id.to_string()] = simulated.clone();
+        }
+    }
+    assert_eq!(payload["scoreboard"].as_array().unwrap().len(), 10);
+    serde_json::to_string(&payload).unwrap()
+}
+
+fn realtime_ipc(c: &mut Criterion) {
+    let body = late_game_payload();
+    let realtime: Realtime = serde_json::from_str(&body).unwrap();
+    let json_size = serde_json::to_string(&realtime).unwrap().len();
+    let msgpack_size = ipc::encode(&realtime).unwrap().len();
+    println!(
+        "late game payload: {} bytes as JSON, {} bytes as MessagePack",
+        json_size, msgpack_size
+    );
+
+    let mut group = c.benchmark_group("realtime_ipc");
+    group.bench_function("json_string", |b| {
+        b.iter_batched(
+            || body.clone(),
+            |body| {
+                let value: Value = serde_json::from_str(&body).unwrap();
+                let message = value.to_string();
+                serde_json::from_str::<Realtime>(&message).unwrap()
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("msgpack", |b| {
+        b.iter_batched(
+            || body.clone(),
+            |body| {
+                let realtime: Realtime = serde_json::from_str(&body).unwrap();
+                let message = ipc::encode(&realtime).unwrap();
+                ipc::decode::<Realtime>(&message).unwrap()
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, realtime_ipc);
+criterion_main!(benches);
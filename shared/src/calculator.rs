@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::realtime::{BasicStats, ComparedItem, Damages, RealResists, Stats};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct CurrentPlayerX {
+    pub champion_id: String,
+    pub damaging_abilities: HashMap<String, String>,
+    pub damaging_items: HashMap<String, String>,
+    pub damaging_runes: HashMap<String, String>,
+    pub level: usize,
+    pub base_stats: BasicStats,
+    pub bonus_stats: BasicStats,
+    pub current_stats: Stats,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct EnemyX {
+    pub champion_name: String,
+    pub champion_id: String,
+    pub level: usize,
+    pub damages: Damages,
+    pub base_stats: BasicStats,
+    pub bonus_stats: BasicStats,
+    pub current_stats: BasicStats,
+    pub real_resists: RealResists,
+}
+
+/// Response of `/api/games/calculator`.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Calculator {
+    pub current_player: CurrentPlayerX,
+    pub enemies: Vec<EnemyX>,
+    pub recommended_items: Vec<usize>,
+    pub compared_items: HashMap<usize, ComparedItem>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct AbilitiesX {
+    pub q: usize,
+    pub w: usize,
+    pub e: usize,
+    pub r: usize,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ActivePlayerX {
+    pub champion_id: String,
+    pub champion_stats: Stats,
+    pub abilities: AbilitiesX,
+    pub items: Vec<usize>,
+    pub runes: Vec<usize>,
+    pub level: usize,
+    pub stacks: usize,
+    /// Derive the stats from the champion, level and items instead of
+    /// reading `champion_stats`
+    pub infer_stats: bool,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct EnemyPlayersX {
+    pub champion_id: String,
+    pub items: Vec<usize>,
+    pub level: usize,
+    pub stats: BasicStats,
+    pub infer_stats: bool,
+}
+
+/// Request body of `/api/games/calculator`, sent under `game`.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct GameX {
+    pub active_player: ActivePlayerX,
+    pub enemy_players: Vec<EnemyPlayersX>,
+    pub ally_earth_dragons: usize,
+    pub ally_fire_dragons: usize,
+    pub enemy_earth_dragons: usize,
+    pub stack_exceptions: HashMap<usize, usize>,
+}
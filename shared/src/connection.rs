@@ -0,0 +1,170 @@
+//! Lifecycle of the realtime feed. The Tauri poller and the browser build
+//! drive the same transitions, and the frontend renders the resulting state.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+
+/// While no game is running the live client is asked less often.
+pub const WAITING_POLL_INTERVAL_MS: u64 = 2000;
+pub const MAX_BACKOFF_MS: u64 = 30_000;
+
+/// Polling only stops when the user stops it. Every error is retried, the
+/// ones that retrying soon would not fix at the slowest rate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum ConnectionState {
+    Idle,
+    /// The live client is not serving a game yet
+    WaitingForGame,
+    Live,
+    /// A request failed and is retried with exponential backoff
+    Reconnecting {
+        attempt: usize,
+        retry_in_ms: u64,
+        error: AppError,
+    },
+    /// The live client went away after a game was live. Polling goes on so
+    /// the next game is picked up automatically.
+    GameEnded,
+    /// The backend rejected the game or its response did not parse
+    Error {
+        error: AppError,
+        retry_in_ms: u64,
+    },
+}
+
+fn backoff(interval_ms: u64, attempt: usize) -> u64 {
+    let factor = 1u64 << attempt.saturating_sub(1).min(16);
+    interval_ms.saturating_mul(factor).min(MAX_BACKOFF_MS)
+}
+
+impl ConnectionState {
+    pub fn is_stopped(&self) -> bool {
+        matches!(self, Self::Idle)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Idle => "Idle",
+            Self::WaitingForGame => "Waiting for game",
+            Self::Live => "Live",
+            Self::Reconnecting { .. } => "Reconnecting",
+            Self::GameEnded => "Game ended",
+            Self::Error { .. } => "Error",
+        }
+    }
+
+    /// State after a fetch finished with `result`, and how many milliseconds
+    /// to wait before the next one.
+    pub fn next(&self, result: Result<(), &AppError>, interval_ms: u64) -> (Self, u64) {
+        let waiting_interval = interval_ms.max(WAITING_POLL_INTERVAL_MS);
+        match result {
+            Ok(()) => (Self::Live, interval_ms),
+            Err(AppError::LiveClientUnavailable) => match self {
+                Self::Live | Self::GameEnded => (Self::GameEnded, waiting_interval),
+                _ => (Self::WaitingForGame, waiting_interval),
+            },
+            Err(error) if error.is_retryable() => {
+                let attempt = match self {
+                    Self::Reconnecting { attempt, .. } => attempt + 1,
+                    _ => 1,
+                };
+                let delay = backoff(interval_ms, attempt);
+                (
+                    Self::Reconnecting {
+                        attempt,
+                        retry_in_ms: delay,
+                        error: error.clone(),
+                    },
+                    delay,
+                )
+            }
+            Err(error) => (
+                Self::Error {
+                    error: error.clone(),
+                    retry_in_ms: MAX_BACKOFF_MS,
+                },
+                MAX_BACKOFF_MS,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_live_client_going_away_ends_the_game() {
+        let unavailable = Err(&AppError::LiveClientUnavailable);
+        assert_eq!(
+            ConnectionState::Idle.next(unavailable, 1000),
+            (ConnectionState::WaitingForGame, WAITING_POLL_INTERVAL_MS)
+        );
+        assert_eq!(
+            ConnectionState::Live.next(unavailable, 1000),
+            (ConnectionState::GameEnded, WAITING_POLL_INTERVAL_MS)
+        );
+        assert_eq!(
+            ConnectionState::GameEnded.next(Ok(()), 1000),
+            (ConnectionState::Live, 1000)
+        );
+    }
+
+    #[test]
+    fn retryable_errors_back_off_up_to_the_cap_and_never_stop() {
+        let mut state = ConnectionState::Live;
+        let mut delays = Vec::new();
+        for _ in 0..100 {
+            let (next, delay) = state.next(Err(&AppError::BackendUnreachable), 1000);
+            assert!(!next.is_stopped());
+            delays.push(delay);
+            state = next;
+        }
+        assert_eq!(delays[..6], [1000, 2000, 4000, 8000, 16000, MAX_BACKOFF_MS]);
+        assert!(delays[6..].iter().all(|&delay| delay == MAX_BACKOFF_MS));
+        assert!(matches!(
+            state,
+            ConnectionState::Reconnecting { attempt: 100, .. }
+        ));
+
+        let (state, delay) = state.next(Err(&AppError::Timeout), 1000);
+        assert!(matches!(
+            state,
+            ConnectionState::Reconnecting {
+                attempt: 101,
+                error: AppError::Timeout,
+                ..
+            }
+        ));
+        assert_eq!(delay, MAX_BACKOFF_MS);
+        assert_eq!(state.next(Ok(()), 1000), (ConnectionState::Live, 1000));
+    }
+
+    #[test]
+    fn other_errors_are_retried_at_the_slowest_rate() {
+        let rejected = AppError::BackendRejected("Game not found".to_string());
+        let (state, delay) = ConnectionState::Live.next(Err(&rejected), 1000);
+        assert_eq!(
+            state,
+            ConnectionState::Error {
+                error: rejected,
+                retry_in_ms: MAX_BACKOFF_MS,
+            }
+        );
+        assert_eq!(delay, MAX_BACKOFF_MS);
+        assert!(!state.is_stopped());
+
+        let (state, _) = state.next(Err(&AppError::ParseError("eof".to_string())), 1000);
+        assert!(matches!(state, ConnectionState::Error { .. }));
+        assert_eq!(state.next(Ok(()), 1000), (ConnectionState::Live, 1000));
+    }
+
+    #[test]
+    fn backoff_does_not_overflow() {
+        assert_eq!(backoff(u64::MAX, 1), MAX_BACKOFF_MS);
+        assert_eq!(backoff(1000, usize::MAX), MAX_BACKOFF_MS);
+        assert_eq!(backoff(500, 1), 500);
+    }
+}
@@ -0,0 +1,102 @@
+//! Errors returned by the Tauri commands. The realtime feed also produces
+//! them in the browser build for its own HTTP failures.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Serialized as `{ "kind": "...", "message": ... }` so the frontend can
+/// match on it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "message", rename_all = "snake_case")]
+pub enum AppError {
+    /// No game is running, the client is still on the loading screen or it
+    /// is spectating a game
+    LiveClientUnavailable,
+    /// The TutorLoL server could not be reached
+    BackendUnreachable,
+    /// The server answered with `success: false`
+    BackendRejected(String),
+    Timeout,
+    ParseError(String),
+    /// An argument of a command was rejected, such as a malformed url or a
+    /// conflicting hotkey
+    InvalidInput(String),
+    /// Reading or writing a file of the app failed
+    Storage(String),
+    /// A window could not be created or changed
+    Window(String),
+    /// The local server could not start
+    LocalServer(String),
+}
+
+impl AppError {
+    /// Whether trying again soon may succeed. A rejected request or a
+    /// payload that does not parse fails the same way until something else
+    /// changes, such as the server being updated.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::LiveClientUnavailable | Self::BackendUnreachable | Self::Timeout
+        )
+    }
+
+    /// Message shown to the user.
+    pub fn description(&self) -> String {
+        match self {
+            Self::LiveClientUnavailable => {
+                "Waiting for the game. The live client is not available yet".to_string()
+            }
+            Self::BackendUnreachable => "The TutorLoL server could not be reached".to_string(),
+            Self::BackendRejected(message) => format!("The server rejected the game: {}", message),
+            Self::Timeout => "The request timed out".to_string(),
+            Self::ParseError(message) => format!("Received an invalid response: {}", message),
+            Self::InvalidInput(message) => message.clone(),
+            Self::Storage(message) => format!("Failed to access the app files: {}", message),
+            Self::Window(message) => format!("Failed to update the window: {}", message),
+            Self::LocalServer(message) => format!("The local server could not start: {}", message),
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LiveClientUnavailable => write!(f, "Live client is unavailable"),
+            Self::BackendUnreachable => write!(f, "Backend is unreachable"),
+            Self::BackendRejected(message) => {
+                write!(f, "Backend rejected the request: {}", message)
+            }
+            Self::Timeout => write!(f, "Request timed out"),
+            Self::ParseError(message) => write!(f, "Failed to parse response: {}", message),
+            Self::InvalidInput(message) => write!(f, "Invalid input: {}", message),
+            Self::Storage(message) => write!(f, "Storage error: {}", message),
+            Self::Window(message) => write!(f, "Window error: {}", message),
+            Self::LocalServer(message) => write!(f, "Local server error: {}", message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn errors_are_tagged_by_kind() {
+        let error = AppError::InvalidInput("Invalid backend url: ftp://".to_string());
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["kind"], "invalid_input");
+        assert_eq!(json["message"], "Invalid backend url: ftp://");
+        assert_eq!(serde_json::from_value::<AppError>(json).unwrap(), error);
+
+        let json = serde_json::to_value(AppError::BackendUnreachable).unwrap();
+        assert_eq!(json, serde_json::json!({ "kind": "backend_unreachable" }));
+    }
+
+    #[test]
+    fn only_connection_errors_are_retried() {
+        assert!(AppError::Timeout.is_retryable());
+        assert!(!AppError::Storage("Access denied".to_string()).is_retryable());
+        assert!(!AppError::Window("Failed to create the window".to_string()).is_retryable());
+    }
+}
@@ -0,0 +1,16 @@
+//! Encoding of the payloads the Tauri app sends to its frontend every poll.
+//! MessagePack keeps the field names, so optional fields skipped when
+//! serializing still decode, while being smaller and faster to read than
+//! JSON.
+
+use serde::{de::DeserializeOwned, Serialize};
+
+pub use rmp_serde::{decode::Error as DecodeError, encode::Error as EncodeError};
+
+pub fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, EncodeError> {
+    rmp_serde::to_vec_named(value)
+}
+
+pub fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, DecodeError> {
+    rmp_serde::from_slice(bytes)
+}
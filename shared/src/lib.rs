@@ -0,0 +1,20 @@
+//! Payloads exchanged with the TutorLoL server and between the Tauri app and
+//! the Yew frontend, shared by both so they read them the same way.
+
+use serde::{Deserialize, Serialize};
+
+pub mod calculator;
+pub mod connection;
+pub mod error;
+pub mod ipc;
+pub mod realtime;
+pub mod replay;
+
+/// Envelope of every server response. `data` is usually an `Option`, since
+/// it is `null` when `success` is false.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ServerResponse<T> {
+    pub success: bool,
+    pub message: Option<String>,
+    pub data: T,
+}
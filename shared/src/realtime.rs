@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Stats {
+    pub ability_power: f64,
+    pub armor: f64,
+    pub armor_penetration_flat: f64,
+    pub armor_penetration_percent: f64,
+    pub attack_damage: f64,
+    pub attack_range: f64,
+    pub attack_speed: f64,
+    pub crit_chance: f64,
+    pub crit_damage: f64,
+    pub current_health: f64,
+    pub magic_penetration_flat: f64,
+    pub magic_penetration_percent: f64,
+    pub magic_resist: f64,
+    pub max_health: f64,
+    pub max_mana: f64,
+    pub current_mana: f64,
+}
+
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
+pub struct BasicStats {
+    pub armor: f64,
+    pub health: f64,
+    pub attack_damage: f64,
+    pub magic_resist: f64,
+    pub mana: f64,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct CurrentPlayer {
+    pub damaging_abilities: HashMap<String, String>,
+    pub damaging_items: HashMap<String, String>,
+    pub damaging_runes: HashMap<String, String>,
+    pub riot_id: String,
+    pub level: usize,
+    pub team: String,
+    pub position: String,
+    pub champion_name: String,
+    pub champion_id: String,
+    pub base_stats: BasicStats,
+    pub bonus_stats: BasicStats,
+    pub current_stats: Stats,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct GameInformation {
+    pub game_time: f64,
+    pub map_number: usize,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct InstanceDamage {
+    pub minimum_damage: f64,
+    pub maximum_damage: f64,
+    pub damage_type: String,
+    pub damages_in_area: bool,
+    pub damages_onhit: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_dmg_change: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_dmg_change: Option<f64>,
+}
+
+pub type DamageLike = HashMap<String, InstanceDamage>;
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Damages {
+    pub abilities: DamageLike,
+    pub items: DamageLike,
+    pub runes: DamageLike,
+    pub compared_items: HashMap<String, SimulatedDamages>,
+}
+
+/// Damage instance of the combo stacked on the Realtime page.
+pub trait StackedInstance {
+    /// `abilities`, `items` or `runes`
+    fn source(&self) -> &str;
+    fn keyname(&self) -> &str;
+    fn is_maximum(&self) -> bool;
+}
+
+impl Damages {
+    /// Damage taken from every instance in `stack`. Instances these damages
+    /// do not have add nothing.
+    pub fn stacked_damage<S: StackedInstance>(&self, stack: &[S]) -> f64 {
+        stack
+            .iter()
+            .filter_map(|instance| {
+                let damages = match instance.source() {
+                    "abilities" => &self.abilities,
+                    "items" => &self.items,
+                    "runes" => &self.runes,
+                    _ => return None,
+                };
+                let damage = damages.get(instance.keyname())?;
+                if instance.is_maximum() {
+                    Some(damage.maximum_damage)
+                } else {
+                    Some(damage.minimum_damage)
+                }
+            })
+            .sum()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ComparedDamage {
+    pub total: f64,
+    pub change: f64,
+    pub damages: DamageLike,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct SimulatedDamages {
+    pub abilities: ComparedDamage,
+    pub items: ComparedDamage,
+    pub runes: ComparedDamage,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct DragonMultipliers {
+    pub earth: f64,
+    pub fire: f64,
+    pub chemtech: f64,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Enemy {
+    pub champion_id: String,
+    pub champion_name: String,
+    pub riot_id: String,
+    pub team: String,
+    pub level: usize,
+    pub position: String,
+    pub damages: Damages,
+    pub base_stats: BasicStats,
+    pub bonus_stats: BasicStats,
+    pub current_stats: BasicStats,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ComparedItem {
+    pub name: String,
+    pub gold_cost: usize,
+    pub prettified_stats: HashMap<String, Value>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct RealResists {
+    pub magic_resist: f64,
+    pub armor: f64,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Scoreboard {
+    pub assists: usize,
+    pub creep_score: usize,
+    pub deaths: usize,
+    pub kills: usize,
+    pub riot_id: String,
+    pub champion_id: Option<String>,
+    pub champion_name: String,
+    pub team: String,
+    pub position: String,
+}
+
+/// Response of `/api/games/realtime` and `/api/games/get_by_code`.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Realtime {
+    pub current_player: CurrentPlayer,
+    pub enemies: Vec<Enemy>,
+    pub game_information: GameInformation,
+    pub recommended_items: Vec<usize>,
+    pub compared_items: HashMap<String, ComparedItem>,
+    pub scoreboard: Vec<Scoreboard>,
+    pub enemy_dragon_multipliers: DragonMultipliers,
+    pub ally_dragon_multipliers: DragonMultipliers,
+}
@@ -0,0 +1,34 @@
+//! Recorded games sent to the frontend for replaying.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::realtime::Realtime;
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordSource {
+    /// Raw response of the live client `allgamedata` endpoint
+    AllGameData,
+    /// `Realtime` returned by the backend for that payload
+    Realtime,
+}
+
+/// One line of a recording file, written by the native recorder.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct RecordEntry {
+    pub game_time: f64,
+    pub recorded_at: u64,
+    pub source: RecordSource,
+    pub payload: Value,
+}
+
+/// `Realtime` frames of a recording in the order they were recorded, one
+/// frame per game time. Recordings grow to hundreds of frames, so they are
+/// read a page at a time.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ReplayPage {
+    pub frames: Vec<Realtime>,
+    /// Offset of the next page, or `None` after the last one
+    pub next_offset: Option<usize>,
+}
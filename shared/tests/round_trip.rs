@@ -0,0 +1,81 @@
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+use tutorlolv2_shared::{
+    calculator::{Calculator, GameX},
+    ipc,
+    realtime::Realtime,
+    ServerResponse,
+};
+
+const REALTIME: &str = include_str!("../../mocks/fixtures/backend/realtime.json");
+const CALCULATOR: &str = include_str!("../../mocks/fixtures/backend/calculator.json");
+const GAME: &str = include_str!("fixtures/game.json");
+
+/// Parses `fixture` as `T`, then checks that serializing it gives back the
+/// same JSON and that the JSON parses into the same value again.
+fn round_trip<T>(fixture: &str) -> T
+where
+    T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug,
+{
+    let parsed: T = serde_json::from_str(fixture).unwrap();
+    let serialized = serde_json::to_value(&parsed).unwrap();
+    assert_eq!(serialized, serde_json::from_str::<Value>(fixture).unwrap());
+    assert_eq!(serde_json::from_value::<T>(serialized).unwrap(), parsed);
+    parsed
+}
+
+#[test]
+fn realtime_fixture_round_trips() {
+    let realtime = round_trip::<Realtime>(REALTIME);
+    assert!(!realtime.enemies.is_empty());
+    assert!(!realtime.scoreboard.is_empty());
+}
+
+#[test]
+fn calculator_fixture_round_trips() {
+    let calculator = round_trip::<Calculator>(CALCULATOR);
+    assert_eq!(calculator.current_player.champion_id, "Gnar");
+}
+
+#[test]
+fn game_request_round_trips() {
+    let game = round_trip::<GameX>(GAME);
+    assert_eq!(game.ally_fire_dragons, 2);
+    assert_eq!(game.stack_exceptions.get(&3042), Some(&360));
+}
+
+#[test]
+fn dragon_counts_are_never_negative() {
+    let mut game: Value = serde_json::from_str(GAME).unwrap();
+    game["enemy_earth_dragons"] = json!(-1);
+    assert!(serde_json::from_value::<GameX>(game).is_err());
+}
+
+#[test]
+fn server_response_wraps_a_realtime() {
+    let body = format!(r#"{{"success":true,"message":null,"data":{}}}"#, REALTIME);
+    let response = round_trip::<ServerResponse<Option<Realtime>>>(&body);
+    assert!(response.success);
+    assert!(response.data.is_some());
+}
+
+#[test]
+fn rejected_server_response_has_no_data() {
+    let response = round_trip::<ServerResponse<Option<Realtime>>>(
+        r#"{"success":false,"message":"Game not found","data":null}"#,
+    );
+    assert_eq!(response.message.as_deref(), Some("Game not found"));
+    assert!(response.data.is_none());
+
+    let response: ServerResponse<Option<Realtime>> =
+        serde_json::from_str(r#"{"success":false,"message":"Game not found"}"#).unwrap();
+    assert!(response.data.is_none());
+}
+
+#[test]
+fn realtime_survives_the_ipc_encoding() {
+    let realtime: Realtime = serde_json::from_str(REALTIME).unwrap();
+    let bytes = ipc::encode(&realtime).unwrap();
+    assert!(bytes.len() < REALTIME.len());
+    assert_eq!(ipc::decode::<Realtime>(&bytes).unwrap(), realtime);
+}
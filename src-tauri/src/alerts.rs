@@ -0,0 +1,271 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Mutex, RwLock},
+    time::{Duration, Instant},
+};
+
+use log::error;
+use serde::Deserialize;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_notification::NotificationExt;
+use tutorlolv2_shared::realtime::{Realtime, StackedInstance};
+
+use crate::{settings::KillAlertSettings, AppState};
+
+pub const MIN_ALERT_COOLDOWN_SECS: u64 = 5;
+pub const MAX_ALERT_COOLDOWN_SECS: u64 = 600;
+
+#[cfg(windows)]
+const ALERT_SOUND: &str = "Default";
+#[cfg(target_os = "macos")]
+const ALERT_SOUND: &str = "Ping";
+#[cfg(not(any(windows, target_os = "macos")))]
+const ALERT_SOUND: &str = "message-new-instant";
+
+/// Fields of a `StackInstance` sent by the Realtime page that matter here.
+#[derive(Deserialize)]
+struct StackEntry {
+    keyname: String,
+    source: String,
+    is_maximum: bool,
+}
+
+struct KillableEnemy {
+    champion_id: String,
+    champion_name: String,
+    damage: f64,
+    health: f64,
+}
+
+impl StackedInstance for StackEntry {
+    fn source(&self) -> &str {
+        &self.source
+    }
+
+    fn keyname(&self) -> &str {
+        &self.keyname
+    }
+
+    fn is_maximum(&self) -> bool {
+        self.is_maximum
+    }
+}
+
+/// Enemies whose current health the combo reaches or exceeds.
+fn killable_enemies(realtime: &Realtime, stack: &[StackEntry]) -> Vec<KillableEnemy> {
+    realtime
+        .enemies
+        .iter()
+        .filter_map(|enemy| {
+            let health = enemy.current_stats.health;
+            let damage = enemy.damages.stacked_damage(stack);
+            if health <= 0.0 || damage < health {
+                return None;
+            }
+            Some(KillableEnemy {
+                champion_id: enemy.champion_id.clone(),
+                champion_name: enemy.champion_name.clone(),
+                damage,
+                health,
+            })
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct Tracker {
+    /// Enemies the combo killed on the last check
+    killable: HashSet<String>,
+    last_alert: HashMap<String, Instant>,
+}
+
+impl Tracker {
+    /// Records the enemies the combo kills at `now` and returns the ones to
+    /// announce, which just became killable and were not announced within
+    /// `cooldown`.
+    fn observe<'a>(
+        &mut self,
+        killable: &'a [KillableEnemy],
+        cooldown: Duration,
+        now: Instant,
+    ) -> Vec<&'a KillableEnemy> {
+        let announced = killable
+            .iter()
+            .filter(|enemy| {
+                let newly_killable = !self.killable.contains(&enemy.champion_id);
+                let cooled_down = self
+                    .last_alert
+                    .get(&enemy.champion_id)
+                    .is_none_or(|&at| now.duration_since(at) >= cooldown);
+                newly_killable && cooled_down
+            })
+            .collect::<Vec<_>>();
+        for enemy in &announced {
+            self.last_alert.insert(enemy.champion_id.clone(), now);
+        }
+        self.killable = killable
+            .iter()
+            .map(|enemy| enemy.champion_id.clone())
+            .collect();
+        announced
+    }
+}
+
+/// Notifies when the damage stack of the Realtime page is enough to kill an
+/// enemy. Checked from the poller and whenever the stack changes, so alerts
+/// keep coming while the window is hidden. An enemy is announced when it
+/// becomes killable, at most once per cooldown.
+pub struct KillAlerts {
+    settings: RwLock<KillAlertSettings>,
+    /// Last `Realtime` response, checked again when the stack changes
+    latest: Mutex<Option<Realtime>>,
+    tracker: Mutex<Tracker>,
+}
+
+impl KillAlerts {
+    pub fn new(settings: KillAlertSettings) -> Self {
+        Self {
+            settings: RwLock::new(settings),
+            latest: Mutex::new(None),
+            tracker: Mutex::new(Tracker::default()),
+        }
+    }
+
+    pub fn settings(&self) -> KillAlertSettings {
+        self.settings.read().unwrap().clone()
+    }
+
+    pub fn set_settings(&self, settings: KillAlertSettings) {
+        *self.settings.write().unwrap() = settings;
+    }
+
+    /// Forgets the previous game, such as when polling another code.
+    pub fn reset(&self) {
+        *self.latest.lock().unwrap() = None;
+        *self.tracker.lock().unwrap() = Tracker::default();
+    }
+
+    pub fn update(&self, app: &AppHandle, realtime: Realtime) {
+        *self.latest.lock().unwrap() = Some(realtime);
+        self.check(app);
+    }
+
+    pub fn check(&self, app: &AppHandle) {
+        let settings = self.settings();
+        let state = app.state::<AppState>();
+        let stack =
+            serde_json::from_value::<Vec<StackEntry>>(state.settings.read().ui.stack.clone())
+                .unwrap_or_default();
+        let killable = match self.latest.lock().unwrap().as_ref() {
+            Some(realtime) if settings.enabled && !stack.is_empty() => {
+                killable_enemies(realtime, &stack)
+            }
+            _ => Vec::new(),
+        };
+
+        let cooldown = Duration::from_secs(settings.cooldown_secs);
+        let mut tracker = self.tracker.lock().unwrap();
+        for enemy in tracker.observe(&killable, cooldown, Instant::now()) {
+            notify(app, enemy, settings.sound);
+        }
+    }
+}
+
+fn notify(app: &AppHandle, enemy: &KillableEnemy, sound: bool) {
+    let mut builder = app
+        .notification()
+        .builder()
+        .title(format!("{} is killable", enemy.champion_name))
+        .body(format!(
+            "Your combo deals {:.0} damage to {:.0} health",
+            enemy.damage, enemy.health
+        ));
+    if sound {
+        builder = builder.sound(ALERT_SOUND);
+    }
+    if let Err(e) = builder.show() {
+        error!("Failed to show the kill alert: {}", e);
+    }
+}
+
+pub fn clamp_cooldown(cooldown_secs: u64) -> u64 {
+    cooldown_secs.clamp(MIN_ALERT_COOLDOWN_SECS, MAX_ALERT_COOLDOWN_SECS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REALTIME: &str = include_str!("../../mocks/fixtures/backend/realtime.json");
+    const COOLDOWN: Duration = Duration::from_secs(30);
+
+    /// Q and W at their maximum damage, about 1200 against Zed.
+    fn combo() -> Vec<StackEntry> {
+        ["Q", "W"]
+            .into_iter()
+            .map(|keyname| StackEntry {
+                keyname: keyname.to_string(),
+                source: "abilities".to_string(),
+                is_maximum: true,
+            })
+            .collect()
+    }
+
+    /// The fixture with Zed at `health`.
+    fn realtime_with_zed_at(health: f64) -> Realtime {
+        let mut realtime: Realtime = serde_json::from_str(REALTIME).unwrap();
+        let zed = realtime
+            .enemies
+            .iter_mut()
+            .find(|enemy| enemy.champion_id == "Zed")
+            .unwrap();
+        zed.current_stats.health = health;
+        realtime
+    }
+
+    #[test]
+    fn an_enemy_in_kill_range_is_announced() {
+        let killable = killable_enemies(&realtime_with_zed_at(900.0), &combo());
+        assert_eq!(killable.len(), 1);
+        assert_eq!(killable[0].champion_id, "Zed");
+        assert!(killable[0].damage >= killable[0].health);
+
+        let announced = Tracker::default().observe(&killable, COOLDOWN, Instant::now());
+        assert_eq!(announced.len(), 1);
+    }
+
+    #[test]
+    fn enemies_above_the_combo_damage_are_not_announced() {
+        let realtime: Realtime = serde_json::from_str(REALTIME).unwrap();
+        assert!(killable_enemies(&realtime, &combo()).is_empty());
+        assert!(killable_enemies(&realtime_with_zed_at(1300.0), &combo()).is_empty());
+    }
+
+    #[test]
+    fn an_enemy_is_announced_once_per_cooldown() {
+        let killable = killable_enemies(&realtime_with_zed_at(900.0), &combo());
+        let mut tracker = Tracker::default();
+        let start = Instant::now();
+        assert_eq!(tracker.observe(&killable, COOLDOWN, start).len(), 1);
+        // Still killable on the next response
+        let next_poll = start + Duration::from_secs(1);
+        assert!(tracker.observe(&killable, COOLDOWN, next_poll).is_empty());
+
+        // Healed out of range and back into it within the cooldown
+        assert!(tracker
+            .observe(&[], COOLDOWN, start + Duration::from_secs(5))
+            .is_empty());
+        let back_in_range = start + Duration::from_secs(10);
+        assert!(tracker
+            .observe(&killable, COOLDOWN, back_in_range)
+            .is_empty());
+
+        assert!(tracker
+            .observe(&[], COOLDOWN, start + Duration::from_secs(20))
+            .is_empty());
+        assert_eq!(
+            tracker.observe(&killable, COOLDOWN, start + COOLDOWN).len(),
+            1
+        );
+    }
+}
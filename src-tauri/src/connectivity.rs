@@ -0,0 +1,254 @@
+use std::{collections::HashMap, error::Error, time::Instant};
+
+use reqwest::{RequestBuilder, StatusCode};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+use tutorlolv2_shared::{realtime::Realtime, ServerResponse};
+
+use crate::{error::AppError, live_client, realtime, session::GameCode, AppState};
+
+/// Static data fetched by the frontend at startup, with the keys they use
+const STATIC_ENDPOINTS: [&str; 3] = ["champions", "items", "runes"];
+
+/// Outcome of the TLS handshake of a probe.
+#[derive(Serialize)]
+#[serde(tag = "result", content = "message", rename_all = "snake_case")]
+pub enum TlsResult {
+    /// Plain http, such as a mock live client
+    NotUsed,
+    Established,
+    Failed(String),
+    /// The connection failed before a handshake was attempted
+    NotReached,
+}
+
+/// Whether a response body deserializes into the type the app reads it as.
+#[derive(Serialize)]
+pub struct ParseCheck {
+    pub target: &'static str,
+    pub error: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct ProbeResult {
+    pub name: String,
+    pub url: String,
+    pub latency_ms: Option<u64>,
+    pub status: Option<u16>,
+    pub tls: TlsResult,
+    pub checks: Vec<ParseCheck>,
+    /// Why the request could not be made or was skipped
+    pub error: Option<String>,
+}
+
+impl ProbeResult {
+    fn new(name: &str, url: String) -> Self {
+        Self {
+            name: name.to_string(),
+            url,
+            latency_ms: None,
+            status: None,
+            tls: TlsResult::NotReached,
+            checks: Vec::new(),
+            error: None,
+        }
+    }
+
+    fn skipped(name: &str, url: String, reason: &str) -> Self {
+        Self {
+            error: Some(reason.to_string()),
+            ..Self::new(name, url)
+        }
+    }
+}
+
+/// reqwest only prints the outermost error, while the cause of a failed
+/// handshake is a few sources down.
+fn error_chain(e: &dyn Error) -> String {
+    let mut message = e.to_string();
+    let mut source = e.source();
+    while let Some(cause) = source {
+        message.push_str(": ");
+        message.push_str(&cause.to_string());
+        source = cause.source();
+    }
+    message
+}
+
+fn tls_failure(message: &str) -> bool {
+    let message = message.to_lowercase();
+    ["certificate", "tls", "handshake", "ssl"]
+        .iter()
+        .any(|word| message.contains(word))
+}
+
+fn check<T: DeserializeOwned>(target: &'static str, body: &str) -> ParseCheck {
+    ParseCheck {
+        target,
+        error: serde_json::from_str::<T>(body).err().map(|e| e.to_string()),
+    }
+}
+
+/// Sends `request` and times it until the whole body has been read.
+async fn probe(name: &str, url: String, request: RequestBuilder) -> (ProbeResult, Option<String>) {
+    let https = url.starts_with("https://");
+    let started = Instant::now();
+    let mut result = ProbeResult::new(name, url);
+
+    let response = match request.send().await {
+        Ok(response) => response,
+        Err(e) => {
+            let message = error_chain(&e);
+            result.tls = match https {
+                false => TlsResult::NotUsed,
+                true if tls_failure(&message) => TlsResult::Failed(message.clone()),
+                true => TlsResult::NotReached,
+            };
+            result.error = Some(message);
+            return (result, None);
+        }
+    };
+    result.tls = match https {
+        true => TlsResult::Established,
+        false => TlsResult::NotUsed,
+    };
+    result.status = Some(response.status().as_u16());
+    let success = response.status().is_success();
+
+    let body = response.text().await;
+    result.latency_ms = Some(started.elapsed().as_millis() as u64);
+    match body {
+        Ok(body) if success => (result, Some(body)),
+        Ok(_) => (result, None),
+        Err(e) => {
+            result.error = Some(error_chain(&e));
+            (result, None)
+        }
+    }
+}
+
+/// Probes the live client, game creation, the game code of this app, the
+/// static endpoints and, when a game is running, the realtime endpoint. Two
+/// of them write to the backend: game creation registers a code nothing
+/// uses, and the realtime probe uploads the running game under the code of
+/// this app, the same as polling does.
+pub async fn run(state: &AppState) -> Vec<ProbeResult> {
+    let backend_url = state.backend_url();
+    let live_client_url = format!("{}/liveclientdata/allgamedata", state.live_client_url);
+    let create_url = format!("{}/api/games/create", backend_url);
+    let game_code_url = format!("{}/api/games/get_by_code", backend_url);
+    let game_code = state.session.current();
+
+    let (mut live_client, game_data) = probe(
+        "Live client",
+        live_client_url.clone(),
+        state.live_client.get(&live_client_url),
+    )
+    .await;
+    let game_data = match game_data.map(|body| (live_client::parse(&body), body)) {
+        Some((Ok(_), body)) => {
+            live_client.checks.push(ParseCheck {
+                target: "AllGameData",
+                error: None,
+            });
+            Some(body)
+        }
+        Some((Err(AppError::LiveClientUnavailable), _)) => {
+            live_client.error = Some("The game is still loading or spectated".to_string());
+            None
+        }
+        Some((Err(e), _)) => {
+            live_client.checks.push(ParseCheck {
+                target: "AllGameData",
+                error: Some(e.to_string()),
+            });
+            None
+        }
+        None => {
+            if live_client.status == Some(StatusCode::NOT_FOUND.as_u16()) {
+                live_client.error = Some("The game is still loading".to_string());
+            }
+            None
+        }
+    };
+
+    let mut results = vec![live_client];
+    let (mut create, body) = probe(
+        "Game creation",
+        create_url.clone(),
+        state.backend_client.get(&create_url),
+    )
+    .await;
+    if let Some(body) = body {
+        create
+            .checks
+            .push(check::<ServerResponse<Option<GameCode>>>(
+                "ServerResponse",
+                &body,
+            ));
+    }
+    results.push(create);
+
+    results.push(match &game_code {
+        Some(code) => {
+            let request = state.backend_client.post(&game_code_url).json(&json!({
+                "game_code": code.game_code,
+                "simulated_items": [],
+            }));
+            let (mut result, body) = probe("Game code", game_code_url, request).await;
+            if let Some(body) = body {
+                result.checks.push(check::<ServerResponse<Option<Value>>>(
+                    "ServerResponse",
+                    &body,
+                ));
+            }
+            result
+        }
+        None => ProbeResult::skipped("Game code", game_code_url, "No game code yet"),
+    });
+
+    for path_name in STATIC_ENDPOINTS {
+        let url = format!("{}/api/static/{}", backend_url, path_name);
+        let (mut result, body) = probe(
+            &format!("Static {}", path_name),
+            url.clone(),
+            state.backend_client.get(&url),
+        )
+        .await;
+        if let Some(body) = body {
+            // Champions are keyed by name, items and runes by id
+            result.checks.push(match path_name {
+                "champions" => {
+                    check::<ServerResponse<HashMap<String, String>>>("ServerResponse", &body)
+                }
+                _ => check::<ServerResponse<HashMap<usize, String>>>("ServerResponse", &body),
+            });
+        }
+        results.push(result);
+    }
+
+    let realtime_url = format!("{}/api/games/realtime", backend_url);
+    results.push(match (game_data, game_code) {
+        (Some(game_data), Some(code)) => {
+            match realtime::realtime_request(state, &code, &game_data, &[]) {
+                Ok(request) => {
+                    let (mut result, body) = probe("Realtime", realtime_url, request).await;
+                    if let Some(body) = body {
+                        result.checks.push(check::<ServerResponse<Option<Value>>>(
+                            "ServerResponse",
+                            &body,
+                        ));
+                        result
+                            .checks
+                            .push(check::<ServerResponse<Option<Realtime>>>("Realtime", &body));
+                    }
+                    result
+                }
+                Err(e) => ProbeResult::skipped("Realtime", realtime_url, &e.to_string()),
+            }
+        }
+        (None, _) => ProbeResult::skipped("Realtime", realtime_url, "Needs a running game"),
+        (_, None) => ProbeResult::skipped("Realtime", realtime_url, "Needs a game code"),
+    });
+    results
+}
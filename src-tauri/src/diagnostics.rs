@@ -0,0 +1,295 @@
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use log::LevelFilter;
+use serde_json::{json, Value};
+use tauri::{plugin::TauriPlugin, AppHandle, Manager, Runtime};
+use tauri_plugin_log::{RotationStrategy, Target, TargetKind};
+use zip::{write::SimpleFileOptions, ZipWriter};
+
+use crate::{
+    error::{self, AppError},
+    settings::Settings,
+    AppState,
+};
+
+pub const LOG_FILE_NAME: &str = "tutorlol";
+/// Recorded payloads attached to a bundle
+const RECENT_PAYLOADS: usize = 10;
+const REDACTED: &str = "<redacted>";
+/// Fields naming a player in live client payloads and `Realtime` responses
+const PLAYER_KEYS: [&str; 4] = ["riotId", "riotIdGameName", "summonerName", "riot_id"];
+/// Fields of live client events naming who took part, which may also be a
+/// turret or a minion
+const EVENT_PLAYER_KEYS: [&str; 5] = ["KillerName", "VictimName", "Assisters", "Recipient", "Acer"];
+const MAX_LOG_FILE_SIZE: u128 = 2 * 1024 * 1024;
+const KEPT_LOG_FILES: usize = 4;
+
+/// Logs from both the backend and the forwarded frontend records go to
+/// stdout and to a log file in the app log directory, which is rotated once
+/// it grows past 2 MB.
+pub fn log_plugin<R: Runtime>() -> TauriPlugin<R> {
+    let level = if cfg!(debug_assertions) {
+        LevelFilter::Debug
+    } else {
+        LevelFilter::Info
+    };
+    tauri_plugin_log::Builder::new()
+        .clear_targets()
+        .target(Target::new(TargetKind::Stdout))
+        .target(Target::new(TargetKind::LogDir {
+            file_name: Some(LOG_FILE_NAME.to_string()),
+        }))
+        .level(level)
+        // Dependencies are only worth reading when something is wrong
+        .level_for("reqwest", LevelFilter::Warn)
+        .level_for("hyper", LevelFilter::Warn)
+        .level_for("tao", LevelFilter::Warn)
+        .max_file_size(MAX_LOG_FILE_SIZE)
+        .rotation_strategy(RotationStrategy::KeepSome(KEPT_LOG_FILES))
+        .build()
+}
+
+/// Settings without anything that identifies the user or their games, such as
+/// a self-hosted backend url or the recent game codes.
+fn redacted_settings(settings: &Settings) -> Value {
+    let mut value = serde_json::to_value(settings).unwrap_or_default();
+    if settings.backend_url.is_some() {
+        value["backend_url"] = json!(REDACTED);
+    }
+    value["recent_game_codes"] = json!(settings.recent_game_codes.len());
+    value
+}
+
+/// Replaces the players named in recorded payloads with "Player 1", "Player
+/// 2" and so on, the same alias for a player everywhere so kills can still
+/// be followed. Turrets and minions named by events are kept.
+#[derive(Default)]
+struct PlayerAliases {
+    /// Aliases by Riot id game name, the part before `#`
+    aliases: HashMap<String, String>,
+}
+
+impl PlayerAliases {
+    fn game_name(name: &str) -> &str {
+        name.split('#').next().unwrap_or(name)
+    }
+
+    /// Gives an alias to every player in `value`.
+    fn collect(&mut self, value: &Value) {
+        match value {
+            Value::Object(object) => {
+                for (key, value) in object {
+                    match value.as_str() {
+                        Some(name) if PLAYER_KEYS.contains(&key.as_str()) => {
+                            let next = format!("Player {}", self.aliases.len() + 1);
+                            self.aliases
+                                .entry(Self::game_name(name).to_string())
+                                .or_insert(next);
+                        }
+                        _ => self.collect(value),
+                    }
+                }
+            }
+            Value::Array(values) => values.iter().for_each(|value| self.collect(value)),
+            _ => {}
+        }
+    }
+
+    fn alias(&self, name: &mut Value) {
+        let alias = name
+            .as_str()
+            .and_then(|name| self.aliases.get(Self::game_name(name)));
+        if let Some(alias) = alias {
+            *name = json!(alias);
+        }
+    }
+
+    fn redact(&self, value: &mut Value) {
+        match value {
+            Value::Object(object) => {
+                for (key, value) in object.iter_mut() {
+                    let key = key.as_str();
+                    if key == "riotIdTagLine" {
+                        *value = json!(REDACTED);
+                    } else if PLAYER_KEYS.contains(&key) || EVENT_PLAYER_KEYS.contains(&key) {
+                        match value {
+                            Value::Array(names) => {
+                                names.iter_mut().for_each(|name| self.alias(name))
+                            }
+                            name => self.alias(name),
+                        }
+                    } else {
+                        self.redact(value);
+                    }
+                }
+            }
+            Value::Array(values) => values.iter_mut().for_each(|value| self.redact(value)),
+            _ => {}
+        }
+    }
+}
+
+/// Recording lines with the players replaced by aliases. Lines that do not
+/// parse could hold anything, so they are left out.
+fn redacted_entries(lines: &[String]) -> String {
+    let mut entries = lines
+        .iter()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .collect::<Vec<_>>();
+    let mut aliases = PlayerAliases::default();
+    entries.iter().for_each(|entry| aliases.collect(entry));
+    entries
+        .iter_mut()
+        .map(|entry| {
+            aliases.redact(entry);
+            entry.to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn app_info(app: &AppHandle) -> Value {
+    let state = app.state::<AppState>();
+    json!({
+        "name": app.package_info().name,
+        "version": app.package_info().version.to_string(),
+        "os": std::env::consts::OS,
+        "arch": std::env::consts::ARCH,
+        "connection": state.poller.connection_state(),
+        "recording": state.recorder.is_enabled(),
+    })
+}
+
+fn add_file<W: Write + std::io::Seek>(
+    zip: &mut ZipWriter<W>,
+    name: &str,
+    contents: &[u8],
+) -> Result<(), AppError> {
+    zip.start_file(name, SimpleFileOptions::default())
+        .map_err(error::from_storage)?;
+    zip.write_all(contents).map_err(error::from_storage)
+}
+
+/// Every log file, including the ones kept by the rotation.
+fn add_logs<W: Write + std::io::Seek>(zip: &mut ZipWriter<W>, dir: &Path) -> Result<(), AppError> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Ok(());
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if path.is_file() && name.ends_with(".log") {
+            let contents = fs::read(&path).map_err(error::from_storage)?;
+            add_file(zip, &format!("logs/{}", name), &contents)?;
+        }
+    }
+    Ok(())
+}
+
+/// Zips the logs, the redacted settings, the app version and the last
+/// recorded payloads, with the players replaced by aliases, into the
+/// downloads folder, returning the file path.
+pub fn export(app: &AppHandle) -> Result<PathBuf, AppError> {
+    let dir = app
+        .path()
+        .download_dir()
+        .or_else(|_| app.path().app_data_dir())
+        .map_err(error::from_storage)?;
+    fs::create_dir_all(&dir).map_err(error::from_storage)?;
+    let exported_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    let path = dir.join(format!("tutorlol-diagnostics-{}.zip", exported_at));
+
+    let file = File::create(&path).map_err(error::from_storage)?;
+    let mut zip = ZipWriter::new(file);
+
+    let info = serde_json::to_vec_pretty(&app_info(app)).map_err(error::from_storage)?;
+    add_file(&mut zip, "app.json", &info)?;
+    let settings =
+        serde_json::to_vec_pretty(&redacted_settings(&app.state::<AppState>().settings.read()))
+            .map_err(error::from_storage)?;
+    add_file(&mut zip, "settings.json", &settings)?;
+    if let Ok(log_dir) = app.path().app_log_dir() {
+        add_logs(&mut zip, &log_dir)?;
+    }
+    let entries = app
+        .state::<AppState>()
+        .recorder
+        .latest_entries(RECENT_PAYLOADS)?;
+    if !entries.is_empty() {
+        let entries = redacted_entries(&entries);
+        add_file(&mut zip, "recordings/latest.jsonl", entries.as_bytes())?;
+    }
+
+    zip.finish().map_err(error::from_storage)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_GAME_DATA: &str = include_str!("../../mocks/fixtures/live_client/allgamedata.json");
+    const REALTIME: &str = include_str!("../../mocks/fixtures/backend/realtime.json");
+
+    #[test]
+    fn recorded_players_are_replaced_by_aliases() {
+        let mut game_data: Value = serde_json::from_str(ALL_GAME_DATA).unwrap();
+        let events = game_data["events"]["Events"].as_array_mut().unwrap();
+        events.push(json!({
+            "EventName": "ChampionKill",
+            "KillerName": "Tutor#BR1",
+            "VictimName": "Shadow#BR1",
+            "Assisters": ["Monk#BR1"],
+        }));
+        events.push(json!({
+            "EventName": "TurretKilled",
+            "KillerName": "Minion_T100L1S0N1",
+            "TurretKilled": "Turret_T2_R_03_A",
+        }));
+        let lines = [
+            json!({ "source": "all_game_data", "payload": game_data }).to_string(),
+            json!({ "source": "realtime", "payload": serde_json::from_str::<Value>(REALTIME).unwrap() })
+                .to_string(),
+        ];
+
+        let redacted = redacted_entries(&lines);
+        assert!(!redacted.contains("#BR1"));
+        assert!(!redacted.contains("\"Tutor\""));
+        assert!(!redacted.contains("\"Shadow\""));
+        assert!(redacted.contains("Minion_T100L1S0N1"));
+
+        let entries = redacted
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).unwrap())
+            .collect::<Vec<_>>();
+        let active_player = &entries[0]["payload"]["activePlayer"]["riotId"];
+        let kill = entries[0]["payload"]["events"]["Events"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|event| event["EventName"] == "ChampionKill")
+            .unwrap();
+        assert_eq!(&kill["KillerName"], active_player);
+        assert_eq!(
+            &entries[1]["payload"]["current_player"]["riot_id"],
+            active_player
+        );
+        assert_eq!(
+            kill["Assisters"][0]
+                .as_str()
+                .map(|name| name.starts_with("Player ")),
+            Some(true)
+        );
+    }
+}
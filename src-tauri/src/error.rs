@@ -0,0 +1,59 @@
+use std::fmt::Display;
+
+use reqwest::Response;
+use serde_json::Value;
+use tutorlolv2_shared::ServerResponse;
+
+pub use tutorlolv2_shared::error::AppError;
+
+/// Maps a failed request to the live client.
+pub fn from_live_client(e: reqwest::Error) -> AppError {
+    if e.is_timeout() {
+        AppError::Timeout
+    } else if e.is_decode() {
+        AppError::ParseError(e.to_string())
+    } else {
+        AppError::LiveClientUnavailable
+    }
+}
+
+/// Maps a failed request to the backend.
+pub fn from_backend(e: reqwest::Error) -> AppError {
+    if e.is_timeout() {
+        AppError::Timeout
+    } else if e.is_decode() {
+        AppError::ParseError(e.to_string())
+    } else {
+        AppError::BackendUnreachable
+    }
+}
+
+/// Maps a file of the app that could not be read or written.
+pub fn from_storage(e: impl Display) -> AppError {
+    AppError::Storage(e.to_string())
+}
+
+/// Maps a window that could not be built or changed.
+pub fn from_window(e: tauri::Error) -> AppError {
+    AppError::Window(e.to_string())
+}
+
+/// Fails a backend response that is not a 2xx before its body is read. The
+/// server sends its usual envelope along with most 4xx, whose message is
+/// kept.
+pub async fn check_backend_status(response: Response) -> Result<Response, AppError> {
+    let status = response.status();
+    if status.is_success() {
+        Ok(response)
+    } else if status.is_server_error() {
+        Err(AppError::BackendUnreachable)
+    } else {
+        let message = response
+            .json::<ServerResponse<Value>>()
+            .await
+            .ok()
+            .and_then(|body| body.message)
+            .unwrap_or_else(|| status.to_string());
+        Err(AppError::BackendRejected(message))
+    }
+}
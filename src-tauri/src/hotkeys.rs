@@ -0,0 +1,461 @@
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+pub const HOTKEY_EVENT: &str = "hotkey";
+
+/// What a global hotkey does. Every action is emitted to the frontend through
+/// `hotkey`. Window toggles are also handled natively, since a hidden window
+/// can not bring itself back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HotkeyAction {
+    ToggleWindow,
+    ToggleOverlay,
+    TogglePolling,
+    CycleHiddenEnemies,
+    ClearStack,
+    SwitchPage,
+}
+
+impl HotkeyAction {
+    pub const ALL: [HotkeyAction; 6] = [
+        HotkeyAction::ToggleWindow,
+        HotkeyAction::ToggleOverlay,
+        HotkeyAction::TogglePolling,
+        HotkeyAction::CycleHiddenEnemies,
+        HotkeyAction::ClearStack,
+        HotkeyAction::SwitchPage,
+    ];
+
+    pub fn default_binding(self) -> &'static str {
+        match self {
+            HotkeyAction::ToggleWindow => "Ctrl+'",
+            HotkeyAction::ToggleOverlay => "Ctrl+Shift+O",
+            HotkeyAction::TogglePolling => "Ctrl+Shift+P",
+            HotkeyAction::CycleHiddenEnemies => "Ctrl+Shift+H",
+            HotkeyAction::ClearStack => "Ctrl+Shift+C",
+            HotkeyAction::SwitchPage => "Ctrl+Shift+Tab",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            HotkeyAction::ToggleWindow => "toggle window visibility",
+            HotkeyAction::ToggleOverlay => "toggle the in-game overlay",
+            HotkeyAction::TogglePolling => "start or stop fetching the game",
+            HotkeyAction::CycleHiddenEnemies => "focus the next enemy",
+            HotkeyAction::ClearStack => "clear the damage stack",
+            HotkeyAction::SwitchPage => "switch to the next page",
+        }
+    }
+}
+
+pub fn default_bindings() -> HashMap<HotkeyAction, String> {
+    HotkeyAction::ALL
+        .into_iter()
+        .map(|action| (action, action.default_binding().to_string()))
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Modifiers {
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+    pub meta: bool,
+}
+
+impl Modifiers {
+    fn is_empty(&self) -> bool {
+        !(self.ctrl || self.shift || self.alt || self.meta)
+    }
+}
+
+/// Keys a hotkey can end with. Punctuation follows the US layout, matching
+/// the physical key rather than the character it types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    Letter(char),
+    Digit(char),
+    F(u8),
+    Quote,
+    Backquote,
+    Minus,
+    Equal,
+    BracketLeft,
+    BracketRight,
+    Backslash,
+    Semicolon,
+    Comma,
+    Period,
+    Slash,
+    Space,
+    Tab,
+    Enter,
+    Escape,
+    Backspace,
+    Delete,
+    Insert,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+const NAMED_KEYS: [(&str, Key); 26] = [
+    ("'", Key::Quote),
+    ("`", Key::Backquote),
+    ("-", Key::Minus),
+    ("=", Key::Equal),
+    ("[", Key::BracketLeft),
+    ("]", Key::BracketRight),
+    ("\\", Key::Backslash),
+    (";", Key::Semicolon),
+    (",", Key::Comma),
+    (".", Key::Period),
+    ("/", Key::Slash),
+    ("Space", Key::Space),
+    ("Tab", Key::Tab),
+    ("Enter", Key::Enter),
+    ("Escape", Key::Escape),
+    ("Backspace", Key::Backspace),
+    ("Delete", Key::Delete),
+    ("Insert", Key::Insert),
+    ("Home", Key::Home),
+    ("End", Key::End),
+    ("PageUp", Key::PageUp),
+    ("PageDown", Key::PageDown),
+    ("Up", Key::Up),
+    ("Down", Key::Down),
+    ("Left", Key::Left),
+    ("Right", Key::Right),
+];
+
+const KEY_ALIASES: [(&str, Key); 4] = [
+    ("Quote", Key::Quote),
+    ("Esc", Key::Escape),
+    ("Del", Key::Delete),
+    ("Return", Key::Enter),
+];
+
+impl FromStr for Key {
+    type Err = String;
+
+    fn from_str(token: &str) -> Result<Self, Self::Err> {
+        let mut chars = token.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            if c.is_ascii_alphabetic() {
+                return Ok(Key::Letter(c.to_ascii_uppercase()));
+            }
+            if c.is_ascii_digit() {
+                return Ok(Key::Digit(c));
+            }
+        }
+        if let Some(n) = token
+            .strip_prefix(['F', 'f'])
+            .and_then(|n| n.parse::<u8>().ok())
+        {
+            if (1..=24).contains(&n) {
+                return Ok(Key::F(n));
+            }
+        }
+        NAMED_KEYS
+            .iter()
+            .chain(KEY_ALIASES.iter())
+            .find(|(name, _)| name.eq_ignore_ascii_case(token))
+            .map(|(_, key)| *key)
+            .ok_or_else(|| format!("unknown key \"{}\"", token))
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Key::Letter(c) | Key::Digit(c) => write!(f, "{}", c),
+            Key::F(n) => write!(f, "F{}", n),
+            key => {
+                let (name, _) = NAMED_KEYS.iter().find(|(_, k)| k == key).unwrap();
+                write!(f, "{}", name)
+            }
+        }
+    }
+}
+
+/// A key pressed together with at least one modifier, such as `Ctrl+Shift+D`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hotkey {
+    pub modifiers: Modifiers,
+    pub key: Key,
+}
+
+impl FromStr for Hotkey {
+    type Err = String;
+
+    fn from_str(binding: &str) -> Result<Self, Self::Err> {
+        let mut modifiers = Modifiers::default();
+        let mut key = None;
+
+        // "+" separates tokens, so the plus key is written as "="
+        for token in binding.split('+').map(str::trim) {
+            let modifier = match token.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => &mut modifiers.ctrl,
+                "shift" => &mut modifiers.shift,
+                "alt" | "option" => &mut modifiers.alt,
+                "meta" | "super" | "win" | "cmd" => &mut modifiers.meta,
+                "" => return Err("empty key in binding".to_string()),
+                _ => {
+                    if key.is_some() {
+                        return Err("only one key can follow the modifiers".to_string());
+                    }
+                    key = Some(token.parse::<Key>()?);
+                    continue;
+                }
+            };
+            if key.is_some() {
+                return Err("modifiers must come before the key".to_string());
+            }
+            if *modifier {
+                return Err(format!("modifier \"{}\" is repeated", token));
+            }
+            *modifier = true;
+        }
+
+        let key = key.ok_or("binding has no key")?;
+        if modifiers.is_empty() {
+            return Err("binding needs at least one modifier".to_string());
+        }
+        Ok(Hotkey { modifiers, key })
+    }
+}
+
+impl fmt::Display for Hotkey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Modifiers {
+            ctrl,
+            shift,
+            alt,
+            meta,
+        } = self.modifiers;
+        for (pressed, name) in [
+            (ctrl, "Ctrl"),
+            (shift, "Shift"),
+            (alt, "Alt"),
+            (meta, "Meta"),
+        ] {
+            if pressed {
+                write!(f, "{}+", name)?;
+            }
+        }
+        write!(f, "{}", self.key)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum HotkeyError {
+    Invalid {
+        action: HotkeyAction,
+        binding: String,
+        reason: String,
+    },
+    Conflict {
+        hotkey: Hotkey,
+        actions: (HotkeyAction, HotkeyAction),
+    },
+}
+
+impl fmt::Display for HotkeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HotkeyError::Invalid {
+                action,
+                binding,
+                reason,
+            } => write!(
+                f,
+                "Invalid hotkey \"{}\" to {}: {}",
+                binding,
+                action.description(),
+                reason
+            ),
+            HotkeyError::Conflict {
+                hotkey,
+                actions: (first, second),
+            } => write!(
+                f,
+                "{} is bound to both {} and {}",
+                hotkey,
+                first.description(),
+                second.description()
+            ),
+        }
+    }
+}
+
+/// Parsed hotkeys in effect. Actions missing from the configuration keep
+/// their default binding and an empty binding disables the action.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Bindings(Vec<(Hotkey, HotkeyAction)>);
+
+impl Bindings {
+    pub const fn empty() -> Self {
+        Self(Vec::new())
+    }
+
+    pub fn parse(configured: &HashMap<HotkeyAction, String>) -> Result<Self, HotkeyError> {
+        let mut bindings = Vec::<(Hotkey, HotkeyAction)>::new();
+
+        for action in HotkeyAction::ALL {
+            let binding = configured
+                .get(&action)
+                .map(String::as_str)
+                .unwrap_or(action.default_binding());
+            if binding.trim().is_empty() {
+                continue;
+            }
+            let hotkey = binding
+                .parse::<Hotkey>()
+                .map_err(|reason| HotkeyError::Invalid {
+                    action,
+                    binding: binding.to_string(),
+                    reason,
+                })?;
+            if let Some((_, existing)) = bindings.iter().find(|(h, _)| *h == hotkey) {
+                return Err(HotkeyError::Conflict {
+                    hotkey,
+                    actions: (*existing, action),
+                });
+            }
+            bindings.push((hotkey, action));
+        }
+        Ok(Self(bindings))
+    }
+
+    pub fn action_for(&self, hotkey: &Hotkey) -> Option<HotkeyAction> {
+        self.iter()
+            .find(|(h, _)| h == hotkey)
+            .map(|(_, action)| *action)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &(Hotkey, HotkeyAction)> {
+        self.0.iter()
+    }
+
+    /// Canonical binding of every action, empty when it is disabled.
+    pub fn to_map(&self) -> HashMap<HotkeyAction, String> {
+        HotkeyAction::ALL
+            .into_iter()
+            .map(|action| {
+                let binding = self
+                    .0
+                    .iter()
+                    .find(|(_, a)| *a == action)
+                    .map(|(hotkey, _)| hotkey.to_string())
+                    .unwrap_or_default();
+                (action, binding)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hotkey(binding: &str) -> Hotkey {
+        binding.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_modifiers_and_key() {
+        let parsed = hotkey("Ctrl+Shift+D");
+        assert!(parsed.modifiers.ctrl && parsed.modifiers.shift);
+        assert!(!parsed.modifiers.alt && !parsed.modifiers.meta);
+        assert_eq!(parsed.key, Key::Letter('D'));
+    }
+
+    #[test]
+    fn parsing_ignores_case_spacing_and_aliases() {
+        assert_eq!(hotkey("control + shift + d"), hotkey("Ctrl+Shift+D"));
+        assert_eq!(hotkey("Shift+Ctrl+D"), hotkey("Ctrl+Shift+D"));
+        assert_eq!(hotkey("Ctrl+Quote"), hotkey("Ctrl+'"));
+        assert_eq!(hotkey("Alt+esc").key, Key::Escape);
+        assert_eq!(hotkey("Win+f12").key, Key::F(12));
+    }
+
+    #[test]
+    fn displays_canonical_form() {
+        assert_eq!(hotkey("shift+ctrl+d").to_string(), "Ctrl+Shift+D");
+        assert_eq!(hotkey("alt+meta+pageup").to_string(), "Alt+Meta+PageUp");
+        assert_eq!(hotkey("ctrl+quote").to_string(), "Ctrl+'");
+        assert_eq!(hotkey("ctrl+left").to_string(), "Ctrl+Left");
+        for action in HotkeyAction::ALL {
+            let binding = action.default_binding();
+            assert_eq!(hotkey(binding).to_string(), binding);
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_bindings() {
+        for binding in [
+            "D",
+            "Ctrl",
+            "Ctrl+",
+            "Ctrl+Ctrl+D",
+            "Ctrl+D+E",
+            "D+Ctrl",
+            "Ctrl+F25",
+            "Ctrl+Hyper",
+        ] {
+            assert!(binding.parse::<Hotkey>().is_err(), "{} parsed", binding);
+        }
+    }
+
+    #[test]
+    fn defaults_are_valid_and_distinct() {
+        let bindings = Bindings::parse(&HashMap::new()).unwrap();
+        assert!(bindings
+            .to_map()
+            .values()
+            .all(|binding| !binding.is_empty()));
+        assert_eq!(
+            bindings.action_for(&hotkey("Ctrl+'")),
+            Some(HotkeyAction::ToggleWindow)
+        );
+        assert_eq!(Bindings::parse(&default_bindings()).unwrap(), bindings);
+    }
+
+    #[test]
+    fn detects_conflicts() {
+        let configured = HashMap::from([(HotkeyAction::ClearStack, "ctrl+'".to_string())]);
+        assert_eq!(
+            Bindings::parse(&configured),
+            Err(HotkeyError::Conflict {
+                hotkey: hotkey("Ctrl+'"),
+                actions: (HotkeyAction::ToggleWindow, HotkeyAction::ClearStack),
+            })
+        );
+    }
+
+    #[test]
+    fn reports_the_invalid_action() {
+        let configured = HashMap::from([(HotkeyAction::SwitchPage, "Ctrl+Nope".to_string())]);
+        let Err(HotkeyError::Invalid { action, .. }) = Bindings::parse(&configured) else {
+            panic!("invalid binding was accepted");
+        };
+        assert_eq!(action, HotkeyAction::SwitchPage);
+    }
+
+    #[test]
+    fn empty_binding_disables_action() {
+        let configured = HashMap::from([(HotkeyAction::ToggleWindow, String::new())]);
+        let bindings = Bindings::parse(&configured).unwrap();
+        assert_eq!(bindings.action_for(&hotkey("Ctrl+'")), None);
+        assert_eq!(bindings.to_map()[&HotkeyAction::ToggleWindow], "");
+        assert_eq!(bindings.to_map()[&HotkeyAction::ClearStack], "Ctrl+Shift+C");
+    }
+}
@@ -0,0 +1,168 @@
+use std::{sync::Arc, time::Duration};
+
+use reqwest::Client;
+use rustls::{
+    client::{
+        danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier},
+        WebPkiServerVerifier,
+    },
+    crypto::{ring, CryptoProvider},
+    pki_types::{pem::PemObject, CertificateDer, ServerName, UnixTime},
+    CertificateError, ClientConfig, DigitallySignedStruct, Error as TlsError, RootCertStore,
+    SignatureScheme,
+};
+
+/// Riot's published root for the live client API, the only certificate the
+/// live client is trusted with. A missing file fails the build and one that
+/// does not parse fails the startup.
+const RIOT_ROOT_CERT: &[u8] = include_bytes!("../certs/riotgames.pem");
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Client for the TutorLoL backend, with the usual certificate checks.
+pub fn backend_client() -> Result<Client, reqwest::Error> {
+    Client::builder().timeout(TIMEOUT).build()
+}
+
+/// Checks the chain against the pinned root only. The live client serves a
+/// certificate for `rclient` without subject alternative names, which can
+/// never match `127.0.0.1`, so the name mismatch is the one error ignored.
+#[derive(Debug)]
+struct PinnedRootVerifier(Arc<WebPkiServerVerifier>);
+
+impl ServerCertVerifier for PinnedRootVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, TlsError> {
+        let result =
+            self.0
+                .verify_server_cert(end_entity, intermediates, server_name, ocsp_response, now);
+        match result {
+            // The name is checked last, once the chain is known to end at the root
+            Err(TlsError::InvalidCertificate(
+                CertificateError::NotValidForName | CertificateError::NotValidForNameContext { .. },
+            )) => Ok(ServerCertVerified::assertion()),
+            result => result,
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, TlsError> {
+        self.0.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, TlsError> {
+        self.0.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.supported_verify_schemes()
+    }
+}
+
+impl PinnedRootVerifier {
+    fn new(root_pem: &[u8], provider: Arc<CryptoProvider>) -> Result<Self, String> {
+        let root = CertificateDer::from_pem_slice(root_pem)
+            .map_err(|e| format!("The pinned root is not a PEM certificate: {}", e))?;
+        let mut roots = RootCertStore::empty();
+        roots
+            .add(root)
+            .map_err(|e| format!("The pinned root can not be trusted: {}", e))?;
+
+        let verifier = WebPkiServerVerifier::builder_with_provider(Arc::new(roots), provider)
+            .build()
+            .map_err(|e| e.to_string())?;
+        Ok(Self(verifier))
+    }
+}
+
+/// Client trusting nothing but `root_pem`.
+fn pinned_client(root_pem: &[u8]) -> Result<Client, String> {
+    let provider = Arc::new(ring::default_provider());
+    let verifier = PinnedRootVerifier::new(root_pem, provider.clone())?;
+    let config = ClientConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()
+        .map_err(|e| e.to_string())?
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(verifier))
+        .with_no_client_auth();
+
+    Client::builder()
+        .timeout(TIMEOUT)
+        .use_preconfigured_tls(config)
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+/// Client for the live client API at `127.0.0.1:2999`, whose certificate is
+/// signed by Riot's own root. Plain http, used by the `live_client` mock,
+/// works as is.
+pub fn live_client() -> Result<Client, String> {
+    pinned_client(RIOT_ROOT_CERT).map_err(|e| format!("certs/riotgames.pem: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROOT: &[u8] = include_bytes!("../../mocks/fixtures/certs/root.pem");
+    const LEAF: &[u8] = include_bytes!("../../mocks/fixtures/certs/leaf.pem");
+    const EXPIRED_LEAF: &[u8] = include_bytes!("../../mocks/fixtures/certs/expired_leaf.pem");
+    /// Signed by another root
+    const FOREIGN_LEAF: &[u8] = include_bytes!("../../mocks/fixtures/certs/foreign_leaf.pem");
+
+    /// Checks `leaf_pem` the way the live client certificate is checked. The
+    /// test leaves are issued to `rclient`, so the name never matches.
+    fn verify(leaf_pem: &[u8]) -> Result<ServerCertVerified, TlsError> {
+        let verifier = PinnedRootVerifier::new(ROOT, Arc::new(ring::default_provider())).unwrap();
+        let leaf = CertificateDer::from_pem_slice(leaf_pem).unwrap();
+        let server_name = ServerName::try_from("127.0.0.1").unwrap();
+        // 2026-01-01, between 2021, when the expired leaf expired, and 2120
+        let now = UnixTime::since_unix_epoch(Duration::from_secs(1_767_225_600));
+        verifier.verify_server_cert(&leaf, &[], &server_name, &[], now)
+    }
+
+    #[test]
+    fn riot_root_is_pinned() {
+        live_client().unwrap();
+        assert!(pinned_client(b"not a certificate").is_err());
+    }
+
+    #[test]
+    fn a_leaf_of_the_pinned_root_is_trusted_for_any_name() {
+        assert!(verify(LEAF).is_ok());
+    }
+
+    #[test]
+    fn a_leaf_of_another_root_is_rejected() {
+        assert!(matches!(
+            verify(FOREIGN_LEAF),
+            Err(TlsError::InvalidCertificate(
+                CertificateError::UnknownIssuer
+            ))
+        ));
+    }
+
+    #[test]
+    fn an_expired_leaf_is_rejected() {
+        assert!(matches!(
+            verify(EXPIRED_LEAF),
+            Err(TlsError::InvalidCertificate(
+                CertificateError::Expired | CertificateError::ExpiredContext { .. }
+            ))
+        ));
+    }
+}
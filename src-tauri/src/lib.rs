@@ -1,250 +1,561 @@
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
 
-use crate::wnd_system::keyboard::{install_hook, uninstall_hook};
+use crate::alerts::{clamp_cooldown, KillAlerts};
+use crate::connectivity::ProbeResult;
+use crate::error::AppError;
+use crate::hotkeys::{Bindings, HotkeyAction, HOTKEY_EVENT};
+use crate::overlay::{Overlay, OverlayState, STACK_UPDATE_EVENT};
+use crate::pacing::PollRate;
+use crate::realtime::{fetch_realtime, Poller, UploadCache};
+use crate::recorder::{Recorder, RecordingInfo, RECORDINGS_DIR};
+use crate::server::{LiveServer, ServerStatus};
+use crate::session::{watch_matches, GameSession};
+use crate::settings::{
+    normalize_url, resolve_backend_url, resolve_live_client_url, simulated_items_for,
+    KillAlertSettings, PollingSettings, ServerSettings, Settings, SettingsStore, WindowSettings,
+};
+use crate::wnd_system::{set_bindings, start_listener};
+use log::{error, info, warn};
 use reqwest::Client;
-use serde::Deserialize;
-use serde_json::{json, Value};
+use serde_json::Value;
 use tauri::{
     async_runtime,
-    menu::{Menu, MenuItem},
-    tray::TrayIconBuilder,
-    Manager, State,
+    ipc::{Channel, Response},
+    AppHandle, Emitter, Manager, State,
 };
-use tauri_plugin_notification::NotificationExt;
-use windows::Win32::UI::WindowsAndMessaging::{GetMessageW, MSG, WM_QUIT, WM_USER};
+use tutorlolv2_shared::{connection::ConnectionState, ipc};
 
-mod model;
+mod alerts;
+mod connectivity;
+mod diagnostics;
+mod error;
+mod hotkeys;
+mod http;
+mod live_client;
+mod main_window;
+mod overlay;
+mod pacing;
+mod realtime;
+mod recorder;
+mod server;
+mod session;
+mod settings;
+mod tray;
 mod wnd_system;
 
-#[derive(Deserialize)]
-struct ServerResponse<T> {
-    success: bool,
-    data: Option<T>,
-    message: Option<String>,
+struct AppState {
+    /// Normal certificate checks, for the TutorLoL backend
+    backend_client: Arc<Client>,
+    /// Trusts only Riot's root certificate, for the live client API
+    live_client: Arc<Client>,
+    backend_url: RwLock<String>,
+    live_client_url: String,
+    poller: Poller,
+    /// Last upload of the live client payload, to skip unchanged ones
+    uploads: UploadCache,
+    recorder: Recorder,
+    session: GameSession,
+    settings: SettingsStore,
+    hotkeys: RwLock<Bindings>,
+    overlay: Overlay,
+    alerts: KillAlerts,
+    server: LiveServer,
 }
 
-struct AppState {
-    client: Arc<Client>,
-    static_game_code: usize,
-    static_game_id: String,
-}
-
-#[tauri::command]
-fn get_game_code(state: State<'_, AppState>) -> usize {
-    state.static_game_code
-}
-
-// const BACKEND_URL: &str = "http://localhost:8082";
-const BACKEND_URL: &str = "https://v2.tutorlol.com";
-
-#[tauri::command]
-async fn get_realtime_game(state: State<'_, AppState>, game_code: usize) -> Result<String, String> {
-    let get_game_data = async |url: &str, json_body: Value| -> Result<String, String> {
-        let server_response = state
-            .client
-            .post(url)
-            .json(&json_body)
-            .send()
-            .await
-            .map_err(|e| {
-                let error_kind = format!("Error om server response: {:#?}", e);
-                println!("{}", error_kind);
-                error_kind
-            })?;
-
-        let json_response = server_response
-            .json::<ServerResponse<Value>>()
-            .await
-            .map_err(|e| {
-                let error_kind = format!("Error ocurred on json parsing: {:#?}", e);
-                println!("{}", error_kind);
-                error_kind
-            })?;
-
-        if let Some(data) = json_response.data {
-            return Ok(data.to_string());
-        } else if let Some(message) = json_response.message {
-            return Err(message);
-        } else {
-            return Err("Unknown error".to_string());
+impl AppState {
+    fn backend_url(&self) -> String {
+        self.backend_url.read().unwrap().clone()
+    }
+
+    fn simulated_items_for(&self, champion_id: Option<&str>) -> Vec<usize> {
+        simulated_items_for(&self.settings.read().simulated_items, champion_id)
+    }
+
+    /// Makes `bindings` the hotkeys the platform listener reacts to.
+    fn apply_hotkeys(&self, app: &AppHandle, bindings: Bindings) {
+        *self.hotkeys.write().unwrap() = bindings.clone();
+        set_bindings(app, &bindings);
+    }
+}
+
+fn toggle_main_window(app: &AppHandle) {
+    match main_window::window(app) {
+        Some(window) if window.is_visible().unwrap_or(false) => {
+            let _ = window.hide();
         }
-    };
-
-    if game_code == state.static_game_code {
-        println!("Getting local game data because code matches the stored in the app");
-        let local_response = state
-            .client
-            .get("https://127.0.0.1:2999/liveclientdata/allgamedata")
-            .send()
-            .await
-            .map_err(|e| {
-                let error_kind = format!("Error ocurred in getting live game: {:#?}", e);
-                println!("{}", error_kind);
-                error_kind
-            })?;
-
-        let game_data = local_response.text().await.unwrap_or_default();
-
-        get_game_data(
-            &format!("{}/api/games/realtime", BACKEND_URL),
-            json!({
-                "game_id": state.static_game_id,
-                "game_code": state.static_game_code,
-                "game_data": game_data,
-                "simulated_items": [3115],
-            }),
-        )
-        .await
-    } else {
-        println!(
-            "Getting a previous game using code because it doesn't match the stored in the app"
-        );
-        get_game_data(
-            &format!("{}/api/games/get_by_code", BACKEND_URL),
-            json!({
-                "game_code": game_code,
-                "simulated_items": [3115],
-            }),
-        )
-        .await
+        _ => main_window::show(app),
     }
 }
 
-#[derive(Deserialize)]
-struct CreateGameResponse {
+fn trigger_hotkey(app: &AppHandle, action: HotkeyAction) {
+    info!("Hotkey pressed: {:?}", action);
+    match action {
+        HotkeyAction::ToggleWindow => toggle_main_window(app),
+        HotkeyAction::ToggleOverlay => {
+            if let Err(e) = app.state::<AppState>().overlay.toggle(app) {
+                error!("Failed to toggle the overlay: {}", e);
+            }
+        }
+        _ => {}
+    }
+    let _ = app.emit(HOTKEY_EVENT, action);
+}
+
+#[tauri::command]
+fn get_game_code(state: State<'_, AppState>) -> Option<usize> {
+    state.session.game_code()
+}
+
+#[tauri::command]
+async fn regenerate_game_code(
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<usize, AppError> {
+    state.session.regenerate(&app).await
+}
+
+#[tauri::command]
+fn get_backend_url(state: State<'_, AppState>) -> String {
+    state.backend_url()
+}
+
+#[tauri::command]
+fn set_backend_url(state: State<'_, AppState>, backend_url: String) -> Result<String, AppError> {
+    let backend_url = normalize_url(&backend_url)
+        .ok_or_else(|| AppError::InvalidInput(format!("Invalid backend url: {}", backend_url)))?;
+
+    state
+        .settings
+        .update(|settings| settings.backend_url = Some(backend_url.clone()))?;
+
+    *state.backend_url.write().unwrap() = backend_url.clone();
+    info!("Backend url changed to {}", backend_url);
+    Ok(backend_url)
+}
+
+#[tauri::command]
+async fn get_realtime_game(
+    state: State<'_, AppState>,
     game_code: usize,
-    game_id: String,
+    simulated_items: Vec<usize>,
+) -> Result<Response, AppError> {
+    let realtime = fetch_realtime(&state, game_code, &simulated_items).await?;
+    ipc::encode(&realtime)
+        .map(Response::new)
+        .map_err(|e| AppError::ParseError(e.to_string()))
+}
+
+/// Sends every `Realtime` the poller fetches to `on_data`, see
+/// `Poller::subscribe`. Returns the id to unsubscribe with.
+#[tauri::command]
+fn subscribe_realtime(state: State<'_, AppState>, on_data: Channel) -> u32 {
+    state.poller.subscribe(on_data)
+}
+
+#[tauri::command]
+fn unsubscribe_realtime(state: State<'_, AppState>, id: u32) {
+    state.poller.unsubscribe(id);
+}
+
+#[tauri::command]
+fn get_simulated_items(state: State<'_, AppState>) -> HashMap<String, Vec<usize>> {
+    state.settings.read().simulated_items.clone()
+}
+
+/// Persists the items compared for `champion_id`. An empty list restores the
+/// default. The poller fetches again so the change shows up right away.
+#[tauri::command]
+fn set_simulated_items(
+    state: State<'_, AppState>,
+    champion_id: String,
+    items: Vec<usize>,
+) -> Result<Vec<usize>, AppError> {
+    state.settings.update(|settings| {
+        if items.is_empty() {
+            settings.simulated_items.remove(&champion_id);
+        } else {
+            settings.simulated_items.insert(champion_id.clone(), items);
+        }
+    })?;
+    state.poller.refresh();
+    Ok(state.simulated_items_for(Some(&champion_id)))
+}
+
+#[tauri::command]
+fn get_hotkeys(state: State<'_, AppState>) -> HashMap<HotkeyAction, String> {
+    state.hotkeys.read().unwrap().to_map()
+}
+
+/// Binds `action` to `binding`, or disables it when `binding` is empty. All
+/// bindings are validated together, so an invalid or conflicting binding
+/// leaves the current hotkeys untouched. Returns the binding as it was saved.
+#[tauri::command]
+fn set_hotkey(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    action: HotkeyAction,
+    binding: String,
+) -> Result<String, AppError> {
+    let bindings = state.settings.try_update(|settings| {
+        settings.hotkeys.insert(action, binding);
+        let bindings = Bindings::parse(&settings.hotkeys)
+            .map_err(|e| AppError::InvalidInput(e.to_string()))?;
+        settings.hotkeys = bindings.to_map();
+        Ok(bindings)
+    })?;
+
+    let saved = bindings.to_map().remove(&action).unwrap_or_default();
+    state.apply_hotkeys(&app, bindings);
+    Ok(saved)
+}
+
+#[tauri::command]
+fn toggle_overlay(app: AppHandle, state: State<'_, AppState>) -> Result<(), AppError> {
+    state.overlay.toggle(&app)
+}
+
+#[tauri::command]
+fn set_overlay_locked(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    locked: bool,
+) -> Result<(), AppError> {
+    state.overlay.set_locked(&app, locked)
+}
+
+#[tauri::command]
+fn set_overlay_base_table(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    enabled: bool,
+) -> Result<(), AppError> {
+    state.overlay.set_show_base_table(&app, enabled)
+}
+
+#[tauri::command]
+fn get_overlay_state(app: AppHandle, state: State<'_, AppState>) -> OverlayState {
+    state.overlay.state(&app)
+}
+
+#[tauri::command]
+fn get_stack(state: State<'_, AppState>) -> Value {
+    state.settings.read().ui.stack.clone()
+}
+
+/// Shares the damage stack of the Realtime page with the overlay window and
+/// keeps it for the next launch.
+#[tauri::command]
+fn set_stack(app: AppHandle, state: State<'_, AppState>, stack: Value) -> Result<(), AppError> {
+    state
+        .settings
+        .update(|settings| settings.ui.stack = stack.clone())?;
+    let _ = app.emit(STACK_UPDATE_EVENT, &stack);
+    state.alerts.check(&app);
+    Ok(())
+}
+
+#[tauri::command]
+fn get_settings(state: State<'_, AppState>) -> Settings {
+    state.settings.read().clone()
+}
+
+/// Applies `window_settings` to the main window and returns what was saved.
+/// The position is left alone, it is only stored when the window is closed.
+#[tauri::command]
+fn set_window_settings(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    window_settings: WindowSettings,
+) -> Result<WindowSettings, AppError> {
+    let window = state.settings.update(|settings| {
+        settings.window = WindowSettings {
+            x: settings.window.x,
+            y: settings.window.y,
+            ..window_settings.clamped()
+        };
+        settings.window.clone()
+    })?;
+    main_window::apply(&app, &window)?;
+    Ok(window)
+}
+
+#[tauri::command]
+fn set_selected_page(state: State<'_, AppState>, page: usize) -> Result<(), AppError> {
+    state
+        .settings
+        .update(|settings| settings.ui.selected_page = page)
+}
+
+#[tauri::command]
+fn set_hidden_enemies(
+    state: State<'_, AppState>,
+    hidden_enemies: Vec<usize>,
+) -> Result<(), AppError> {
+    state
+        .settings
+        .update(|settings| settings.ui.hidden_enemies = hidden_enemies)
+}
+
+#[tauri::command]
+fn get_server_status(state: State<'_, AppState>) -> ServerStatus {
+    state.server.status()
+}
+
+/// Saves `server_settings` and restarts the local server with them. The
+/// settings are kept even when the server fails to start, so the error can
+/// be fixed from the same form.
+#[tauri::command]
+async fn set_server_settings(
+    state: State<'_, AppState>,
+    server_settings: ServerSettings,
+) -> Result<ServerStatus, AppError> {
+    state
+        .settings
+        .update(|settings| settings.server = server_settings.clone())?;
+    state.server.restart(&server_settings).await
+}
+
+/// Writes a zip for bug reports and returns where it was saved.
+#[tauri::command]
+fn export_diagnostics(app: AppHandle) -> Result<String, AppError> {
+    let path = diagnostics::export(&app)?;
+    info!("Exported diagnostics to {:?}", path);
+    Ok(path.display().to_string())
+}
+
+/// Checks the connection to the live client and the backend, one result per
+/// endpoint.
+#[tauri::command]
+async fn run_diagnostics(state: State<'_, AppState>) -> Result<Vec<ProbeResult>, AppError> {
+    Ok(connectivity::run(&state).await)
+}
+
+#[tauri::command]
+fn get_kill_alerts(state: State<'_, AppState>) -> KillAlertSettings {
+    state.alerts.settings()
+}
+
+/// Returns the saved settings, with the cooldown clamped to its bounds.
+#[tauri::command]
+fn set_kill_alerts(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    mut kill_alerts: KillAlertSettings,
+) -> Result<KillAlertSettings, AppError> {
+    kill_alerts.cooldown_secs = clamp_cooldown(kill_alerts.cooldown_secs);
+    state
+        .settings
+        .update(|settings| settings.kill_alerts = kill_alerts.clone())?;
+    state.alerts.set_settings(kill_alerts.clone());
+    state.alerts.check(&app);
+    Ok(kill_alerts)
+}
+
+#[tauri::command]
+fn start_polling(app: AppHandle, state: State<'_, AppState>, game_code: usize) {
+    info!("Started polling game {}", game_code);
+    state.settings.remember_game_code(game_code);
+    state.alerts.reset();
+    state.poller.start(&app, game_code);
+}
+
+#[tauri::command]
+fn stop_polling(app: AppHandle, state: State<'_, AppState>) {
+    info!("Stopped polling");
+    state.poller.stop(&app);
+}
+
+#[tauri::command]
+fn get_connection_state(state: State<'_, AppState>) -> ConnectionState {
+    state.poller.connection_state()
+}
+
+#[tauri::command]
+fn pause_polling(app: AppHandle, state: State<'_, AppState>) {
+    state.poller.set_paused(&app, true);
+}
+
+#[tauri::command]
+fn resume_polling(app: AppHandle, state: State<'_, AppState>) {
+    state.poller.set_paused(&app, false);
+}
+
+#[tauri::command]
+fn set_poll_interval(state: State<'_, AppState>, interval_ms: u64) -> Result<u64, AppError> {
+    let interval_ms = state.poller.set_interval(interval_ms);
+    state
+        .settings
+        .update(|settings| settings.poll_interval_ms = interval_ms)?;
+    Ok(interval_ms)
+}
+
+#[tauri::command]
+fn get_poll_rate(state: State<'_, AppState>) -> Option<PollRate> {
+    state.poller.rate()
+}
+
+/// Returns the saved bounds, clamped to what the poller accepts.
+#[tauri::command]
+fn set_polling_settings(
+    state: State<'_, AppState>,
+    polling: PollingSettings,
+) -> Result<PollingSettings, AppError> {
+    let polling = polling.clamped();
+    state
+        .settings
+        .update(|settings| settings.polling = polling.clone())?;
+    state.poller.set_bounds(polling.clone());
+    Ok(polling)
+}
+
+#[tauri::command]
+fn get_recording(state: State<'_, AppState>) -> bool {
+    state.recorder.is_enabled()
+}
+
+#[tauri::command]
+fn set_recording(state: State<'_, AppState>, enabled: bool) -> Result<bool, AppError> {
+    state
+        .settings
+        .update(|settings| settings.record_games = enabled)?;
+    state.recorder.set_enabled(enabled);
+    info!(
+        "Game recording {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(enabled)
+}
+
+#[tauri::command]
+fn list_recordings(state: State<'_, AppState>) -> Result<Vec<RecordingInfo>, AppError> {
+    state.recorder.list()
+}
+
+/// Page of the frames of a recording, read on a blocking thread and encoded
+/// as MessagePack like the realtime channel.
+#[tauri::command]
+async fn load_recording(
+    app: AppHandle,
+    name: String,
+    offset: usize,
+    limit: usize,
+) -> Result<Response, AppError> {
+    let page = async_runtime::spawn_blocking(move || {
+        app.state::<AppState>()
+            .recorder
+            .read_replay(&name, offset, limit)
+    })
+    .await
+    .map_err(error::from_storage)??;
+    ipc::encode(&page)
+        .map(Response::new)
+        .map_err(|e| AppError::ParseError(e.to_string()))
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .device_event_filter(tauri::DeviceEventFilter::Always)
+        .plugin(diagnostics::log_plugin())
         .plugin(tauri_plugin_notification::init())
-        .invoke_handler(tauri::generate_handler![get_realtime_game, get_game_code])
+        .plugin(tauri_plugin_clipboard_manager::init())
+        .invoke_handler(tauri::generate_handler![
+            get_realtime_game,
+            subscribe_realtime,
+            unsubscribe_realtime,
+            get_game_code,
+            regenerate_game_code,
+            get_backend_url,
+            set_backend_url,
+            start_polling,
+            stop_polling,
+            get_connection_state,
+            get_simulated_items,
+            set_simulated_items,
+            get_hotkeys,
+            set_hotkey,
+            toggle_overlay,
+            set_overlay_locked,
+            set_overlay_base_table,
+            get_overlay_state,
+            get_stack,
+            set_stack,
+            get_settings,
+            set_window_settings,
+            set_selected_page,
+            set_hidden_enemies,
+            get_server_status,
+            set_server_settings,
+            export_diagnostics,
+            run_diagnostics,
+            get_kill_alerts,
+            set_kill_alerts,
+            pause_polling,
+            resume_polling,
+            set_poll_interval,
+            get_poll_rate,
+            set_polling_settings,
+            get_recording,
+            set_recording,
+            list_recordings,
+            load_recording
+        ])
         .setup(|app| {
-            let quit_i = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
-            let help_i = MenuItem::with_id(app, "help", "Help", true, None::<&str>)?;
-            let show_i = MenuItem::with_id(
-                app,
-                "toggle_visibility",
-                "Toggle Visibility",
-                true,
-                None::<&str>,
-            )?;
-            let menu = Menu::with_items(app, &[&quit_i, &help_i, &show_i])?;
-
-            let tray = TrayIconBuilder::new()
-                .icon(app.default_window_icon().unwrap().clone())
-                .menu(&menu)
-                .show_menu_on_left_click(true)
-                .build(app)?;
-
-            {
-                let app_handle = app.handle().clone();
-                tray.on_menu_event(move |_, event| match event.id().0.as_str() {
-                    "quit" => {
-                        println!("Item 'Quit' selected");
-                        std::process::exit(0);
-                    }
-                    // #![error] For some reason this feature is not working.
-                    "help" => {
-                        println!("Item 'Help' selected");
-                        app_handle
-                            .notification()
-                            .builder()
-                            .title("Keybindings")
-                            .body("Press Ctrl + ' to toggle window visibility.")
-                            .show()
-                            .unwrap();
-                    }
-                    "toggle_visibility" => {
-                        println!("Item 'Show' selected");
-                        if let Some(window) = app_handle.get_webview_window("main") {
-                            if window.is_visible().unwrap_or(false) {
-                                let _ = window.hide();
-                            } else {
-                                let _ = window.show();
-                                let _ = window.set_focus();
-                            }
-                        }
-                    }
-                    _ => {
-                        println!("Unrecognized menu item: {}", event.id().0);
-                    }
-                });
-            }
+            let store = SettingsStore::load(app.handle());
+            let settings = store.read().clone();
 
-            #[cfg(target_os = "windows")]
-            unsafe {
-                let app_handle = app.handle().clone();
-                tauri::async_runtime::spawn(async move {
-                    install_hook();
-                    let mut msg = MSG::default();
-                    while GetMessageW(&mut msg, None, 0, 0).into() {
-                        if msg.message == WM_QUIT {
-                            break;
-                        }
-                        if msg.message == WM_USER + 1 {
-                            if let Some(win) = app_handle.get_webview_window("main") {
-                                if !win.is_visible().unwrap() {
-                                    let _ = win.show();
-                                    let _ = win.set_focus();
-                                } else {
-                                    let _ = win.hide();
-                                }
-                            }
-                        }
-                    }
-                    uninstall_hook();
-                });
-            }
-            let client = Client::builder()
-                .danger_accept_invalid_certs(true)
-                .timeout(std::time::Duration::from_secs(10))
-                .build()
-                .unwrap();
-
-            let initialization: Option<CreateGameResponse> = async_runtime::block_on(async {
-                let ServerResponse::<CreateGameResponse> {
-                    success,
-                    data,
-                    message,
-                } = client
-                    .get(&format!("{}/api/games/create", BACKEND_URL))
-                    .send()
-                    .await
-                    .map_err(|e| println!("Failed request send: {:#?}", e))
-                    .ok()?
-                    .json()
-                    .await
-                    .map_err(|e| println!("Json parse error: {:#?}", e))
-                    .ok()?;
-                if success {
-                    data
-                } else {
-                    println!("Failed to initialize app game creation: {:#?}", message);
-                    None
-                }
-            });
+            let backend_client = http::backend_client()?;
+            let live_client = http::live_client()?;
 
-            let (static_game_code, static_game_id) = initialization
-                .map(|res| (res.game_code, res.game_id))
-                .unwrap_or_default();
+            let backend_url = resolve_backend_url(&settings);
+            info!("Using backend url {}", backend_url);
 
             app.manage(AppState {
-                client: Arc::new(client),
-                static_game_code,
-                static_game_id,
+                backend_client: Arc::new(backend_client),
+                live_client: Arc::new(live_client),
+                backend_url: RwLock::new(backend_url),
+                live_client_url: resolve_live_client_url(),
+                poller: Poller::new(settings.poll_interval_ms, settings.polling.clone()),
+                uploads: UploadCache::new(),
+                recorder: Recorder::new(
+                    app.path()
+                        .app_data_dir()
+                        .ok()
+                        .map(|dir| dir.join(RECORDINGS_DIR)),
+                    settings.record_games,
+                ),
+                session: GameSession::new(),
+                settings: store,
+                hotkeys: RwLock::new(Bindings::empty()),
+                overlay: Overlay::new(),
+                alerts: KillAlerts::new(settings.kill_alerts.clone()),
+                server: LiveServer::new(),
             });
+            main_window::build(app.handle(), &settings.window)?;
+            tray::create(app.handle())?;
+            start_listener(app.handle())?;
 
-            if cfg!(debug_assertions) {
-                app.handle().plugin(
-                    tauri_plugin_log::Builder::default()
-                        .level(log::LevelFilter::Info)
-                        .build(),
-                )?;
+            let bindings = Bindings::parse(&settings.hotkeys).unwrap_or_else(|e| {
+                warn!("{}, using the default hotkeys", e);
+                Bindings::parse(&HashMap::new()).unwrap()
+            });
+            app.state::<AppState>()
+                .apply_hotkeys(app.handle(), bindings);
+            async_runtime::spawn(watch_matches(app.handle().clone()));
+            if settings.server.enabled {
+                let app = app.handle().clone();
+                let server_settings = settings.server.clone();
+                async_runtime::spawn(async move {
+                    let state = app.state::<AppState>();
+                    if let Err(e) = state.server.restart(&server_settings).await {
+                        error!("{}", e);
+                    }
+                });
             }
+
             Ok(())
         })
         .run(tauri::generate_context!())
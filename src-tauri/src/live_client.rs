@@ -0,0 +1,602 @@
+// Mirrors Riot's schema as a whole, so not every field is read
+#![allow(dead_code)]
+
+use std::hash::{Hash, Hasher};
+
+use log::debug;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::error::AppError;
+
+/// Response of `/liveclientdata/allgamedata`. Riot sends the fields in
+/// camelCase, except for the events which use PascalCase.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AllGameData {
+    pub active_player: ActivePlayer,
+    pub all_players: Vec<Player>,
+    pub events: Events,
+    pub game_data: GameData,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivePlayer {
+    pub abilities: Abilities,
+    pub champion_stats: ChampionStats,
+    pub current_gold: f64,
+    pub full_runes: FullRunes,
+    pub level: usize,
+    pub riot_id: String,
+    #[serde(default)]
+    pub riot_id_game_name: String,
+    #[serde(default)]
+    pub riot_id_tag_line: String,
+    #[serde(default)]
+    pub summoner_name: String,
+    #[serde(default)]
+    pub team_relative_colors: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Abilities {
+    pub passive: Ability,
+    pub q: Ability,
+    pub w: Ability,
+    pub e: Ability,
+    pub r: Ability,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ability {
+    /// Missing on the passive
+    #[serde(default)]
+    pub ability_level: usize,
+    pub display_name: String,
+    pub id: String,
+    pub raw_description: String,
+    pub raw_display_name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChampionStats {
+    pub ability_haste: f64,
+    pub ability_power: f64,
+    pub armor: f64,
+    pub armor_penetration_flat: f64,
+    pub armor_penetration_percent: f64,
+    pub attack_damage: f64,
+    pub attack_range: f64,
+    pub attack_speed: f64,
+    pub bonus_armor_penetration_percent: f64,
+    pub bonus_magic_penetration_percent: f64,
+    pub crit_chance: f64,
+    pub crit_damage: f64,
+    pub current_health: f64,
+    pub heal_shield_power: f64,
+    pub health_regen_rate: f64,
+    pub life_steal: f64,
+    pub magic_lethality: f64,
+    pub magic_penetration_flat: f64,
+    pub magic_penetration_percent: f64,
+    pub magic_resist: f64,
+    pub max_health: f64,
+    pub move_speed: f64,
+    pub omnivamp: f64,
+    pub physical_lethality: f64,
+    pub physical_vamp: f64,
+    pub resource_max: f64,
+    pub resource_regen_rate: f64,
+    pub resource_type: String,
+    pub resource_value: f64,
+    pub spell_vamp: f64,
+    pub tenacity: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Rune {
+    pub display_name: String,
+    pub id: usize,
+    pub raw_description: String,
+    pub raw_display_name: String,
+}
+
+/// Stat shards have no display name
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatRune {
+    pub id: usize,
+    pub raw_description: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FullRunes {
+    pub general_runes: Vec<Rune>,
+    pub keystone: Rune,
+    pub primary_rune_tree: Rune,
+    pub secondary_rune_tree: Rune,
+    pub stat_runes: Vec<StatRune>,
+}
+
+/// The runes the client shows for every player
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerRunes {
+    pub keystone: Rune,
+    pub primary_rune_tree: Rune,
+    pub secondary_rune_tree: Rune,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Item {
+    pub can_use: bool,
+    pub consumable: bool,
+    pub count: usize,
+    pub display_name: String,
+    #[serde(rename = "itemID")]
+    pub item_id: usize,
+    pub price: usize,
+    pub raw_description: String,
+    pub raw_display_name: String,
+    pub slot: usize,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Scores {
+    pub assists: usize,
+    pub creep_score: usize,
+    pub deaths: usize,
+    pub kills: usize,
+    pub ward_score: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SummonerSpell {
+    pub display_name: String,
+    pub raw_description: String,
+    pub raw_display_name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SummonerSpells {
+    pub summoner_spell_one: SummonerSpell,
+    pub summoner_spell_two: SummonerSpell,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Player {
+    pub champion_name: String,
+    pub is_bot: bool,
+    pub is_dead: bool,
+    pub items: Vec<Item>,
+    pub level: usize,
+    /// Empty outside of Summoner's Rift
+    pub position: String,
+    pub raw_champion_name: String,
+    #[serde(default)]
+    pub raw_skin_name: String,
+    pub respawn_timer: f64,
+    pub riot_id: String,
+    #[serde(default)]
+    pub riot_id_game_name: String,
+    #[serde(default)]
+    pub riot_id_tag_line: String,
+    pub runes: PlayerRunes,
+    pub scores: Scores,
+    #[serde(rename = "skinID")]
+    pub skin_id: usize,
+    #[serde(default)]
+    pub summoner_name: String,
+    pub summoner_spells: SummonerSpells,
+    /// `ORDER` or `CHAOS`
+    pub team: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Events {
+    #[serde(rename = "Events")]
+    pub events: Vec<Event>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Event {
+    #[serde(rename = "EventID")]
+    pub id: u64,
+    #[serde(rename = "EventTime")]
+    pub time: f64,
+    #[serde(flatten)]
+    pub kind: EventKind,
+}
+
+/// Players are referred to by riot id. Booleans such as `Stolen` come as
+/// the strings `"True"` and `"False"`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "EventName")]
+pub enum EventKind {
+    GameStart,
+    MinionsSpawning,
+    FirstBrick {
+        #[serde(rename = "KillerName")]
+        killer: String,
+    },
+    FirstBlood {
+        #[serde(rename = "Recipient")]
+        recipient: String,
+    },
+    ChampionKill {
+        #[serde(rename = "KillerName")]
+        killer: String,
+        #[serde(rename = "VictimName")]
+        victim: String,
+        #[serde(rename = "Assisters", default)]
+        assisters: Vec<String>,
+    },
+    Multikill {
+        #[serde(rename = "KillerName")]
+        killer: String,
+        #[serde(rename = "KillStreak")]
+        kill_streak: usize,
+    },
+    Ace {
+        #[serde(rename = "Acer")]
+        acer: String,
+        #[serde(rename = "AcingTeam")]
+        acing_team: String,
+    },
+    TurretKilled {
+        #[serde(rename = "TurretKilled")]
+        turret: String,
+        #[serde(rename = "KillerName")]
+        killer: String,
+        #[serde(rename = "Assisters", default)]
+        assisters: Vec<String>,
+    },
+    InhibKilled {
+        #[serde(rename = "InhibKilled")]
+        inhibitor: String,
+        #[serde(rename = "KillerName")]
+        killer: String,
+        #[serde(rename = "Assisters", default)]
+        assisters: Vec<String>,
+    },
+    DragonKill {
+        #[serde(rename = "DragonType")]
+        dragon_type: String,
+        #[serde(rename = "Stolen")]
+        stolen: String,
+        #[serde(rename = "KillerName")]
+        killer: String,
+        #[serde(rename = "Assisters", default)]
+        assisters: Vec<String>,
+    },
+    HeraldKill {
+        #[serde(rename = "Stolen")]
+        stolen: String,
+        #[serde(rename = "KillerName")]
+        killer: String,
+        #[serde(rename = "Assisters", default)]
+        assisters: Vec<String>,
+    },
+    BaronKill {
+        #[serde(rename = "Stolen")]
+        stolen: String,
+        #[serde(rename = "KillerName")]
+        killer: String,
+        #[serde(rename = "Assisters", default)]
+        assisters: Vec<String>,
+    },
+    GameEnd {
+        #[serde(rename = "Result")]
+        result: String,
+    },
+    /// Events added by newer clients, or ones nothing here reads
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameData {
+    pub game_mode: String,
+    pub game_time: f64,
+    pub map_name: String,
+    pub map_number: usize,
+    pub map_terrain: String,
+}
+
+impl AllGameData {
+    /// Entry of the active player in `all_players`.
+    pub fn active_player_entry(&self) -> Option<&Player> {
+        self.all_players
+            .iter()
+            .find(|player| player.riot_id == self.active_player.riot_id)
+    }
+
+    pub fn is_active_player_dead(&self) -> bool {
+        self.active_player_entry()
+            .is_some_and(|player| player.is_dead)
+    }
+
+    /// Events that happened after the one with `last_id`, or every event
+    /// when nothing was seen yet.
+    pub fn events_after(&self, last_id: Option<u64>) -> impl Iterator<Item = &Event> {
+        self.events
+            .events
+            .iter()
+            .filter(move |event| last_id.is_none_or(|last_id| event.id > last_id))
+    }
+
+    /// Feeds `state` with the fields the backend calculates damages from.
+    /// The game clock, gold, respawn timers and the current health and
+    /// resource change all the time without changing any damage, so they are
+    /// left out.
+    pub fn hash_relevant<H: Hasher>(&self, state: &mut H) {
+        let active_player = &self.active_player;
+        active_player.riot_id.hash(state);
+        active_player.level.hash(state);
+        let abilities = &active_player.abilities;
+        for ability in [&abilities.q, &abilities.w, &abilities.e, &abilities.r] {
+            ability.ability_level.hash(state);
+        }
+        let runes = &active_player.full_runes;
+        for rune in [
+            &runes.keystone,
+            &runes.primary_rune_tree,
+            &runes.secondary_rune_tree,
+        ]
+        .into_iter()
+        .chain(&runes.general_runes)
+        {
+            rune.id.hash(state);
+        }
+        for rune in &runes.stat_runes {
+            rune.id.hash(state);
+        }
+        let stats = &active_player.champion_stats;
+        for stat in [
+            stats.ability_haste,
+            stats.ability_power,
+            stats.armor,
+            stats.armor_penetration_flat,
+            stats.armor_penetration_percent,
+            stats.attack_damage,
+            stats.attack_range,
+            stats.attack_speed,
+            stats.bonus_armor_penetration_percent,
+            stats.bonus_magic_penetration_percent,
+            stats.crit_chance,
+            stats.crit_damage,
+            stats.magic_lethality,
+            stats.magic_penetration_flat,
+            stats.magic_penetration_percent,
+            stats.magic_resist,
+            stats.max_health,
+            stats.physical_lethality,
+            stats.resource_max,
+        ] {
+            stat.to_bits().hash(state);
+        }
+
+        for player in &self.all_players {
+            player.riot_id.hash(state);
+            player.champion_name.hash(state);
+            player.team.hash(state);
+            player.level.hash(state);
+            for item in &player.items {
+                item.item_id.hash(state);
+                item.count.hash(state);
+            }
+            let runes = &player.runes;
+            for rune in [
+                &runes.keystone,
+                &runes.primary_rune_tree,
+                &runes.secondary_rune_tree,
+            ] {
+                rune.id.hash(state);
+            }
+            let scores = &player.scores;
+            [
+                scores.kills,
+                scores.deaths,
+                scores.assists,
+                scores.creep_score,
+            ]
+            .hash(state);
+        }
+        // Dragons change the multipliers
+        for event in &self.events.events {
+            if let EventKind::DragonKill {
+                dragon_type,
+                killer,
+                ..
+            } = &event.kind
+            {
+                (dragon_type, killer).hash(state);
+            }
+        }
+    }
+}
+
+/// Why a payload the client answered with cannot be used yet.
+fn incomplete_reason(payload: &Value) -> Option<&'static str> {
+    if payload["activePlayer"].get("error").is_some() {
+        return Some("the client is spectating a game");
+    }
+    let players = payload["allPlayers"].as_array();
+    if payload["activePlayer"].is_null()
+        || players.is_none_or(|players| players.is_empty())
+        || payload["gameData"].is_null()
+    {
+        return Some("the game is still loading");
+    }
+    None
+}
+
+/// Parses an `allgamedata` body before anything is sent to the backend.
+/// Spectated games and the partial payloads served while a game is loading
+/// are reported as `LiveClientUnavailable`, so polling waits for a game the
+/// app can follow. Anything else that does not match the schema is a
+/// `ParseError`.
+pub fn parse(body: &str) -> Result<AllGameData, AppError> {
+    let payload: Value =
+        serde_json::from_str(body).map_err(|e| AppError::ParseError(e.to_string()))?;
+    if let Some(reason) = incomplete_reason(&payload) {
+        debug!("Skipping a live client payload, {}", reason);
+        return Err(AppError::LiveClientUnavailable);
+    }
+    serde_json::from_value(payload)
+        .map_err(|e| AppError::ParseError(format!("Unexpected live client payload: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::DefaultHasher;
+
+    use serde_json::json;
+
+    use super::*;
+
+    const ALL_GAME_DATA: &str = include_str!("../../mocks/fixtures/live_client/allgamedata.json");
+
+    fn fixture() -> Value {
+        serde_json::from_str(ALL_GAME_DATA).unwrap()
+    }
+
+    #[test]
+    fn parses_the_mock_payload() {
+        let data = parse(ALL_GAME_DATA).unwrap();
+        assert_eq!(data.all_players.len(), 10);
+        assert_eq!(data.game_data.map_number, 11);
+        assert_eq!(data.active_player_entry().unwrap().champion_name, "Ahri");
+        assert!(!data.is_active_player_dead());
+        assert!(matches!(data.events.events[0].kind, EventKind::GameStart));
+    }
+
+    #[test]
+    fn reads_events_and_skips_unknown_ones() {
+        let mut payload = fixture();
+        payload["events"]["Events"] = json!([
+            { "EventID": 0, "EventName": "GameStart", "EventTime": 0.0 },
+            {
+                "EventID": 1,
+                "EventName": "ChampionKill",
+                "EventTime": 310.5,
+                "KillerName": "Tutor#BR1",
+                "VictimName": "Shadow#BR1",
+                "Assisters": ["Monk#BR1"]
+            },
+            { "EventID": 2, "EventName": "FeatsOfStrength", "EventTime": 400.0 }
+        ]);
+        let data = parse(&payload.to_string()).unwrap();
+        let new: Vec<_> = data.events_after(Some(0)).collect();
+        assert_eq!(new.len(), 2);
+        assert!(matches!(
+            &new[0].kind,
+            EventKind::ChampionKill { victim, .. } if victim == "Shadow#BR1"
+        ));
+        assert!(matches!(new[1].kind, EventKind::Other));
+    }
+
+    #[test]
+    fn detects_a_dead_active_player() {
+        let mut payload = fixture();
+        payload["allPlayers"][0]["isDead"] = json!(true);
+        payload["allPlayers"][0]["respawnTimer"] = json!(12.5);
+        assert!(parse(&payload.to_string()).unwrap().is_active_player_dead());
+    }
+
+    #[test]
+    fn relevant_hash_ignores_the_clock_and_gold() {
+        let hash = |payload: &Value| {
+            let mut hasher = DefaultHasher::new();
+            parse(&payload.to_string())
+                .unwrap()
+                .hash_relevant(&mut hasher);
+            hasher.finish()
+        };
+        let payload = fixture();
+        let unchanged = hash(&payload);
+
+        let mut ticked = payload.clone();
+        ticked["gameData"]["gameTime"] = json!(1234.5);
+        ticked["activePlayer"]["currentGold"] = json!(9999.0);
+        ticked["allPlayers"][3]["respawnTimer"] = json!(20.0);
+        assert_eq!(hash(&ticked), unchanged);
+
+        let mut bought = payload.clone();
+        bought["allPlayers"][0]["items"][0]["itemID"] = json!(3089);
+        assert_ne!(hash(&bought), unchanged);
+
+        let mut leveled = payload;
+        leveled["activePlayer"]["abilities"]["R"]["abilityLevel"] = json!(3);
+        assert_ne!(hash(&leveled), unchanged);
+    }
+
+    #[test]
+    fn relevant_hash_ignores_the_current_health_and_resource() {
+        let hash = |payload: &Value| {
+            let mut hasher = DefaultHasher::new();
+            parse(&payload.to_string())
+                .unwrap()
+                .hash_relevant(&mut hasher);
+            hasher.finish()
+        };
+        let payload = fixture();
+        let unchanged = hash(&payload);
+
+        let mut hit = payload.clone();
+        let stats = &mut hit["activePlayer"]["championStats"];
+        stats["currentHealth"] = json!(12.0);
+        stats["resourceValue"] = json!(3.0);
+        assert_eq!(hash(&hit), unchanged);
+
+        let mut grown = payload;
+        grown["activePlayer"]["championStats"]["maxHealth"] = json!(4000.0);
+        assert_ne!(hash(&grown), unchanged);
+    }
+
+    #[test]
+    fn spectator_and_loading_payloads_are_unavailable() {
+        let mut spectator = fixture();
+        spectator["activePlayer"] =
+            json!({ "error": "Spectator mode doesn't currently support this feature" });
+        assert_eq!(
+            parse(&spectator.to_string()).unwrap_err(),
+            AppError::LiveClientUnavailable
+        );
+
+        let mut loading = fixture();
+        loading["allPlayers"] = json!([]);
+        loading.as_object_mut().unwrap().remove("gameData");
+        assert_eq!(
+            parse(&loading.to_string()).unwrap_err(),
+            AppError::LiveClientUnavailable
+        );
+    }
+
+    #[test]
+    fn malformed_payloads_are_parse_errors() {
+        assert!(matches!(
+            parse("{\"activePlayer\":"),
+            Err(AppError::ParseError(_))
+        ));
+
+        let mut payload = fixture();
+        payload["allPlayers"][0]["level"] = json!("one");
+        assert!(matches!(
+            parse(&payload.to_string()),
+            Err(AppError::ParseError(_))
+        ));
+    }
+}
@@ -0,0 +1,75 @@
+use tauri::{
+    AppHandle, LogicalSize, Manager, Size, WebviewUrl, WebviewWindow, WebviewWindowBuilder,
+    WindowEvent,
+};
+
+use crate::{
+    error::{self, AppError},
+    settings::{self, WindowSettings},
+};
+
+pub const MAIN_LABEL: &str = "main";
+
+pub fn window(app: &AppHandle) -> Option<WebviewWindow> {
+    app.get_webview_window(MAIN_LABEL)
+}
+
+pub fn show(app: &AppHandle) {
+    if let Some(window) = window(app) {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+/// Creates the main window from the saved settings. It is built here rather
+/// than in `tauri.conf.json` so its options can be changed from the app.
+pub fn build(app: &AppHandle, settings: &WindowSettings) -> tauri::Result<WebviewWindow> {
+    let builder = WebviewWindowBuilder::new(app, MAIN_LABEL, WebviewUrl::default())
+        .title("TutorLoL Native")
+        .inner_size(settings.width, settings.height)
+        .transparent(true)
+        .shadow(false)
+        .decorations(settings.decorations)
+        .always_on_top(settings.always_on_top)
+        .skip_taskbar(settings.skip_taskbar)
+        .resizable(settings.resizable)
+        .focused(true);
+    let builder = match (settings.x, settings.y) {
+        (Some(x), Some(y)) => builder.position(x, y),
+        _ => builder.center(),
+    };
+    let window = builder.build()?;
+
+    {
+        let app = app.clone();
+        let main_window = window.clone();
+        window.on_window_event(move |event| {
+            if let WindowEvent::CloseRequested { .. } = event {
+                save_geometry(&app, &main_window);
+            }
+        });
+    }
+    Ok(window)
+}
+
+/// Applies the options that can change while the window is open.
+pub fn apply(app: &AppHandle, settings: &WindowSettings) -> Result<(), AppError> {
+    let Some(window) = window(app) else {
+        return Ok(());
+    };
+    window
+        .set_size(Size::Logical(LogicalSize::new(
+            settings.width,
+            settings.height,
+        )))
+        .and_then(|_| window.set_always_on_top(settings.always_on_top))
+        .and_then(|_| window.set_decorations(settings.decorations))
+        .and_then(|_| window.set_skip_taskbar(settings.skip_taskbar))
+        .and_then(|_| window.set_resizable(settings.resizable))
+        .map_err(error::from_window)
+}
+
+/// Stores where the main window was left, so it reopens there.
+pub fn save_geometry(app: &AppHandle, window: &WebviewWindow) {
+    settings::save_geometry(app, window, |settings| &mut settings.window);
+}
@@ -0,0 +1,146 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::Serialize;
+use tauri::{
+    AppHandle, Emitter, Manager, WebviewUrl, WebviewWindow, WebviewWindowBuilder, WindowEvent,
+};
+
+use crate::{
+    error::{self, AppError},
+    settings::{self, OverlaySettings},
+    tray, AppState,
+};
+
+pub const OVERLAY_LABEL: &str = "overlay";
+pub const OVERLAY_STATE_EVENT: &str = "overlay-state";
+/// Damage stack built on the Realtime page, mirrored to the overlay
+pub const STACK_UPDATE_EVENT: &str = "stack-update";
+
+#[derive(Clone, Serialize)]
+pub struct OverlayState {
+    pub visible: bool,
+    /// Unlocked overlays take mouse input so they can be moved and resized
+    pub locked: bool,
+    pub show_base_table: bool,
+}
+
+/// Compact always-on-top window that renders the realtime feed over the game.
+/// It ignores the mouse while locked, so clicks reach the game underneath.
+pub struct Overlay {
+    locked: AtomicBool,
+}
+
+impl Overlay {
+    pub fn new() -> Self {
+        Self {
+            locked: AtomicBool::new(true),
+        }
+    }
+
+    pub fn state(&self, app: &AppHandle) -> OverlayState {
+        OverlayState {
+            visible: window(app).is_some_and(|window| window.is_visible().unwrap_or(false)),
+            locked: self.locked.load(Ordering::SeqCst),
+            show_base_table: app
+                .state::<AppState>()
+                .settings
+                .read()
+                .overlay
+                .show_base_table,
+        }
+    }
+
+    fn emit_state(&self, app: &AppHandle) {
+        let _ = app.emit(OVERLAY_STATE_EVENT, self.state(app));
+        tray::refresh(app);
+    }
+
+    pub fn toggle(&self, app: &AppHandle) -> Result<(), AppError> {
+        match window(app) {
+            Some(window) if window.is_visible().unwrap_or(false) => {
+                save_geometry(app, &window);
+                let _ = window.hide();
+            }
+            Some(window) => {
+                let _ = window.show();
+            }
+            None => {
+                let overlay = app.state::<AppState>().settings.read().overlay.clone();
+                let window = build(app, &overlay)?;
+                window
+                    .set_ignore_cursor_events(self.locked.load(Ordering::SeqCst))
+                    .map_err(error::from_window)?;
+            }
+        }
+        self.emit_state(app);
+        Ok(())
+    }
+
+    /// Locking stores where the overlay was left and makes it click-through.
+    pub fn set_locked(&self, app: &AppHandle, locked: bool) -> Result<(), AppError> {
+        self.locked.store(locked, Ordering::SeqCst);
+        if let Some(window) = window(app) {
+            if locked {
+                save_geometry(app, &window);
+            }
+            window
+                .set_ignore_cursor_events(locked)
+                .map_err(error::from_window)?;
+            if !locked {
+                let _ = window.set_focus();
+            }
+        }
+        self.emit_state(app);
+        Ok(())
+    }
+
+    pub fn set_show_base_table(&self, app: &AppHandle, enabled: bool) -> Result<(), AppError> {
+        app.state::<AppState>()
+            .settings
+            .update(|settings| settings.overlay.show_base_table = enabled)?;
+        self.emit_state(app);
+        Ok(())
+    }
+}
+
+pub fn window(app: &AppHandle) -> Option<WebviewWindow> {
+    app.get_webview_window(OVERLAY_LABEL)
+}
+
+fn build(app: &AppHandle, overlay: &OverlaySettings) -> Result<WebviewWindow, AppError> {
+    let builder = WebviewWindowBuilder::new(app, OVERLAY_LABEL, WebviewUrl::default())
+        .title("TutorLoL Overlay")
+        .inner_size(overlay.width, overlay.height)
+        .transparent(true)
+        .decorations(false)
+        .shadow(false)
+        .always_on_top(true)
+        .skip_taskbar(true)
+        .resizable(true)
+        .focused(false);
+    let builder = match (overlay.x, overlay.y) {
+        (Some(x), Some(y)) => builder.position(x, y),
+        _ => builder.center(),
+    };
+    let window = builder.build().map_err(error::from_window)?;
+
+    {
+        let app = app.clone();
+        let overlay_window = window.clone();
+        window.on_window_event(move |event| {
+            // Closing only hides the overlay, so it reopens where it was
+            if let WindowEvent::CloseRequested { api, .. } = event {
+                api.prevent_close();
+                save_geometry(&app, &overlay_window);
+                let _ = overlay_window.hide();
+                app.state::<AppState>().overlay.emit_state(&app);
+            }
+        });
+    }
+    Ok(window)
+}
+
+/// Stores where the overlay was left, so it reopens there.
+pub fn save_geometry(app: &AppHandle, window: &WebviewWindow) {
+    settings::save_geometry(app, window, |settings| &mut settings.overlay);
+}
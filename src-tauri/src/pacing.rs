@@ -0,0 +1,189 @@
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tutorlolv2_shared::realtime::Realtime;
+
+use crate::{live_client::AllGameData, settings::PollingSettings};
+
+/// How long the fight rate is kept after a kill or a death
+pub const FIGHT_WINDOW: Duration = Duration::from_secs(15);
+
+/// Why the poller runs at its current rate.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Pace {
+    Normal,
+    /// Someone was killed moments ago
+    Fighting,
+    /// The player is waiting to respawn
+    Dead,
+    /// No window shows the feed and the live server is stopped
+    Hidden,
+    /// The game clock stopped. The live client is still read to notice the
+    /// game resuming, but nothing is uploaded or pushed until it does
+    GamePaused,
+}
+
+/// Rate of the poller while the game is live, shown in the status indicator.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct PollRate {
+    pub pace: Pace,
+    pub interval_ms: u64,
+}
+
+impl PollRate {
+    /// `interval_ms` is the rate chosen by the user, kept within the bounds.
+    pub fn new(pace: Pace, interval_ms: u64, bounds: &PollingSettings) -> Self {
+        let interval_ms = match pace {
+            Pace::Fighting => bounds.min_interval_ms,
+            Pace::Dead | Pace::Hidden => bounds.max_interval_ms,
+            Pace::Normal | Pace::GamePaused => {
+                interval_ms.clamp(bounds.min_interval_ms, bounds.max_interval_ms)
+            }
+        };
+        Self { pace, interval_ms }
+    }
+
+    pub fn interval(&self) -> Duration {
+        Duration::from_millis(self.interval_ms)
+    }
+}
+
+/// What the live client said about the game, which only exists for the
+/// code generated by this app.
+#[derive(Clone, Copy)]
+pub struct LiveSignals {
+    pub game_time: f64,
+    pub dead: bool,
+}
+
+impl LiveSignals {
+    pub fn of(game_data: &AllGameData) -> Self {
+        Self {
+            game_time: game_data.game_data.game_time,
+            dead: game_data.is_active_player_dead(),
+        }
+    }
+}
+
+/// Derives the pace from consecutive responses of one game.
+#[derive(Default)]
+pub struct Pacer {
+    /// Kills and deaths on the scoreboard of the last response
+    takedowns: Option<usize>,
+    last_takedown: Option<Instant>,
+    game_time: Option<f64>,
+}
+
+impl Pacer {
+    /// Records a response fetched at `now` and returns the pace it calls for.
+    pub fn observe(
+        &mut self,
+        realtime: &Realtime,
+        live: Option<LiveSignals>,
+        hidden: bool,
+        now: Instant,
+    ) -> Pace {
+        let takedowns = realtime
+            .scoreboard
+            .iter()
+            .map(|score| score.kills + score.deaths)
+            .sum();
+        if self.takedowns.is_some_and(|last| last != takedowns) {
+            self.last_takedown = Some(now);
+        }
+        self.takedowns = Some(takedowns);
+
+        let game_time = live.map(|live| live.game_time);
+        let game_paused = self.game_time.is_some() && self.game_time == game_time;
+        self.game_time = game_time;
+
+        if game_paused {
+            Pace::GamePaused
+        } else if live.is_some_and(|live| live.dead) {
+            Pace::Dead
+        } else if hidden {
+            Pace::Hidden
+        } else if self
+            .last_takedown
+            .is_some_and(|last| now.duration_since(last) < FIGHT_WINDOW)
+        {
+            Pace::Fighting
+        } else {
+            Pace::Normal
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REALTIME: &str = include_str!("../../mocks/fixtures/backend/realtime.json");
+
+    fn live(game_time: f64) -> Option<LiveSignals> {
+        Some(LiveSignals {
+            game_time,
+            dead: false,
+        })
+    }
+
+    #[test]
+    fn kills_speed_up_polling_for_a_while() {
+        let mut realtime: Realtime = serde_json::from_str(REALTIME).unwrap();
+        let mut pacer = Pacer::default();
+        let start = Instant::now();
+        assert_eq!(
+            pacer.observe(&realtime, live(600.0), false, start),
+            Pace::Normal
+        );
+
+        realtime.scoreboard[0].kills += 1;
+        let killed = start + Duration::from_secs(1);
+        assert_eq!(
+            pacer.observe(&realtime, live(601.0), false, killed),
+            Pace::Fighting
+        );
+        assert_eq!(
+            pacer.observe(&realtime, live(610.0), false, killed + FIGHT_WINDOW),
+            Pace::Normal
+        );
+    }
+
+    #[test]
+    fn a_stopped_clock_pauses_and_death_slows_down() {
+        let realtime: Realtime = serde_json::from_str(REALTIME).unwrap();
+        let mut pacer = Pacer::default();
+        let now = Instant::now();
+        pacer.observe(&realtime, live(600.0), false, now);
+        assert_eq!(
+            pacer.observe(&realtime, live(600.0), false, now),
+            Pace::GamePaused
+        );
+
+        let dead = Some(LiveSignals {
+            game_time: 601.0,
+            dead: true,
+        });
+        assert_eq!(pacer.observe(&realtime, dead, false, now), Pace::Dead);
+        assert_eq!(
+            pacer.observe(&realtime, live(602.0), true, now),
+            Pace::Hidden
+        );
+    }
+
+    #[test]
+    fn rates_stay_within_the_bounds() {
+        let bounds = PollingSettings {
+            min_interval_ms: 400,
+            max_interval_ms: 4000,
+        };
+        assert_eq!(
+            PollRate::new(Pace::Fighting, 1000, &bounds).interval_ms,
+            400
+        );
+        assert_eq!(PollRate::new(Pace::Hidden, 1000, &bounds).interval_ms, 4000);
+        assert_eq!(PollRate::new(Pace::Normal, 250, &bounds).interval_ms, 400);
+        assert_eq!(PollRate::new(Pace::Normal, 1000, &bounds).interval_ms, 1000);
+    }
+}
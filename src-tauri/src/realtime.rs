@@ -0,0 +1,481 @@
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+    io::Write,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use flate2::{write::GzEncoder, Compression};
+use log::{debug, error};
+use reqwest::{
+    header::{CONTENT_ENCODING, CONTENT_TYPE},
+    RequestBuilder,
+};
+use serde::Serialize;
+use serde_json::{json, Value};
+use tauri::{
+    async_runtime,
+    ipc::{Channel, InvokeResponseBody},
+    AppHandle, Emitter, Manager,
+};
+use tokio::sync::Notify;
+use tutorlolv2_shared::{connection::ConnectionState, ipc, realtime::Realtime, ServerResponse};
+
+use crate::{
+    error::{self, AppError},
+    live_client,
+    main_window::MAIN_LABEL,
+    overlay::OVERLAY_LABEL,
+    pacing::{LiveSignals, Pace, Pacer, PollRate},
+    session::GameCode,
+    settings::PollingSettings,
+    tray, AppState,
+};
+
+pub const REALTIME_UPDATE_EVENT: &str = "realtime-update";
+pub const DEFAULT_POLL_INTERVAL_MS: u64 = 1000;
+pub const MIN_POLL_INTERVAL_MS: u64 = 250;
+pub const MAX_POLL_INTERVAL_MS: u64 = 10_000;
+
+/// Changes of the poller. The `Realtime` responses themselves go through the
+/// subscribed channels instead, see `Poller::subscribe`.
+#[derive(Clone, Serialize)]
+#[serde(tag = "status", content = "payload", rename_all = "snake_case")]
+pub enum RealtimeUpdate {
+    State(ConnectionState),
+    Paused(bool),
+    Rate(Option<PollRate>),
+}
+
+/// Live client payloads run from 30 to 60 KB and compress to a fraction of
+/// that, so they are uploaded with gzip.
+fn gzip_json(json_body: &Value) -> Result<Vec<u8>, AppError> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    serde_json::to_writer(&mut encoder, json_body)
+        .map_err(|e| AppError::ParseError(e.to_string()))?;
+    encoder
+        .flush()
+        .and_then(|_| encoder.finish())
+        .map_err(|e| AppError::ParseError(e.to_string()))
+}
+
+/// Uploads the running game under `own_code`. The diagnostics probe sends
+/// it the same way polling does.
+pub fn realtime_request(
+    state: &AppState,
+    own_code: &GameCode,
+    game_data: &str,
+    simulated_items: &[usize],
+) -> Result<RequestBuilder, AppError> {
+    let body = gzip_json(&json!({
+        "game_id": own_code.game_id,
+        "game_code": own_code.game_code,
+        "game_data": game_data,
+        "simulated_items": simulated_items,
+    }))?;
+    Ok(state
+        .backend_client
+        .post(format!("{}/api/games/realtime", state.backend_url()))
+        .header(CONTENT_TYPE, "application/json")
+        .header(CONTENT_ENCODING, "gzip")
+        .body(body))
+}
+
+async fn get_game_data(request: RequestBuilder) -> Result<Realtime, AppError> {
+    let server_response = request.send().await.map_err(|e| {
+        error!("Failed to reach the backend: {}", e);
+        error::from_backend(e)
+    })?;
+    let server_response = error::check_backend_status(server_response)
+        .await
+        .inspect_err(|e| error!("The backend failed the request: {}", e))?;
+
+    let json_response = server_response
+        .json::<ServerResponse<Option<Realtime>>>()
+        .await
+        .map_err(|e| {
+            error!("Failed to parse the backend response: {}", e);
+            AppError::ParseError(e.to_string())
+        })?;
+
+    match (json_response.data, json_response.message) {
+        (Some(data), _) => Ok(data),
+        (None, Some(message)) => Err(AppError::BackendRejected(message)),
+        (None, None) => Err(AppError::BackendRejected("Unknown error".to_string())),
+    }
+}
+
+/// Last live client payload uploaded to the backend, by the hash of its
+/// relevant fields, and the `Realtime` it answered with.
+pub struct UploadCache {
+    last: Mutex<Option<(u64, Realtime)>>,
+}
+
+impl UploadCache {
+    pub fn new() -> Self {
+        Self {
+            last: Mutex::new(None),
+        }
+    }
+
+    fn get(&self, hash: u64) -> Option<Realtime> {
+        match &*self.last.lock().unwrap() {
+            Some((last_hash, realtime)) if *last_hash == hash => Some(realtime.clone()),
+            _ => None,
+        }
+    }
+
+    fn store(&self, hash: u64, realtime: &Realtime) {
+        *self.last.lock().unwrap() = Some((hash, realtime.clone()));
+    }
+}
+
+/// Fetches the game behind `game_code`. The code generated by this app reads
+/// the local live client and forwards it to the backend, any other code is
+/// looked up on the backend directly. Both payloads are handed to the
+/// recorder, which drops them unless recording is enabled.
+///
+/// The live client payload is only uploaded when a field the backend
+/// calculates with changed, such as items, levels or scores. Otherwise the
+/// `Realtime` of the previous upload is returned, which is what happens
+/// while shopping, dead or paused.
+pub async fn fetch_realtime(
+    state: &AppState,
+    game_code: usize,
+    simulated_items: &[usize],
+) -> Result<Realtime, AppError> {
+    fetch_game(state, game_code, simulated_items)
+        .await
+        .map(|(realtime, _)| realtime)
+}
+
+/// `fetch_realtime` along with what the live client said about the game,
+/// which the poller paces itself with.
+async fn fetch_game(
+    state: &AppState,
+    game_code: usize,
+    simulated_items: &[usize],
+) -> Result<(Realtime, Option<LiveSignals>), AppError> {
+    let backend_url = state.backend_url();
+    let own_code = state
+        .session
+        .current()
+        .filter(|code| code.game_code == game_code);
+
+    if let Some(own_code) = own_code {
+        let local_response = state
+            .live_client
+            .get(format!(
+                "{}/liveclientdata/allgamedata",
+                state.live_client_url
+            ))
+            .send()
+            .await
+            .map_err(|e| {
+                error!("Failed to reach the live client: {}", e);
+                error::from_live_client(e)
+            })?;
+
+        // The live client answers 404 until the game has loaded
+        if !local_response.status().is_success() {
+            return Err(AppError::LiveClientUnavailable);
+        }
+        let game_data = local_response
+            .text()
+            .await
+            .map_err(error::from_live_client)?;
+        state.recorder.record_game_data(game_code, &game_data);
+        // Loading screens and spectated games never reach the backend
+        let parsed = live_client::parse(&game_data)?;
+        let signals = Some(LiveSignals::of(&parsed));
+
+        let mut hasher = DefaultHasher::new();
+        parsed.hash_relevant(&mut hasher);
+        (&backend_url, &own_code.game_id, simulated_items).hash(&mut hasher);
+        let hash = hasher.finish();
+        if let Some(realtime) = state.uploads.get(hash) {
+            debug!("Game {} did not change, skipping the upload", game_code);
+            return Ok((realtime, signals));
+        }
+
+        let realtime = get_game_data(realtime_request(
+            state,
+            &own_code,
+            &game_data,
+            simulated_items,
+        )?)
+        .await?;
+        state.uploads.store(hash, &realtime);
+        state.recorder.record_realtime(game_code, &realtime);
+        Ok((realtime, signals))
+    } else {
+        let realtime = get_game_data(
+            state
+                .backend_client
+                .post(format!("{}/api/games/get_by_code", backend_url))
+                .json(&json!({
+                    "game_code": game_code,
+                    "simulated_items": simulated_items,
+                })),
+        )
+        .await?;
+        state.recorder.record_realtime(game_code, &realtime);
+        Ok((realtime, None))
+    }
+}
+
+/// Control block of the background poller. Every call to `start` bumps the
+/// generation, so a task that sees a newer generation knows it was replaced
+/// or stopped and exits without emitting.
+pub struct Poller {
+    generation: AtomicU64,
+    paused: AtomicBool,
+    game_code: AtomicUsize,
+    interval_ms: AtomicU64,
+    bounds: Mutex<PollingSettings>,
+    /// Adapted rate while the game is live
+    rate: Mutex<Option<PollRate>>,
+    /// Champion of the last response, used to pick the simulated items
+    champion_id: Mutex<Option<String>>,
+    state: Mutex<ConnectionState>,
+    /// Cuts the wait before the next request short. A wake-up sent while a
+    /// request is running is kept until the loop waits again
+    wake: Notify,
+    /// Windows following the feed, keyed by channel id
+    subscribers: Mutex<HashMap<u32, Channel>>,
+}
+
+impl Poller {
+    pub fn new(interval_ms: u64, bounds: PollingSettings) -> Self {
+        Self {
+            generation: AtomicU64::new(0),
+            paused: AtomicBool::new(false),
+            game_code: AtomicUsize::new(0),
+            interval_ms: AtomicU64::new(clamp_interval(interval_ms)),
+            bounds: Mutex::new(bounds.clamped()),
+            rate: Mutex::new(None),
+            champion_id: Mutex::new(None),
+            state: Mutex::new(ConnectionState::Idle),
+            wake: Notify::new(),
+            subscribers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    pub fn game_code(&self) -> usize {
+        self.game_code.load(Ordering::SeqCst)
+    }
+
+    pub fn interval(&self) -> Duration {
+        Duration::from_millis(self.interval_ms.load(Ordering::SeqCst))
+    }
+
+    pub fn bounds(&self) -> PollingSettings {
+        self.bounds.lock().unwrap().clone()
+    }
+
+    pub fn set_bounds(&self, bounds: PollingSettings) {
+        *self.bounds.lock().unwrap() = bounds;
+        self.wake.notify_one();
+    }
+
+    pub fn rate(&self) -> Option<PollRate> {
+        *self.rate.lock().unwrap()
+    }
+
+    /// Stores the new rate and emits it when it changed.
+    fn set_rate(&self, app: &AppHandle, rate: Option<PollRate>) {
+        let mut current = self.rate.lock().unwrap();
+        if *current != rate {
+            *current = rate;
+            let _ = app.emit(REALTIME_UPDATE_EVENT, RealtimeUpdate::Rate(rate));
+        }
+    }
+
+    pub fn champion_id(&self) -> Option<String> {
+        self.champion_id.lock().unwrap().clone()
+    }
+
+    /// Fetches again right away, such as after the simulated items changed.
+    pub fn refresh(&self) {
+        self.wake.notify_one();
+    }
+
+    pub fn connection_state(&self) -> ConnectionState {
+        self.state.lock().unwrap().clone()
+    }
+
+    /// Stores the new state and emits it when it changed.
+    fn set_state(&self, app: &AppHandle, state: ConnectionState) {
+        let mut current = self.state.lock().unwrap();
+        if *current != state {
+            *current = state.clone();
+            let _ = app.emit(REALTIME_UPDATE_EVENT, RealtimeUpdate::State(state));
+            drop(current);
+            tray::refresh(app);
+        }
+    }
+
+    /// Starts polling `game_code`, replacing any task that is already running.
+    pub fn start(&self, app: &AppHandle, game_code: usize) {
+        let generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        self.game_code.store(game_code, Ordering::SeqCst);
+        self.paused.store(false, Ordering::SeqCst);
+        *self.champion_id.lock().unwrap() = None;
+        self.wake.notify_one();
+        self.set_state(app, ConnectionState::WaitingForGame);
+        self.set_rate(app, None);
+
+        let app = app.clone();
+        async_runtime::spawn(async move { poll_loop(app, generation).await });
+    }
+
+    /// Moves polling over to the new code of this app when it was polling
+    /// the code that was just replaced.
+    pub fn follow_game_code(&self, previous: Option<usize>, game_code: usize) {
+        if let Some(previous) = previous {
+            let _ = self.game_code.compare_exchange(
+                previous,
+                game_code,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            );
+        }
+    }
+
+    pub fn stop(&self, app: &AppHandle) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        self.wake.notify_one();
+        self.set_state(app, ConnectionState::Idle);
+        self.set_rate(app, None);
+    }
+
+    /// Pausing keeps the state and the game code but skips requests. Pausing
+    /// from the tray is reported to the frontend through `Paused`.
+    pub fn set_paused(&self, app: &AppHandle, paused: bool) {
+        self.paused.store(paused, Ordering::SeqCst);
+        self.wake.notify_one();
+        let _ = app.emit(REALTIME_UPDATE_EVENT, RealtimeUpdate::Paused(paused));
+        tray::refresh(app);
+    }
+
+    pub fn set_interval(&self, interval_ms: u64) -> u64 {
+        let interval_ms = clamp_interval(interval_ms);
+        self.interval_ms.store(interval_ms, Ordering::SeqCst);
+        self.wake.notify_one();
+        interval_ms
+    }
+
+    /// Sends every new `Realtime` to `channel` as MessagePack, which the
+    /// frontend decodes without going through JSON. Returns the id to
+    /// unsubscribe with.
+    pub fn subscribe(&self, channel: Channel) -> u32 {
+        let id = channel.id();
+        self.subscribers.lock().unwrap().insert(id, channel);
+        id
+    }
+
+    pub fn unsubscribe(&self, id: u32) {
+        self.subscribers.lock().unwrap().remove(&id);
+    }
+
+    /// Encodes `realtime` once for every subscriber. Channels of closed
+    /// windows fail to send and are dropped.
+    fn publish(&self, realtime: &Realtime) {
+        let bytes = match ipc::encode(realtime) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                error!("Failed to encode the realtime update: {}", e);
+                return;
+            }
+        };
+        self.subscribers
+            .lock()
+            .unwrap()
+            .retain(|_, channel| channel.send(InvokeResponseBody::Raw(bytes.clone())).is_ok());
+    }
+
+    fn is_current(&self, generation: u64) -> bool {
+        self.generation.load(Ordering::SeqCst) == generation
+    }
+}
+
+pub fn clamp_interval(interval_ms: u64) -> u64 {
+    interval_ms.clamp(MIN_POLL_INTERVAL_MS, MAX_POLL_INTERVAL_MS)
+}
+
+/// Whether nothing follows the feed: the main window and the overlay are
+/// hidden or minimized and no second screen can be connected.
+fn feed_hidden(app: &AppHandle, state: &AppState) -> bool {
+    if state.server.status().running {
+        return false;
+    }
+    ![MAIN_LABEL, OVERLAY_LABEL]
+        .into_iter()
+        .filter_map(|label| app.get_webview_window(label))
+        .any(|window| {
+            window.is_visible().unwrap_or(false) && !window.is_minimized().unwrap_or(false)
+        })
+}
+
+/// Requests run one after another, so a slow backend delays the next tick
+/// instead of stacking requests on top of each other. The connection state
+/// decides how long to wait before the next one, and while the game is live
+/// the pace of the game does.
+async fn poll_loop(app: AppHandle, generation: u64) {
+    let state = app.state::<AppState>();
+    let poller = &state.poller;
+    let mut pacer = Pacer::default();
+    let mut delay = poller.interval();
+
+    while poller.is_current(generation) {
+        if !poller.is_paused() {
+            let simulated_items = state.simulated_items_for(poller.champion_id().as_deref());
+            let result = fetch_game(&state, poller.game_code(), &simulated_items).await;
+            if !poller.is_current(generation) {
+                break;
+            }
+
+            let (next_state, next_delay) = poller.connection_state().next(
+                result.as_ref().map(|_| ()),
+                poller.interval().as_millis() as u64,
+            );
+            delay = Duration::from_millis(next_delay);
+            match result {
+                Ok((realtime, live)) => {
+                    let hidden = feed_hidden(&app, &state);
+                    let pace = pacer.observe(&realtime, live, hidden, Instant::now());
+                    let rate =
+                        PollRate::new(pace, poller.interval().as_millis() as u64, &poller.bounds());
+                    poller.set_rate(&app, Some(rate));
+                    delay = rate.interval();
+
+                    *poller.champion_id.lock().unwrap() =
+                        Some(realtime.current_player.champion_id.clone());
+                    if pace != Pace::GamePaused {
+                        state.server.publish(&realtime);
+                        poller.publish(&realtime);
+                        state.alerts.update(&app, realtime);
+                    }
+                }
+                Err(e) => {
+                    error!("Failed to fetch game {}: {}", poller.game_code(), e);
+                    poller.set_rate(&app, None);
+                }
+            }
+            poller.set_state(&app, next_state);
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(delay) => {}
+            _ = poller.wake.notified() => {}
+        }
+    }
+}
@@ -0,0 +1,526 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufRead, BufReader, ErrorKind, Read, Seek, SeekFrom, Write},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, Sender},
+        Mutex,
+    },
+    thread,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tutorlolv2_shared::{
+    realtime::Realtime,
+    replay::{RecordEntry, RecordSource, ReplayPage},
+};
+
+use crate::error::{self, AppError};
+
+pub const RECORDINGS_DIR: &str = "recordings";
+/// Most frames sent to the frontend at once
+pub const MAX_REPLAY_PAGE: usize = 200;
+const TAIL_BLOCK_SIZE: u64 = 64 * 1024;
+
+/// Summary of a recording file shown in the replay picker.
+#[derive(Serialize)]
+pub struct RecordingInfo {
+    pub name: String,
+    pub size: u64,
+    pub modified_at: u64,
+}
+
+/// Source and time of a recording line, read without building its payload.
+#[derive(Deserialize)]
+struct EntryHeader {
+    game_time: f64,
+    source: RecordSource,
+}
+
+#[derive(Deserialize)]
+struct RealtimeEntry {
+    payload: Realtime,
+}
+
+/// Where every frame of a recording starts, so a page is read without going
+/// through the lines before it. A recording still being written is indexed
+/// further as it grows.
+struct ReplayIndex {
+    name: String,
+    /// Byte offset of every `Realtime` line kept for the replay
+    frames: Vec<u64>,
+    /// Bytes of the file indexed so far
+    indexed_len: u64,
+    last_game_time: Option<f64>,
+}
+
+impl ReplayIndex {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            frames: Vec::new(),
+            indexed_len: 0,
+            last_game_time: None,
+        }
+    }
+
+    /// Indexes the lines after `indexed_len` until `count` frames are known
+    /// or the file ends.
+    fn extend(&mut self, file: &mut File, count: usize) -> io::Result<()> {
+        let mut reader = BufReader::new(file);
+        reader.seek(SeekFrom::Start(self.indexed_len))?;
+        let mut line = String::new();
+        while self.frames.len() < count {
+            line.clear();
+            let read = reader.read_line(&mut line)?;
+            if read == 0 {
+                break;
+            }
+            let header = serde_json::from_str::<EntryHeader>(&line);
+            // The writer may be halfway through the last line
+            if header.is_err() && !line.ends_with('\n') {
+                break;
+            }
+            let start = self.indexed_len;
+            self.indexed_len += read as u64;
+
+            let Ok(header) = header else {
+                continue;
+            };
+            if !matches!(header.source, RecordSource::Realtime)
+                || self.last_game_time == Some(header.game_time)
+            {
+                continue;
+            }
+            self.last_game_time = Some(header.game_time);
+            self.frames.push(start);
+        }
+        Ok(())
+    }
+}
+
+/// Work handed to the writer thread, so polling never waits for a payload to
+/// be serialized or written.
+enum WriterMessage {
+    /// Raw live client body, parsed by the writer
+    GameData {
+        game_code: usize,
+        recorded_at: u64,
+        body: String,
+    },
+    Realtime {
+        game_code: usize,
+        recorded_at: u64,
+        realtime: Box<Realtime>,
+    },
+    /// Closes the current file, the next entry starts a new one
+    Close,
+}
+
+struct Session {
+    game_code: usize,
+    last_game_time: f64,
+    file: File,
+}
+
+/// Owns the recording file on a thread of its own, until the recorder is
+/// dropped.
+struct Writer {
+    dir: PathBuf,
+    session: Option<Session>,
+}
+
+/// Appends every payload seen while fetching a game to a JSONL file under
+/// `<app data>/recordings`. A new file is started whenever the game code
+/// changes or the game clock goes back, which means a new match began.
+pub struct Recorder {
+    enabled: AtomicBool,
+    dir: Option<PathBuf>,
+    writer: Sender<WriterMessage>,
+    /// Index of the recording being replayed
+    replay_index: Mutex<Option<ReplayIndex>>,
+}
+
+fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+impl Recorder {
+    pub fn new(dir: Option<PathBuf>, enabled: bool) -> Self {
+        let (writer, messages) = mpsc::channel();
+        if let Some(dir) = dir.clone() {
+            thread::spawn(move || Writer { dir, session: None }.run(messages));
+        }
+        Self {
+            enabled: AtomicBool::new(enabled),
+            dir,
+            writer,
+            replay_index: Mutex::new(None),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::SeqCst)
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::SeqCst);
+        if !enabled {
+            let _ = self.writer.send(WriterMessage::Close);
+        }
+    }
+
+    /// Records the raw live client response. Bodies that are not a running
+    /// game, such as the loading screen 404, are skipped.
+    pub fn record_game_data(&self, game_code: usize, game_data: &str) {
+        if self.is_enabled() {
+            let _ = self.writer.send(WriterMessage::GameData {
+                game_code,
+                recorded_at: unix_millis(),
+                body: game_data.to_string(),
+            });
+        }
+    }
+
+    pub fn record_realtime(&self, game_code: usize, realtime: &Realtime) {
+        if self.is_enabled() {
+            let _ = self.writer.send(WriterMessage::Realtime {
+                game_code,
+                recorded_at: unix_millis(),
+                realtime: Box::new(realtime.clone()),
+            });
+        }
+    }
+
+    /// Lists the recording files, most recent first.
+    pub fn list(&self) -> Result<Vec<RecordingInfo>, AppError> {
+        let Some(dir) = &self.dir else {
+            return Ok(Vec::new());
+        };
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(error::from_storage(e)),
+        };
+
+        let mut recordings = entries
+            .filter_map(|entry| {
+                let entry = entry.ok()?;
+                let name = entry.file_name().into_string().ok()?;
+                let metadata = entry.metadata().ok()?;
+                if !name.ends_with(".jsonl") || !metadata.is_file() {
+                    return None;
+                }
+                let modified_at = metadata
+                    .modified()
+                    .ok()
+                    .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                    .map(|duration| duration.as_secs())
+                    .unwrap_or_default();
+                Some(RecordingInfo {
+                    name,
+                    size: metadata.len(),
+                    modified_at,
+                })
+            })
+            .collect::<Vec<_>>();
+        recordings.sort_by_key(|recording| std::cmp::Reverse(recording.modified_at));
+        Ok(recordings)
+    }
+
+    /// Path of a recording by file name. Names with path separators are
+    /// rejected so only files inside the recordings directory can be read.
+    fn path(&self, name: &str) -> Result<PathBuf, AppError> {
+        let dir = self
+            .dir
+            .as_ref()
+            .ok_or_else(|| error::from_storage("App data directory is unavailable"))?;
+        if name.contains(['/', '\\']) || name.starts_with('.') {
+            return Err(AppError::InvalidInput(format!(
+                "Invalid recording name: {}",
+                name
+            )));
+        }
+        Ok(dir.join(name))
+    }
+
+    /// Up to `limit` `Realtime` frames of a recording, starting at the
+    /// `offset`-th. The raw `allgamedata` lines are skipped without being
+    /// parsed, and so are frames repeating the game time of the previous
+    /// one, such as while the game was paused.
+    pub fn read_replay(
+        &self,
+        name: &str,
+        offset: usize,
+        limit: usize,
+    ) -> Result<ReplayPage, AppError> {
+        let mut file = File::open(self.path(name)?).map_err(error::from_storage)?;
+        let len = file.metadata().map_err(error::from_storage)?.len();
+        let limit = limit.clamp(1, MAX_REPLAY_PAGE);
+
+        let mut replay_index = self.replay_index.lock().unwrap();
+        let index = match replay_index.as_mut() {
+            Some(index) if index.name == name && index.indexed_len <= len => index,
+            _ => replay_index.insert(ReplayIndex::new(name)),
+        };
+        // One frame past the page tells whether another page follows
+        index
+            .extend(&mut file, offset + limit + 1)
+            .map_err(error::from_storage)?;
+
+        let mut reader = BufReader::new(file);
+        let mut line = String::new();
+        let mut frames = Vec::new();
+        for &start in index.frames.iter().skip(offset).take(limit) {
+            line.clear();
+            reader
+                .seek(SeekFrom::Start(start))
+                .and_then(|_| reader.read_line(&mut line))
+                .map_err(error::from_storage)?;
+            match serde_json::from_str::<RealtimeEntry>(&line) {
+                Ok(entry) => frames.push(entry.payload),
+                Err(e) => warn!("Skipped an invalid frame of {}: {}", name, e),
+            }
+        }
+        Ok(ReplayPage {
+            frames,
+            next_offset: (index.frames.len() > offset + limit).then_some(offset + limit),
+        })
+    }
+
+    /// Last `count` lines of the most recent recording, attached to
+    /// diagnostics bundles. A whole game runs into tens of megabytes, so
+    /// only the end of the file is read.
+    pub fn latest_entries(&self, count: usize) -> Result<Vec<String>, AppError> {
+        let Some(latest) = self.list()?.into_iter().next() else {
+            return Ok(Vec::new());
+        };
+        let mut file = File::open(self.path(&latest.name)?).map_err(error::from_storage)?;
+        tail_lines(&mut file, count).map_err(error::from_storage)
+    }
+}
+
+/// Reads `file` backwards a block at a time until the last `count` lines
+/// were read whole.
+fn tail_lines(file: &mut File, count: usize) -> io::Result<Vec<String>> {
+    let mut end = file.seek(SeekFrom::End(0))?;
+    let mut tail = Vec::new();
+    let mut newlines = 0;
+    while end > 0 && newlines <= count {
+        let start = end.saturating_sub(TAIL_BLOCK_SIZE);
+        let mut block = vec![0; (end - start) as usize];
+        file.seek(SeekFrom::Start(start))?;
+        file.read_exact(&mut block)?;
+        newlines += block.iter().filter(|&&byte| byte == b'\n').count();
+        block.extend_from_slice(&tail);
+        tail = block;
+        end = start;
+    }
+
+    // Unless the file was read from its start, the first line is cut
+    let tail = String::from_utf8_lossy(&tail);
+    let lines = tail
+        .lines()
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>();
+    Ok(lines[lines.len().saturating_sub(count)..]
+        .iter()
+        .map(|line| line.to_string())
+        .collect())
+}
+
+impl Writer {
+    fn run(mut self, messages: Receiver<WriterMessage>) {
+        for message in messages {
+            match message {
+                WriterMessage::GameData {
+                    game_code,
+                    recorded_at,
+                    body,
+                } => {
+                    let Ok(payload) = serde_json::from_str::<Value>(&body) else {
+                        continue;
+                    };
+                    if let Some(game_time) = payload["gameData"]["gameTime"].as_f64() {
+                        self.write(
+                            game_code,
+                            RecordEntry {
+                                game_time,
+                                recorded_at,
+                                source: RecordSource::AllGameData,
+                                payload,
+                            },
+                        );
+                    }
+                }
+                WriterMessage::Realtime {
+                    game_code,
+                    recorded_at,
+                    realtime,
+                } => match serde_json::to_value(&realtime) {
+                    Ok(payload) => self.write(
+                        game_code,
+                        RecordEntry {
+                            game_time: realtime.game_information.game_time,
+                            recorded_at,
+                            source: RecordSource::Realtime,
+                            payload,
+                        },
+                    ),
+                    Err(e) => error!("Failed to record the realtime response: {}", e),
+                },
+                WriterMessage::Close => self.session = None,
+            }
+        }
+    }
+
+    fn write(&mut self, game_code: usize, entry: RecordEntry) {
+        let line = match serde_json::to_string(&entry) {
+            Ok(line) => line,
+            Err(e) => {
+                error!("Failed to serialize recording entry: {:?}", e);
+                return;
+            }
+        };
+
+        let is_new_game = self.session.as_ref().is_none_or(|session| {
+            session.game_code != game_code || entry.game_time + 1.0 < session.last_game_time
+        });
+        if is_new_game {
+            self.session = self.open_session(game_code);
+        }
+        let Some(session) = self.session.as_mut() else {
+            return;
+        };
+
+        session.last_game_time = entry.game_time;
+        if let Err(e) = writeln!(session.file, "{}", line) {
+            error!("Failed to write recording entry: {:?}", e);
+        }
+    }
+
+    fn open_session(&self, game_code: usize) -> Option<Session> {
+        let path = self
+            .dir
+            .join(format!("{}-{}.jsonl", game_code, unix_millis() / 1000));
+        let file = fs::create_dir_all(&self.dir)
+            .and_then(|_| File::options().create(true).append(true).open(&path))
+            .map_err(|e| error!("Failed to open recording file {:?}: {:?}", path, e))
+            .ok()?;
+        info!("Recording game {} to {:?}", game_code, path);
+        Some(Session {
+            game_code,
+            last_game_time: 0.0,
+            file,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const REALTIME: &str = include_str!("../../mocks/fixtures/backend/realtime.json");
+
+    #[test]
+    fn replays_are_paged_without_the_raw_payloads() {
+        let dir = std::env::temp_dir().join(format!("tutorlolv2-replay-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let realtime: Value = serde_json::from_str(REALTIME).unwrap();
+        let mut lines = Vec::new();
+        // The backend answers twice at 61s, like it does while paused
+        for game_time in [60.0, 61.0, 61.0, 62.0, 63.0] {
+            for (source, payload) in [
+                (
+                    RecordSource::AllGameData,
+                    json!({ "gameData": { "gameTime": game_time } }),
+                ),
+                (RecordSource::Realtime, realtime.clone()),
+            ] {
+                let entry = RecordEntry {
+                    game_time,
+                    recorded_at: 0,
+                    source,
+                    payload,
+                };
+                lines.push(serde_json::to_string(&entry).unwrap());
+            }
+        }
+        fs::write(dir.join("123456-1.jsonl"), lines.join("\n")).unwrap();
+
+        let recorder = Recorder::new(Some(dir.clone()), false);
+        let first = recorder.read_replay("123456-1.jsonl", 0, 3).unwrap();
+        assert_eq!(first.frames.len(), 3);
+        assert_eq!(first.next_offset, Some(3));
+        let second = recorder.read_replay("123456-1.jsonl", 3, 3).unwrap();
+        assert_eq!(second.frames.len(), 1);
+        assert_eq!(second.next_offset, None);
+        assert!(recorder.read_replay("../123456-1.jsonl", 0, 3).is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn a_recording_being_written_is_paged_as_it_grows() {
+        let dir = std::env::temp_dir().join(format!("tutorlolv2-growing-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("123456-1.jsonl");
+        let realtime: Value = serde_json::from_str(REALTIME).unwrap();
+        let line = |game_time: f64| {
+            let entry = RecordEntry {
+                game_time,
+                recorded_at: 0,
+                source: RecordSource::Realtime,
+                payload: realtime.clone(),
+            };
+            serde_json::to_string(&entry).unwrap() + "\n"
+        };
+        let mut file = File::create(&path).unwrap();
+        file.write_all((line(60.0) + &line(61.0)).as_bytes())
+            .unwrap();
+        // Half of a line the writer has not finished yet
+        let next = line(62.0).into_bytes();
+        file.write_all(&next[..10]).unwrap();
+
+        let recorder = Recorder::new(Some(dir.clone()), false);
+        let first = recorder.read_replay("123456-1.jsonl", 0, 2).unwrap();
+        assert_eq!(first.frames.len(), 2);
+        assert_eq!(first.next_offset, None);
+
+        file.write_all(&next[10..]).unwrap();
+        file.write_all(line(63.0).as_bytes()).unwrap();
+        let second = recorder.read_replay("123456-1.jsonl", 2, 2).unwrap();
+        assert_eq!(second.frames.len(), 2);
+        assert_eq!(second.next_offset, None);
+        let whole = recorder.read_replay("123456-1.jsonl", 0, 3).unwrap();
+        assert_eq!(whole.next_offset, Some(3));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn only_the_last_lines_are_read_whole() {
+        let path =
+            std::env::temp_dir().join(format!("tutorlolv2-tail-{}.jsonl", std::process::id()));
+        // Lines longer than a block, like allgamedata payloads
+        let lines = (0..6)
+            .map(|line| format!("{}{}", line, "x".repeat(TAIL_BLOCK_SIZE as usize / 2)))
+            .collect::<Vec<_>>();
+        fs::write(&path, lines.join("\n") + "\n").unwrap();
+
+        let mut file = File::open(&path).unwrap();
+        assert_eq!(tail_lines(&mut file, 3).unwrap(), lines[3..]);
+        assert_eq!(tail_lines(&mut file, 10).unwrap(), lines);
+
+        fs::remove_file(path).unwrap();
+    }
+}
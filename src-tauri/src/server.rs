@@ -0,0 +1,340 @@
+use std::sync::{Arc, Mutex, RwLock};
+
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        State as AxumState,
+    },
+    http::{header, StatusCode},
+    response::{Html, IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use log::info;
+use serde::Serialize;
+use tauri::async_runtime::JoinHandle;
+use tokio::{
+    net::TcpListener,
+    sync::{broadcast, mpsc, watch},
+};
+use tutorlolv2_shared::realtime::Realtime;
+
+use crate::{error::AppError, settings::ServerSettings};
+
+/// Browser source for OBS, which follows `/ws` on the same server
+const OBS_OVERLAY_PAGE: &str = include_str!("obs_overlay.html");
+/// Updates a slow client may fall behind before it skips to the newest one
+const UPDATE_CAPACITY: usize = 16;
+
+#[derive(Clone, Serialize)]
+pub struct ServerStatus {
+    pub running: bool,
+    pub url: Option<String>,
+    /// Why the server could not start, such as a port already in use
+    pub error: Option<String>,
+}
+
+/// Shared with the request handlers. Holds the `Realtime` JSON of the last
+/// response, serialized once for every client.
+struct Feed {
+    latest: RwLock<Option<String>>,
+    updates: broadcast::Sender<String>,
+}
+
+/// State of the request handlers of one started server.
+#[derive(Clone)]
+struct Connections {
+    feed: Arc<Feed>,
+    /// Turns true when the server stops, which closes the `/ws` followers
+    stopping: watch::Receiver<bool>,
+    /// Held by the router and every follower, so stopping can wait until
+    /// nothing uses the port anymore
+    alive: mpsc::Sender<()>,
+}
+
+struct Running {
+    stopping: watch::Sender<bool>,
+    task: JoinHandle<()>,
+    /// Closes once every `Connections::alive` was dropped
+    closed: mpsc::Receiver<()>,
+}
+
+/// Optional HTTP server on the local network for second screens and OBS.
+/// `GET /realtime` returns the latest `Realtime`, `GET /ws` pushes every new
+/// one and `GET /` is an overlay page built on top of it.
+pub struct LiveServer {
+    feed: Arc<Feed>,
+    running: Mutex<Option<Running>>,
+    status: Mutex<ServerStatus>,
+}
+
+impl LiveServer {
+    pub fn new() -> Self {
+        Self {
+            feed: Arc::new(Feed {
+                latest: RwLock::new(None),
+                updates: broadcast::channel(UPDATE_CAPACITY).0,
+            }),
+            running: Mutex::new(None),
+            status: Mutex::new(ServerStatus {
+                running: false,
+                url: None,
+                error: None,
+            }),
+        }
+    }
+
+    pub fn status(&self) -> ServerStatus {
+        self.status.lock().unwrap().clone()
+    }
+
+    /// Keeps `realtime` for new clients and sends it to the connected ones. Does
+    /// nothing while the server is stopped.
+    pub fn publish(&self, realtime: &Realtime) {
+        if self.running.lock().unwrap().is_none() {
+            return;
+        }
+        let Ok(json) = serde_json::to_string(realtime) else {
+            return;
+        };
+        *self.feed.latest.write().unwrap() = Some(json.clone());
+        // Fails only when nobody is connected
+        let _ = self.feed.updates.send(json);
+    }
+
+    /// Stops the running server, then starts a new one when `settings`
+    /// enables it. Bind errors are returned and kept in the status.
+    pub async fn restart(&self, settings: &ServerSettings) -> Result<ServerStatus, AppError> {
+        self.stop().await;
+        if !settings.enabled {
+            return Ok(self.status());
+        }
+
+        let address = format!("{}:{}", settings.address, settings.port);
+        let listener = match TcpListener::bind(&address).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                let error = format!("Could not listen on {}: {}", address, e);
+                self.status.lock().unwrap().error = Some(error.clone());
+                return Err(AppError::LocalServer(error));
+            }
+        };
+
+        let (stopping, mut stopped) = watch::channel(false);
+        let (alive, closed) = mpsc::channel(1);
+        let router = Router::new()
+            .route("/", get(overlay_page))
+            .route("/realtime", get(latest_realtime))
+            .route("/ws", get(websocket))
+            .with_state(Connections {
+                feed: self.feed.clone(),
+                stopping: stopped.clone(),
+                alive,
+            });
+        let task = tauri::async_runtime::spawn(async move {
+            let server = axum::serve(listener, router).with_graceful_shutdown(async move {
+                let _ = stopped.wait_for(|&stopping| stopping).await;
+            });
+            if let Err(e) = server.await {
+                info!("Live server stopped: {}", e);
+            }
+        });
+
+        info!("Live server listening on http://{}", address);
+        *self.running.lock().unwrap() = Some(Running {
+            stopping,
+            task,
+            closed,
+        });
+        *self.status.lock().unwrap() = ServerStatus {
+            running: true,
+            url: Some(format!("http://{}", address)),
+            error: None,
+        };
+        Ok(self.status())
+    }
+
+    /// Returns once the port is free again. The latest `Realtime` is
+    /// forgotten, so a restarted server does not serve the previous match.
+    pub async fn stop(&self) {
+        let running = self.running.lock().unwrap().take();
+        if let Some(mut running) = running {
+            let _ = running.stopping.send(true);
+            let _ = running.task.await;
+            // Ends when the last follower dropped its sender
+            let _ = running.closed.recv().await;
+        }
+        *self.feed.latest.write().unwrap() = None;
+        *self.status.lock().unwrap() = ServerStatus {
+            running: false,
+            url: None,
+            error: None,
+        };
+    }
+}
+
+async fn overlay_page() -> Html<&'static str> {
+    Html(OBS_OVERLAY_PAGE)
+}
+
+/// Other origins are allowed so pages hosted elsewhere can read the feed.
+async fn latest_realtime(AxumState(connections): AxumState<Connections>) -> Response {
+    match connections.feed.latest.read().unwrap().clone() {
+        Some(json) => (
+            [
+                (header::CONTENT_TYPE, "application/json"),
+                (header::ACCESS_CONTROL_ALLOW_ORIGIN, "*"),
+            ],
+            json,
+        )
+            .into_response(),
+        None => StatusCode::NO_CONTENT.into_response(),
+    }
+}
+
+async fn websocket(
+    ws: WebSocketUpgrade,
+    AxumState(connections): AxumState<Connections>,
+) -> Response {
+    ws.on_upgrade(move |socket| follow_feed(socket, connections))
+}
+
+/// Sends the latest `Realtime` right away, then every update until the
+/// client disconnects or the server stops.
+async fn follow_feed(mut socket: WebSocket, connections: Connections) {
+    let Connections {
+        feed,
+        mut stopping,
+        alive: _alive,
+    } = connections;
+    let mut updates = feed.updates.subscribe();
+    let latest = feed.latest.read().unwrap().clone();
+    if let Some(json) = latest {
+        if socket.send(Message::Text(json.into())).await.is_err() {
+            return;
+        }
+    }
+    loop {
+        tokio::select! {
+            update = updates.recv() => match update {
+                Ok(json) => {
+                    if socket.send(Message::Text(json.into())).await.is_err() {
+                        return;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(_)) => {}
+                Err(broadcast::error::RecvError::Closed) => return,
+            },
+            message = socket.recv() => match message {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => return,
+                _ => {}
+            },
+            // Only ever changes to true, or drops when the server stops
+            _ = stopping.changed() => {
+                let _ = socket.send(Message::Close(None)).await;
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::{TcpListener as StdTcpListener, TcpStream as StdTcpStream},
+    };
+
+    use super::*;
+
+    const REALTIME: &str = include_str!("../../mocks/fixtures/backend/realtime.json");
+
+    fn settings(port: u16) -> ServerSettings {
+        ServerSettings {
+            enabled: true,
+            address: "127.0.0.1".to_string(),
+            port,
+        }
+    }
+
+    /// A port nothing listens on right now.
+    fn free_port() -> u16 {
+        StdTcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port()
+    }
+
+    #[test]
+    fn a_port_in_use_is_reported_in_the_status() {
+        let taken = StdTcpListener::bind("127.0.0.1:0").unwrap();
+        let port = taken.local_addr().unwrap().port();
+        let server = LiveServer::new();
+
+        let restarted = tauri::async_runtime::block_on(server.restart(&settings(port)));
+        assert!(matches!(restarted, Err(AppError::LocalServer(_))));
+        let status = server.status();
+        assert!(!status.running);
+        assert!(status.error.is_some());
+    }
+
+    #[test]
+    fn the_latest_realtime_is_served_once_published() {
+        let realtime: Realtime = serde_json::from_str(REALTIME).unwrap();
+        let server = LiveServer::new();
+        // Nothing is kept while the server is stopped
+        server.publish(&realtime);
+
+        tauri::async_runtime::block_on(async {
+            let status = server.restart(&settings(free_port())).await.unwrap();
+            let url = format!("{}/realtime", status.url.unwrap());
+            let empty = reqwest::get(&url).await.unwrap();
+            assert_eq!(empty.status(), StatusCode::NO_CONTENT);
+
+            server.publish(&realtime);
+            let served = reqwest::get(&url)
+                .await
+                .unwrap()
+                .json::<Realtime>()
+                .await
+                .unwrap();
+            assert_eq!(served, realtime);
+        });
+
+        tauri::async_runtime::block_on(server.stop());
+        assert!(!server.status().running);
+    }
+
+    #[test]
+    fn restarting_closes_followers_and_forgets_the_last_match() {
+        let realtime: Realtime = serde_json::from_str(REALTIME).unwrap();
+        let server = LiveServer::new();
+        let port = free_port();
+        tauri::async_runtime::block_on(async {
+            server.restart(&settings(port)).await.unwrap();
+            server.publish(&realtime);
+        });
+
+        let mut follower = StdTcpStream::connect(("127.0.0.1", port)).unwrap();
+        write!(
+            follower,
+            "GET /ws HTTP/1.1\r\nHost: 127.0.0.1\r\nConnection: Upgrade\r\n\
+             Upgrade: websocket\r\nSec-WebSocket-Version: 13\r\n\
+             Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n\r\n"
+        )
+        .unwrap();
+        let mut response = [0; 12];
+        follower.read_exact(&mut response).unwrap();
+        assert_eq!(&response, b"HTTP/1.1 101");
+
+        tauri::async_runtime::block_on(async {
+            let status = server.restart(&settings(port)).await.unwrap();
+            assert!(status.running);
+            let url = format!("{}/realtime", status.url.unwrap());
+            let previous_match = reqwest::get(&url).await.unwrap();
+            assert_eq!(previous_match.status(), StatusCode::NO_CONTENT);
+            server.stop().await;
+        });
+    }
+}
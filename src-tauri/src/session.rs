@@ -0,0 +1,275 @@
+use std::{sync::RwLock, time::Duration};
+
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::sync::Mutex;
+use tutorlolv2_shared::ServerResponse;
+
+use crate::{
+    error::{self, AppError},
+    tray, AppState,
+};
+
+pub const GAME_SESSION_EVENT: &str = "game-session";
+pub const WATCH_INTERVAL_MS: u64 = 3000;
+/// Game time may jitter slightly between requests. A bigger drop means the
+/// client started another match.
+const GAME_TIME_RESET_THRESHOLD: f64 = 5.0;
+
+#[derive(Clone, Deserialize)]
+pub struct GameCode {
+    pub game_code: usize,
+    pub game_id: String,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum SessionEvent {
+    CodeChanged {
+        game_code: usize,
+        previous: Option<usize>,
+    },
+    MatchStarted {
+        game_code: Option<usize>,
+    },
+    MatchEnded {
+        game_code: Option<usize>,
+    },
+}
+
+/// Game code shared by this app. Each match gets its own code, created when
+/// the match starts if the current one already belongs to an earlier match.
+pub struct GameSession {
+    code: RwLock<Option<GameCode>>,
+    /// Whether a match was already played under the current code
+    code_used: RwLock<bool>,
+    creating: Mutex<()>,
+}
+
+impl GameSession {
+    pub fn new() -> Self {
+        Self {
+            code: RwLock::new(None),
+            code_used: RwLock::new(false),
+            creating: Mutex::new(()),
+        }
+    }
+
+    pub fn current(&self) -> Option<GameCode> {
+        self.code.read().unwrap().clone()
+    }
+
+    pub fn game_code(&self) -> Option<usize> {
+        self.current().map(|code| code.game_code)
+    }
+
+    /// Requests a new code from the backend and makes it current. Concurrent
+    /// calls wait for each other so a single match never gets two codes.
+    pub async fn regenerate(&self, app: &AppHandle) -> Result<usize, AppError> {
+        let _creating = self.creating.lock().await;
+        let state = app.state::<AppState>();
+        let code = create_game(&state).await?;
+
+        let previous = self.code.write().unwrap().replace(code.clone());
+        *self.code_used.write().unwrap() = false;
+        let previous = previous.map(|code| code.game_code);
+        state.poller.follow_game_code(previous, code.game_code);
+
+        info!("Game code changed to {}", code.game_code);
+        state.settings.remember_game_code(code.game_code);
+        tray::refresh(app);
+        let _ = app.emit(
+            GAME_SESSION_EVENT,
+            SessionEvent::CodeChanged {
+                game_code: code.game_code,
+                previous,
+            },
+        );
+        Ok(code.game_code)
+    }
+
+    async fn on_match_started(&self, app: &AppHandle) {
+        let code_used = *self.code_used.read().unwrap();
+        if code_used || self.current().is_none() {
+            if let Err(e) = self.regenerate(app).await {
+                error!("Failed to create a game code for the new match: {}", e);
+            }
+        }
+        *self.code_used.write().unwrap() = true;
+        let _ = app.emit(
+            GAME_SESSION_EVENT,
+            SessionEvent::MatchStarted {
+                game_code: self.game_code(),
+            },
+        );
+    }
+}
+
+async fn create_game(state: &AppState) -> Result<GameCode, AppError> {
+    let response = state
+        .backend_client
+        .get(format!("{}/api/games/create", state.backend_url()))
+        .send()
+        .await
+        .map_err(|e| {
+            error!("Failed to send the game creation request: {}", e);
+            error::from_backend(e)
+        })?;
+    let ServerResponse::<Option<GameCode>> {
+        success,
+        data,
+        message,
+    } = error::check_backend_status(response)
+        .await
+        .inspect_err(|e| error!("The backend failed to create a game: {}", e))?
+        .json()
+        .await
+        .map_err(|e| {
+            error!("Failed to parse the game creation response: {}", e);
+            AppError::ParseError(e.to_string())
+        })?;
+
+    match data {
+        Some(code) if success => Ok(code),
+        _ => Err(AppError::BackendRejected(
+            message.unwrap_or("Failed to create game".to_string()),
+        )),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum MatchChange {
+    Started,
+    Ended,
+    /// The game time went back while the client kept serving a game
+    Restarted,
+}
+
+#[derive(Default)]
+struct MatchTracker {
+    running: bool,
+    last_game_time: f64,
+}
+
+impl MatchTracker {
+    /// `game_time` is `None` while the live client has no game to serve.
+    fn update(&mut self, game_time: Option<f64>) -> Option<MatchChange> {
+        let change = match (self.running, game_time) {
+            (false, Some(_)) => Some(MatchChange::Started),
+            (true, None) => Some(MatchChange::Ended),
+            (true, Some(game_time))
+                if game_time + GAME_TIME_RESET_THRESHOLD < self.last_game_time =>
+            {
+                Some(MatchChange::Restarted)
+            }
+            _ => None,
+        };
+        self.running = game_time.is_some();
+        self.last_game_time = game_time.unwrap_or_default();
+        change
+    }
+}
+
+async fn live_game_time(state: &AppState) -> Option<f64> {
+    let response = state
+        .live_client
+        .get(format!(
+            "{}/liveclientdata/gamestats",
+            state.live_client_url
+        ))
+        .send()
+        .await
+        .ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    response.json::<Value>().await.ok()?["gameTime"].as_f64()
+}
+
+/// Creates the first game code without blocking startup, then watches the
+/// live client for matches starting, ending or restarting.
+pub async fn watch_matches(app: AppHandle) {
+    let state = app.state::<AppState>();
+    let session = &state.session;
+    let mut tracker = MatchTracker::default();
+
+    if let Err(e) = session.regenerate(&app).await {
+        error!("Failed to initialize app game creation: {}", e);
+    }
+
+    loop {
+        tokio::time::sleep(Duration::from_millis(WATCH_INTERVAL_MS)).await;
+
+        let game_time = live_game_time(&state).await;
+        match tracker.update(game_time) {
+            Some(MatchChange::Started) => {
+                info!("Match started");
+                session.on_match_started(&app).await;
+            }
+            Some(MatchChange::Restarted) => {
+                info!("Match restarted");
+                let _ = app.emit(
+                    GAME_SESSION_EVENT,
+                    SessionEvent::MatchEnded {
+                        game_code: session.game_code(),
+                    },
+                );
+                session.on_match_started(&app).await;
+            }
+            Some(MatchChange::Ended) => {
+                info!("Match ended");
+                let _ = app.emit(
+                    GAME_SESSION_EVENT,
+                    SessionEvent::MatchEnded {
+                        game_code: session.game_code(),
+                    },
+                );
+            }
+            // Retries a code that could not be created earlier
+            None if session.current().is_none() => {
+                let _ = session.regenerate(&app).await;
+            }
+            None => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_game_time_going_back_restarts_the_match() {
+        let mut tracker = MatchTracker::default();
+        assert_eq!(tracker.update(Some(600.0)), Some(MatchChange::Started));
+        assert_eq!(tracker.update(Some(601.0)), None);
+        assert_eq!(tracker.update(Some(4.0)), Some(MatchChange::Restarted));
+        assert_eq!(tracker.update(Some(5.0)), None);
+    }
+
+    #[test]
+    fn the_live_client_going_away_ends_the_match() {
+        let mut tracker = MatchTracker::default();
+        assert_eq!(tracker.update(None), None);
+        assert_eq!(tracker.update(Some(600.0)), Some(MatchChange::Started));
+        assert_eq!(tracker.update(None), Some(MatchChange::Ended));
+        assert_eq!(tracker.update(None), None);
+        assert_eq!(tracker.update(Some(1.0)), Some(MatchChange::Started));
+    }
+
+    #[test]
+    fn repeated_game_stats_keep_the_match_going() {
+        let mut tracker = MatchTracker::default();
+        assert_eq!(tracker.update(Some(600.0)), Some(MatchChange::Started));
+        // A paused game answers with the same time every poll
+        assert_eq!(tracker.update(Some(600.0)), None);
+        assert_eq!(tracker.update(Some(600.0)), None);
+        // and small differences in how the time is rounded are not a reset
+        assert_eq!(
+            tracker.update(Some(600.0 - GAME_TIME_RESET_THRESHOLD / 2.0)),
+            None
+        );
+    }
+}
@@ -0,0 +1,587 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    sync::{RwLock, RwLockReadGuard},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use log::{error, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::{AppHandle, Manager, PhysicalPosition, PhysicalSize, WebviewWindow};
+
+use crate::{
+    error::{self, AppError},
+    hotkeys::{default_bindings, HotkeyAction},
+    realtime::{DEFAULT_POLL_INTERVAL_MS, MAX_POLL_INTERVAL_MS, MIN_POLL_INTERVAL_MS},
+    AppState,
+};
+
+pub const DEFAULT_BACKEND_URL: &str = "https://v2.tutorlol.com";
+pub const BACKEND_URL_ENV: &str = "TUTORLOL_BACKEND_URL";
+pub const BACKEND_URL_FLAG: &str = "--backend-url";
+pub const DEFAULT_LIVE_CLIENT_URL: &str = "https://127.0.0.1:2999";
+pub const LIVE_CLIENT_URL_ENV: &str = "TUTORLOL_LIVE_CLIENT_URL";
+/// Nashor's Tooth, compared when no items were chosen for the champion
+pub const DEFAULT_SIMULATED_ITEMS: [usize; 1] = [3115];
+
+const SETTINGS_FILE: &str = "settings.json";
+const MAX_RECENT_GAME_CODES: usize = 5;
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub backend_url: Option<String>,
+    pub poll_interval_ms: u64,
+    pub polling: PollingSettings,
+    pub record_games: bool,
+    /// Items compared in Realtime, keyed by champion id
+    pub simulated_items: HashMap<String, Vec<usize>>,
+    /// Global hotkeys such as "Ctrl+Shift+D", empty to disable an action
+    pub hotkeys: HashMap<HotkeyAction, String>,
+    pub overlay: OverlaySettings,
+    /// Codes created or watched lately, most recent first
+    pub recent_game_codes: Vec<usize>,
+    pub kill_alerts: KillAlertSettings,
+    pub window: WindowSettings,
+    pub ui: UiSettings,
+    pub server: ServerSettings,
+}
+
+/// Local server for second screens and OBS. Listening on `0.0.0.0` makes it
+/// reachable from other devices on the network.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServerSettings {
+    pub enabled: bool,
+    pub address: String,
+    pub port: u16,
+}
+
+impl Default for ServerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            address: "127.0.0.1".to_string(),
+            port: 47800,
+        }
+    }
+}
+
+/// Main window options that used to be fixed in `tauri.conf.json`, in
+/// logical pixels. The window is centered until it was moved for the first
+/// time.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WindowSettings {
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+    pub width: f64,
+    pub height: f64,
+    pub always_on_top: bool,
+    pub decorations: bool,
+    pub skip_taskbar: bool,
+    pub resizable: bool,
+    /// Opacity of the page background, from 0.2 to 1. The window itself is
+    /// always transparent so the game shows through
+    pub background_opacity: f64,
+}
+
+impl Default for WindowSettings {
+    fn default() -> Self {
+        Self {
+            x: None,
+            y: None,
+            width: 1280.0,
+            height: 720.0,
+            always_on_top: true,
+            decorations: true,
+            skip_taskbar: true,
+            resizable: true,
+            background_opacity: 1.0,
+        }
+    }
+}
+
+impl WindowSettings {
+    pub const MIN_WIDTH: f64 = 640.0;
+    pub const MIN_HEIGHT: f64 = 360.0;
+    pub const MIN_BACKGROUND_OPACITY: f64 = 0.2;
+
+    /// Keeps the size and opacity within bounds, so a bad value can not leave
+    /// the window unusable.
+    pub fn clamped(self) -> Self {
+        Self {
+            width: self.width.max(Self::MIN_WIDTH),
+            height: self.height.max(Self::MIN_HEIGHT),
+            background_opacity: self
+                .background_opacity
+                .clamp(Self::MIN_BACKGROUND_OPACITY, 1.0),
+            ..self
+        }
+    }
+}
+
+/// What the frontend was showing when the app was closed.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiSettings {
+    pub selected_page: usize,
+    /// Indices of the enemies hidden on the Realtime page
+    pub hidden_enemies: Vec<usize>,
+    /// Damage stack of the Realtime page, as sent by the frontend
+    pub stack: Value,
+}
+
+impl Default for UiSettings {
+    fn default() -> Self {
+        Self {
+            selected_page: 1,
+            hidden_enemies: Vec::new(),
+            stack: Value::Array(Vec::new()),
+        }
+    }
+}
+
+/// Notifications sent when the damage stack can kill an enemy.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KillAlertSettings {
+    pub enabled: bool,
+    pub sound: bool,
+    /// Minimum time between two alerts for the same enemy
+    pub cooldown_secs: u64,
+}
+
+impl Default for KillAlertSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            sound: true,
+            cooldown_secs: 30,
+        }
+    }
+}
+
+/// Bounds of the adaptive poll rate. Fights are polled at the fastest rate,
+/// while the player is dead or no window shows the feed at the slowest.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PollingSettings {
+    pub min_interval_ms: u64,
+    pub max_interval_ms: u64,
+}
+
+impl Default for PollingSettings {
+    fn default() -> Self {
+        Self {
+            min_interval_ms: 500,
+            max_interval_ms: 5000,
+        }
+    }
+}
+
+impl PollingSettings {
+    /// Keeps both bounds within what the poller accepts, with the fastest
+    /// rate never slower than the slowest.
+    pub fn clamped(self) -> Self {
+        let min_interval_ms = self
+            .min_interval_ms
+            .clamp(MIN_POLL_INTERVAL_MS, MAX_POLL_INTERVAL_MS);
+        Self {
+            min_interval_ms,
+            max_interval_ms: self
+                .max_interval_ms
+                .clamp(min_interval_ms, MAX_POLL_INTERVAL_MS),
+        }
+    }
+}
+
+/// Placement of the overlay window in logical pixels. The window is centered
+/// until it was moved for the first time.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OverlaySettings {
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+    pub width: f64,
+    pub height: f64,
+    /// Adds the damage of every ability, item and rune per enemy
+    pub show_base_table: bool,
+}
+
+impl Default for OverlaySettings {
+    fn default() -> Self {
+        Self {
+            x: None,
+            y: None,
+            width: 360.0,
+            height: 240.0,
+            show_base_table: false,
+        }
+    }
+}
+
+/// Where a window was left, in logical pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geometry {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Geometry {
+    /// Converts the outer position and inner size a window reports.
+    pub fn from_physical(
+        position: PhysicalPosition<i32>,
+        size: PhysicalSize<u32>,
+        scale: f64,
+    ) -> Self {
+        let position = position.to_logical::<f64>(scale);
+        let size = size.to_logical::<f64>(scale);
+        Self {
+            x: position.x,
+            y: position.y,
+            width: size.width,
+            height: size.height,
+        }
+    }
+}
+
+/// Settings of a window that reopens where it was left.
+pub trait WindowGeometry {
+    fn set_geometry(&mut self, geometry: Geometry);
+}
+
+impl WindowGeometry for WindowSettings {
+    fn set_geometry(&mut self, geometry: Geometry) {
+        self.x = Some(geometry.x);
+        self.y = Some(geometry.y);
+        self.width = geometry.width;
+        self.height = geometry.height;
+    }
+}
+
+impl WindowGeometry for OverlaySettings {
+    fn set_geometry(&mut self, geometry: Geometry) {
+        self.x = Some(geometry.x);
+        self.y = Some(geometry.y);
+        self.width = geometry.width;
+        self.height = geometry.height;
+    }
+}
+
+/// Stores where `window` was left in the settings `slot` points to.
+pub fn save_geometry<T: WindowGeometry>(
+    app: &AppHandle,
+    window: &WebviewWindow,
+    slot: fn(&mut Settings) -> &mut T,
+) {
+    let Ok(scale) = window.scale_factor() else {
+        return;
+    };
+    let (Ok(position), Ok(size)) = (window.outer_position(), window.inner_size()) else {
+        return;
+    };
+    let geometry = Geometry::from_physical(position, size, scale);
+
+    let saved = app
+        .state::<AppState>()
+        .settings
+        .update(|settings| slot(settings).set_geometry(geometry));
+    if let Err(e) = saved {
+        error!("Failed to save where {} was left: {}", window.label(), e);
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            backend_url: None,
+            poll_interval_ms: DEFAULT_POLL_INTERVAL_MS,
+            polling: PollingSettings::default(),
+            record_games: false,
+            simulated_items: HashMap::new(),
+            hotkeys: default_bindings(),
+            overlay: OverlaySettings::default(),
+            recent_game_codes: Vec::new(),
+            kill_alerts: KillAlertSettings::default(),
+            window: WindowSettings::default(),
+            ui: UiSettings::default(),
+            server: ServerSettings::default(),
+        }
+    }
+}
+
+/// Items compared for `champion_id`, falling back to the default list while
+/// the champion is unknown or has none chosen.
+pub fn simulated_items_for(
+    simulated_items: &HashMap<String, Vec<usize>>,
+    champion_id: Option<&str>,
+) -> Vec<usize> {
+    champion_id
+        .and_then(|champion_id| simulated_items.get(champion_id))
+        .filter(|items| !items.is_empty())
+        .cloned()
+        .unwrap_or_else(|| DEFAULT_SIMULATED_ITEMS.to_vec())
+}
+
+fn settings_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path()
+        .app_config_dir()
+        .map(|dir| dir.join(SETTINGS_FILE))
+        .ok()
+}
+
+/// The settings of the app, read from disk once at startup. Every change
+/// goes through `update`, which holds the lock until the file is written so
+/// two commands can never overwrite each other's changes.
+pub struct SettingsStore {
+    path: Option<PathBuf>,
+    settings: RwLock<Settings>,
+}
+
+impl SettingsStore {
+    /// Reads the settings file from the app config directory.
+    pub fn load(app: &AppHandle) -> Self {
+        Self::open(settings_path(app))
+    }
+
+    fn open(path: Option<PathBuf>) -> Self {
+        let settings = path.as_deref().map(read_settings).unwrap_or_default();
+        Self {
+            path,
+            settings: RwLock::new(settings),
+        }
+    }
+
+    pub fn read(&self) -> RwLockReadGuard<'_, Settings> {
+        self.settings.read().unwrap()
+    }
+
+    pub fn update<T>(&self, change: impl FnOnce(&mut Settings) -> T) -> Result<T, AppError> {
+        self.try_update(|settings| Ok(change(settings)))
+    }
+
+    /// Applies `change` to a copy of the settings, which replaces them once
+    /// it was saved. Nothing changes when `change` or saving fails.
+    pub fn try_update<T>(
+        &self,
+        change: impl FnOnce(&mut Settings) -> Result<T, AppError>,
+    ) -> Result<T, AppError> {
+        let mut settings = self.settings.write().unwrap();
+        let mut changed = settings.clone();
+        let value = change(&mut changed)?;
+        self.save(&changed)?;
+        *settings = changed;
+        Ok(value)
+    }
+
+    /// Moves `game_code` to the front of the recent codes and saves them.
+    pub fn remember_game_code(&self, game_code: usize) {
+        let saved = self.update(|settings| {
+            settings.recent_game_codes.retain(|&code| code != game_code);
+            settings.recent_game_codes.insert(0, game_code);
+            settings.recent_game_codes.truncate(MAX_RECENT_GAME_CODES);
+        });
+        if let Err(e) = saved {
+            error!("Failed to save recent game codes: {}", e);
+        }
+    }
+
+    /// Writes a temporary file and renames it over the settings, so quitting
+    /// while saving can not leave a truncated file behind.
+    fn save(&self, settings: &Settings) -> Result<(), AppError> {
+        let path = self
+            .path
+            .as_ref()
+            .ok_or_else(|| error::from_storage("App config directory is unavailable"))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(error::from_storage)?;
+        }
+        let contents = serde_json::to_string_pretty(settings).map_err(error::from_storage)?;
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, contents)
+            .and_then(|_| fs::rename(&temp_path, path))
+            .map_err(error::from_storage)
+    }
+}
+
+/// Falls back to the defaults when the file is missing or invalid. An
+/// invalid file is moved next to it first, so the next save does not
+/// destroy what the user may want to recover.
+fn read_settings(path: &Path) -> Settings {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Settings::default(),
+        Err(e) => {
+            error!("Failed to read the settings at {:?}: {}", path, e);
+            return Settings::default();
+        }
+    };
+    serde_json::from_str(&contents).unwrap_or_else(|e| {
+        error!(
+            "Invalid settings file at {:?}, using the defaults: {}",
+            path, e
+        );
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+        let backup = path.with_extension(format!("invalid-{}.json", secs));
+        match fs::rename(path, &backup) {
+            Ok(()) => warn!("Moved the invalid settings to {:?}", backup),
+            Err(e) => error!("Failed to back up the invalid settings: {}", e),
+        }
+        Settings::default()
+    })
+}
+
+/// Trims whitespace and trailing slashes so the url can be joined with
+/// `/api/...` paths, rejecting anything that is not http(s).
+pub fn normalize_url(url: &str) -> Option<String> {
+    let url = url.trim().trim_end_matches('/');
+    if url.starts_with("http://") || url.starts_with("https://") {
+        Some(url.to_string())
+    } else {
+        None
+    }
+}
+
+/// Resolves the backend url in order of precedence: `--backend-url <url>`
+/// (or `--backend-url=<url>`), the `TUTORLOL_BACKEND_URL` environment
+/// variable, the persisted setting and finally the public server.
+pub fn resolve_backend_url(settings: &Settings) -> String {
+    let mut args = std::env::args().skip(1);
+    let mut from_flag = None;
+    while let Some(arg) = args.next() {
+        if arg == BACKEND_URL_FLAG {
+            from_flag = args.next();
+        } else if let Some(value) = arg.strip_prefix(&format!("{}=", BACKEND_URL_FLAG)) {
+            from_flag = Some(value.to_string());
+        }
+    }
+
+    from_flag
+        .into_iter()
+        .chain(std::env::var(BACKEND_URL_ENV).ok())
+        .chain(settings.backend_url.clone())
+        .find_map(|url| normalize_url(&url))
+        .unwrap_or_else(|| DEFAULT_BACKEND_URL.to_string())
+}
+
+/// The live client is always `https://127.0.0.1:2999` in a real game. The
+/// `TUTORLOL_LIVE_CLIENT_URL` variable points the app at a stand-in instead,
+/// such as the `live_client` mock.
+pub fn resolve_live_client_url() -> String {
+    std::env::var(LIVE_CLIENT_URL_ENV)
+        .ok()
+        .and_then(|url| normalize_url(&url))
+        .unwrap_or_else(|| DEFAULT_LIVE_CLIENT_URL.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Settings file inside an empty directory of its own.
+    fn settings_in_temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("tutorlolv2-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir.join(SETTINGS_FILE)
+    }
+
+    #[test]
+    fn invalid_settings_are_moved_aside() {
+        let path = settings_in_temp_dir("invalid-settings");
+        let dir = path.parent().unwrap().to_path_buf();
+        fs::write(&path, "{ \"poll_interval_ms\": ").unwrap();
+
+        let store = SettingsStore::open(Some(path.clone()));
+        assert_eq!(store.read().poll_interval_ms, DEFAULT_POLL_INTERVAL_MS);
+        assert!(!path.exists());
+        let backups = fs::read_dir(&dir)
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                name.starts_with("settings.invalid-")
+                    && fs::read_to_string(entry.path()).unwrap() == "{ \"poll_interval_ms\": "
+            })
+            .count();
+        assert_eq!(backups, 1);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn only_saved_updates_are_kept() {
+        let path = settings_in_temp_dir("update-settings");
+        let store = SettingsStore::open(Some(path.clone()));
+        store
+            .update(|settings| settings.poll_interval_ms = 2000)
+            .unwrap();
+        let rejected = store.try_update(|settings| {
+            settings.poll_interval_ms = 3000;
+            Err::<(), _>(AppError::InvalidInput("Rejected".to_string()))
+        });
+        assert!(rejected.is_err());
+
+        assert_eq!(store.read().poll_interval_ms, 2000);
+        assert!(!path.with_extension("json.tmp").exists());
+        let reopened = SettingsStore::open(Some(path.clone()));
+        assert_eq!(reopened.read().poll_interval_ms, 2000);
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn the_captured_overlay_geometry_survives_a_restart() {
+        let path = settings_in_temp_dir("overlay-settings");
+        // Written before the overlay existed
+        fs::write(&path, "{ \"poll_interval_ms\": 2000 }").unwrap();
+        let store = SettingsStore::open(Some(path.clone()));
+        assert_eq!(store.read().overlay, OverlaySettings::default());
+        store
+            .update(|settings| settings.overlay.show_base_table = true)
+            .unwrap();
+
+        // What a window moved on a 150% display reports
+        let geometry = Geometry::from_physical(
+            PhysicalPosition::new(2250, 60),
+            PhysicalSize::new(630, 450),
+            1.5,
+        );
+        assert_eq!(
+            geometry,
+            Geometry {
+                x: 1500.0,
+                y: 40.0,
+                width: 420.0,
+                height: 300.0,
+            }
+        );
+        store
+            .update(|settings| settings.overlay.set_geometry(geometry))
+            .unwrap();
+
+        let reopened = SettingsStore::open(Some(path.clone()));
+        assert_eq!(
+            reopened.read().overlay,
+            OverlaySettings {
+                x: Some(1500.0),
+                y: Some(40.0),
+                width: 420.0,
+                height: 300.0,
+                show_base_table: true,
+            }
+        );
+        assert_eq!(reopened.read().poll_interval_ms, 2000);
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
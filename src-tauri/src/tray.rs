@@ -0,0 +1,367 @@
+use log::{error, info, warn};
+use serde::Serialize;
+use tauri::{
+    image::Image,
+    menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem, Submenu},
+    AppHandle, Emitter, Manager,
+};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+use tauri_plugin_notification::NotificationExt;
+use tutorlolv2_shared::connection::ConnectionState;
+
+use crate::{main_window, overlay, toggle_main_window, AppState};
+
+pub const TRAY_ID: &str = "main";
+/// Asks the frontend to open a page, such as `settings`
+pub const NAVIGATE_EVENT: &str = "navigate";
+/// Carries the keybindings shown by the Help item
+pub const HELP_EVENT: &str = "show-help";
+
+const RECENT_CODE_PREFIX: &str = "recent:";
+
+#[derive(Clone, Serialize)]
+pub struct KeybindingHelp {
+    pub binding: String,
+    pub description: &'static str,
+}
+
+/// Snapshot of everything the tray displays.
+struct TrayStatus {
+    game_code: Option<usize>,
+    connection: ConnectionState,
+    paused: bool,
+    overlay_visible: bool,
+    recent_game_codes: Vec<usize>,
+}
+
+impl TrayStatus {
+    fn read(app: &AppHandle) -> Option<Self> {
+        let state = app.try_state::<AppState>()?;
+        let recent_game_codes = state.settings.read().recent_game_codes.clone();
+        Some(Self {
+            game_code: state.session.game_code(),
+            connection: state.poller.connection_state(),
+            paused: state.poller.is_paused(),
+            overlay_visible: state.overlay.state(app).visible,
+            recent_game_codes,
+        })
+    }
+
+    fn status_label(&self) -> &'static str {
+        if self.paused && !self.connection.is_stopped() {
+            "Paused"
+        } else {
+            self.connection.label()
+        }
+    }
+
+    fn tooltip(&self) -> String {
+        match self.game_code {
+            Some(game_code) => format!("TutorLoL - {} - {}", self.status_label(), game_code),
+            None => format!("TutorLoL - {}", self.status_label()),
+        }
+    }
+}
+
+fn build_menu(app: &AppHandle, status: &TrayStatus) -> tauri::Result<Menu<tauri::Wry>> {
+    let code_label = match status.game_code {
+        Some(game_code) => format!("Game code: {}", game_code),
+        None => "Game code: unavailable".to_string(),
+    };
+    let code_i = MenuItem::with_id(app, "game_code", code_label, false, None::<&str>)?;
+    let status_i = MenuItem::with_id(
+        app,
+        "status",
+        format!("Status: {}", status.status_label()),
+        false,
+        None::<&str>,
+    )?;
+    let copy_i = MenuItem::with_id(
+        app,
+        "copy_code",
+        "Copy game code",
+        status.game_code.is_some(),
+        None::<&str>,
+    )?;
+    let pause_i = MenuItem::with_id(
+        app,
+        "toggle_pause",
+        if status.paused {
+            "Resume polling"
+        } else {
+            "Pause polling"
+        },
+        !status.connection.is_stopped(),
+        None::<&str>,
+    )?;
+    let overlay_i = MenuItem::with_id(
+        app,
+        "toggle_overlay",
+        if status.overlay_visible {
+            "Close overlay"
+        } else {
+            "Open overlay"
+        },
+        true,
+        None::<&str>,
+    )?;
+
+    let recent_i = Submenu::with_id(app, "recent_codes", "Recent game codes", true)?;
+    if status.recent_game_codes.is_empty() {
+        recent_i.append(&MenuItem::with_id(
+            app,
+            "no_recent_codes",
+            "No recent codes",
+            false,
+            None::<&str>,
+        )?)?;
+    }
+    for game_code in &status.recent_game_codes {
+        recent_i.append(&MenuItem::with_id(
+            app,
+            format!("{}{}", RECENT_CODE_PREFIX, game_code),
+            format!("Copy {}", game_code),
+            true,
+            None::<&str>,
+        )?)?;
+    }
+
+    let settings_i = MenuItem::with_id(app, "settings", "Settings", true, None::<&str>)?;
+    let help_i = MenuItem::with_id(app, "help", "Help", true, None::<&str>)?;
+    let show_i = MenuItem::with_id(
+        app,
+        "toggle_visibility",
+        "Toggle Visibility",
+        true,
+        None::<&str>,
+    )?;
+    let quit_i = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+
+    Menu::with_items(
+        app,
+        &[
+            &code_i,
+            &status_i,
+            &PredefinedMenuItem::separator(app)?,
+            &copy_i,
+            &pause_i,
+            &overlay_i,
+            &recent_i,
+            &PredefinedMenuItem::separator(app)?,
+            &settings_i,
+            &help_i,
+            &show_i,
+            &PredefinedMenuItem::separator(app)?,
+            &quit_i,
+        ],
+    )
+}
+
+/// Dimmed grey copy of the app icon, shown while no game is live.
+fn idle_icon(icon: &Image<'_>) -> Image<'static> {
+    let rgba = icon
+        .rgba()
+        .chunks_exact(4)
+        .flat_map(|pixel| {
+            let grey = (pixel[0] as u32 * 30 + pixel[1] as u32 * 59 + pixel[2] as u32 * 11) / 100;
+            [
+                grey as u8,
+                grey as u8,
+                grey as u8,
+                pixel[3] / 2 + pixel[3] / 4,
+            ]
+        })
+        .collect::<Vec<_>>();
+    Image::new_owned(rgba, icon.width(), icon.height())
+}
+
+fn icon_for(app: &AppHandle, status: &TrayStatus) -> Option<Image<'static>> {
+    let icon = app.default_window_icon()?;
+    if status.connection == ConnectionState::Live && !status.paused {
+        Some(icon.clone().to_owned())
+    } else {
+        Some(idle_icon(icon))
+    }
+}
+
+/// Shows the current game code and connection state in the tray menu,
+/// tooltip and icon. Called whenever any of them changes.
+pub fn refresh(app: &AppHandle) {
+    let (Some(tray), Some(status)) = (app.tray_by_id(TRAY_ID), TrayStatus::read(app)) else {
+        return;
+    };
+    match build_menu(app, &status) {
+        Ok(menu) => {
+            let _ = tray.set_menu(Some(menu));
+        }
+        Err(e) => error!("Failed to build the tray menu: {}", e),
+    }
+    let _ = tray.set_tooltip(Some(status.tooltip()));
+    let _ = tray.set_icon(icon_for(app, &status));
+}
+
+/// Game code of an item of the recent codes submenu.
+fn recent_game_code(id: &str) -> Option<usize> {
+    id.strip_prefix(RECENT_CODE_PREFIX)?.parse().ok()
+}
+
+fn copy_game_code(app: &AppHandle, game_code: usize) {
+    if let Err(e) = app.clipboard().write_text(game_code.to_string()) {
+        error!("Failed to copy game code {}: {}", game_code, e);
+    }
+}
+
+/// Lists the configured hotkeys as a notification, and inside the main window
+/// since notifications are muted while a fullscreen game has focus.
+fn show_help(app: &AppHandle) {
+    let keybindings = app
+        .state::<AppState>()
+        .hotkeys
+        .read()
+        .unwrap()
+        .iter()
+        .map(|(hotkey, action)| KeybindingHelp {
+            binding: hotkey.to_string(),
+            description: action.description(),
+        })
+        .collect::<Vec<_>>();
+
+    let body = if keybindings.is_empty() {
+        "No hotkeys are configured.".to_string()
+    } else {
+        keybindings
+            .iter()
+            .map(|help| format!("{}: {}", help.binding, help.description))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    if let Err(e) = app
+        .notification()
+        .builder()
+        .title("Keybindings")
+        .body(body)
+        .show()
+    {
+        error!("Failed to show the keybindings notification: {}", e);
+    }
+
+    main_window::show(app);
+    let _ = app.emit(HELP_EVENT, keybindings);
+}
+
+fn on_menu_event(app: &AppHandle, event: MenuEvent) {
+    let state = app.state::<AppState>();
+    match event.id().0.as_str() {
+        "quit" => {
+            info!("Item 'Quit' selected");
+            if let Some(window) = main_window::window(app) {
+                main_window::save_geometry(app, &window);
+            }
+            if let Some(window) = overlay::window(app) {
+                overlay::save_geometry(app, &window);
+            }
+            app.exit(0);
+        }
+        "help" => {
+            info!("Item 'Help' selected");
+            show_help(app);
+        }
+        "toggle_visibility" => {
+            info!("Item 'Show' selected");
+            toggle_main_window(app);
+        }
+        "copy_code" => {
+            if let Some(game_code) = state.session.game_code() {
+                copy_game_code(app, game_code);
+            }
+        }
+        "toggle_pause" => state.poller.set_paused(app, !state.poller.is_paused()),
+        "toggle_overlay" => {
+            if let Err(e) = state.overlay.toggle(app) {
+                error!("Failed to toggle the overlay: {}", e);
+            }
+        }
+        "settings" => {
+            main_window::show(app);
+            let _ = app.emit(NAVIGATE_EVENT, "settings");
+        }
+        id => match recent_game_code(id) {
+            Some(game_code) => copy_game_code(app, game_code),
+            None => warn!("Unrecognized menu item: {}", id),
+        },
+    }
+}
+
+pub fn create(app: &AppHandle) -> tauri::Result<()> {
+    let status = TrayStatus {
+        game_code: None,
+        connection: ConnectionState::Idle,
+        paused: false,
+        overlay_visible: false,
+        recent_game_codes: app
+            .state::<AppState>()
+            .settings
+            .read()
+            .recent_game_codes
+            .clone(),
+    };
+    let mut builder = tauri::tray::TrayIconBuilder::with_id(TRAY_ID)
+        .menu(&build_menu(app, &status)?)
+        .tooltip(status.tooltip())
+        .show_menu_on_left_click(true)
+        .on_menu_event(on_menu_event);
+    if let Some(icon) = icon_for(app, &status) {
+        builder = builder.icon(icon);
+    }
+    builder.build(app)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use tutorlolv2_shared::error::AppError;
+
+    use super::*;
+
+    fn status(connection: ConnectionState, paused: bool) -> TrayStatus {
+        TrayStatus {
+            game_code: Some(123456),
+            connection,
+            paused,
+            overlay_visible: false,
+            recent_game_codes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn the_tooltip_shows_the_status_and_code() {
+        assert_eq!(
+            status(ConnectionState::Live, false).tooltip(),
+            "TutorLoL - Live - 123456"
+        );
+        let no_code = TrayStatus {
+            game_code: None,
+            ..status(ConnectionState::Idle, false)
+        };
+        assert_eq!(no_code.tooltip(), "TutorLoL - Idle");
+    }
+
+    #[test]
+    fn pausing_only_shows_while_polling() {
+        assert_eq!(status(ConnectionState::Live, true).status_label(), "Paused");
+        let reconnecting = ConnectionState::Reconnecting {
+            attempt: 1,
+            retry_in_ms: 1000,
+            error: AppError::BackendUnreachable,
+        };
+        assert_eq!(status(reconnecting, true).status_label(), "Paused");
+        assert_eq!(status(ConnectionState::Idle, true).status_label(), "Idle");
+    }
+
+    #[test]
+    fn recent_code_items_carry_their_code() {
+        assert_eq!(recent_game_code("recent:123456"), Some(123456));
+        assert_eq!(recent_game_code("recent:"), None);
+        assert_eq!(recent_game_code("copy_code"), None);
+    }
+}
@@ -1,57 +1,172 @@
-#![allow(static_mut_refs)]
-#![cfg(target_os = "windows")]
+use std::{
+    sync::{
+        atomic::{AtomicIsize, Ordering},
+        RwLock,
+    },
+    thread,
+};
+
+use tauri::AppHandle;
+
 use windows::Win32::{
     Foundation::{LPARAM, LRESULT, WPARAM},
     System::{LibraryLoader::GetModuleHandleW, Threading::GetCurrentThreadId},
     UI::{
-        Input::KeyboardAndMouse::{GetAsyncKeyState, VK_CONTROL, VK_OEM_7},
+        Input::KeyboardAndMouse::{
+            GetAsyncKeyState, VIRTUAL_KEY, VK_CONTROL, VK_LWIN, VK_MENU, VK_RWIN, VK_SHIFT,
+        },
         WindowsAndMessaging::{
-            CallNextHookEx, PostThreadMessageW, SetWindowsHookExW, UnhookWindowsHookEx, HC_ACTION,
-            HHOOK, KBDLLHOOKSTRUCT, WH_KEYBOARD_LL, WM_KEYDOWN, WM_USER,
+            CallNextHookEx, GetMessageW, PostThreadMessageW, SetWindowsHookExW,
+            UnhookWindowsHookEx, HC_ACTION, HHOOK, KBDLLHOOKSTRUCT, MSG, WH_KEYBOARD_LL,
+            WM_KEYDOWN, WM_QUIT, WM_SYSKEYDOWN, WM_USER,
         },
     },
 };
 
-// ? Replace with lazy_static!
-static mut HOOK_HANDLE: HHOOK = HHOOK(0 as _);
+use crate::{
+    hotkeys::{Bindings, Hotkey, HotkeyAction, Key, Modifiers},
+    trigger_hotkey,
+};
+
+/// Posted to the hook thread with the index of the action in `wparam`
+const WM_HOTKEY_ACTION: u32 = WM_USER + 1;
+
+static HOOK_HANDLE: AtomicIsize = AtomicIsize::new(0);
+static BINDINGS: RwLock<Bindings> = RwLock::new(Bindings::empty());
+
+/// Replaces the hotkeys the hook reacts to.
+pub fn set_bindings(_app: &AppHandle, bindings: &Bindings) {
+    *BINDINGS.write().unwrap() = bindings.clone();
+}
+
+/// Action posted by the hook, read back from the message `wparam`.
+fn action_from_message(wparam: WPARAM) -> Option<HotkeyAction> {
+    HotkeyAction::ALL.get(wparam.0).copied()
+}
+
+fn key_from_vk(vk_code: u32) -> Option<Key> {
+    let key = match vk_code {
+        0x41..=0x5A => Key::Letter(char::from_u32(vk_code)?),
+        0x30..=0x39 => Key::Digit(char::from_u32(vk_code)?),
+        0x70..=0x87 => Key::F((vk_code - 0x6F) as u8),
+        0xDE => Key::Quote,
+        0xC0 => Key::Backquote,
+        0xBD => Key::Minus,
+        0xBB => Key::Equal,
+        0xDB => Key::BracketLeft,
+        0xDD => Key::BracketRight,
+        0xDC => Key::Backslash,
+        0xBA => Key::Semicolon,
+        0xBC => Key::Comma,
+        0xBE => Key::Period,
+        0xBF => Key::Slash,
+        0x20 => Key::Space,
+        0x09 => Key::Tab,
+        0x0D => Key::Enter,
+        0x1B => Key::Escape,
+        0x08 => Key::Backspace,
+        0x2E => Key::Delete,
+        0x2D => Key::Insert,
+        0x24 => Key::Home,
+        0x23 => Key::End,
+        0x21 => Key::PageUp,
+        0x22 => Key::PageDown,
+        0x26 => Key::Up,
+        0x28 => Key::Down,
+        0x25 => Key::Left,
+        0x27 => Key::Right,
+        _ => return None,
+    };
+    Some(key)
+}
+
+unsafe fn is_pressed(vk: VIRTUAL_KEY) -> bool {
+    GetAsyncKeyState(vk.0 as i32) & 0x8000u16 as i16 != 0
+}
+
+unsafe fn pressed_modifiers() -> Modifiers {
+    Modifiers {
+        ctrl: is_pressed(VK_CONTROL),
+        shift: is_pressed(VK_SHIFT),
+        alt: is_pressed(VK_MENU),
+        meta: is_pressed(VK_LWIN) || is_pressed(VK_RWIN),
+    }
+}
 
 unsafe extern "system" fn low_level_keyboard_proc(
     code: i32,
     wparam: WPARAM,
     lparam: LPARAM,
 ) -> LRESULT {
-    if code == HC_ACTION as i32 {
+    // Alt combinations arrive as WM_SYSKEYDOWN
+    let key_down = matches!(wparam.0 as u32, WM_KEYDOWN | WM_SYSKEYDOWN);
+    if code == HC_ACTION as i32 && key_down {
         let kb_struct = *(lparam.0 as *const KBDLLHOOKSTRUCT);
-        if wparam.0 as u32 == WM_KEYDOWN && kb_struct.vkCode == VK_OEM_7.0 as u32 {
-            if GetAsyncKeyState(VK_CONTROL.0 as i32) & 0x8000u16 as i16 != 0 {
-                let _ = PostThreadMessageW(GetCurrentThreadId(), WM_USER + 1, WPARAM(0), LPARAM(0));
+        if let Some(key) = key_from_vk(kb_struct.vkCode) {
+            let hotkey = Hotkey {
+                modifiers: pressed_modifiers(),
+                key,
+            };
+            let action = BINDINGS.read().unwrap().action_for(&hotkey);
+            if let Some(index) = action.and_then(|a| HotkeyAction::ALL.iter().position(|&b| b == a))
+            {
+                let _ = PostThreadMessageW(
+                    GetCurrentThreadId(),
+                    WM_HOTKEY_ACTION,
+                    WPARAM(index),
+                    LPARAM(0),
+                );
             }
         }
     }
-    CallNextHookEx(Some(HOOK_HANDLE), code, wparam, lparam)
+    let hook = HHOOK(HOOK_HANDLE.load(Ordering::SeqCst) as _);
+    CallNextHookEx(Some(hook), code, wparam, lparam)
 }
 
-pub fn install_hook() {
-    unsafe {
-        if !HOOK_HANDLE.is_invalid() {
-            return;
+/// Runs the hook on its own thread, since low level hooks are called through
+/// the message loop of the thread that installed them.
+pub fn start_listener(app: &AppHandle) -> tauri::Result<()> {
+    let app = app.clone();
+    thread::spawn(move || unsafe {
+        install_hook();
+        let mut msg = MSG::default();
+        while GetMessageW(&mut msg, None, 0, 0).into() {
+            if msg.message == WM_QUIT {
+                break;
+            }
+            if msg.message == WM_HOTKEY_ACTION {
+                if let Some(action) = action_from_message(msg.wParam) {
+                    trigger_hotkey(&app, action);
+                }
+            }
         }
+        uninstall_hook();
+    });
+    Ok(())
+}
+
+fn install_hook() {
+    if HOOK_HANDLE.load(Ordering::SeqCst) != 0 {
+        return;
+    }
+    unsafe {
         let hinstance = GetModuleHandleW(None).unwrap();
-        HOOK_HANDLE = SetWindowsHookExW(
+        let hook = SetWindowsHookExW(
             WH_KEYBOARD_LL,
             Some(low_level_keyboard_proc),
             Some(hinstance.into()),
             0,
         )
         .unwrap();
+        HOOK_HANDLE.store(hook.0 as isize, Ordering::SeqCst);
     }
 }
 
-pub fn uninstall_hook() {
-    unsafe {
-        if HOOK_HANDLE.is_invalid() {
-            let _ = UnhookWindowsHookEx(HOOK_HANDLE);
-            HOOK_HANDLE = HHOOK(0 as _);
+fn uninstall_hook() {
+    let hook = HOOK_HANDLE.swap(0, Ordering::SeqCst);
+    if hook != 0 {
+        unsafe {
+            let _ = UnhookWindowsHookEx(HHOOK(hook as _));
         }
     }
 }
@@ -1 +1,13 @@
+//! Global hotkey listeners. Every platform exposes `start_listener` and
+//! `set_bindings` with the same signatures, and reports pressed hotkeys
+//! through `trigger_hotkey`.
+
+#[cfg(target_os = "windows")]
 pub mod keyboard;
+#[cfg(not(target_os = "windows"))]
+pub mod shortcuts;
+
+#[cfg(target_os = "windows")]
+pub use keyboard::{set_bindings, start_listener};
+#[cfg(not(target_os = "windows"))]
+pub use shortcuts::{set_bindings, start_listener};
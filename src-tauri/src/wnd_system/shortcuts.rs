@@ -0,0 +1,194 @@
+use std::str::FromStr;
+
+use log::error;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_global_shortcut::{
+    Code, GlobalShortcutExt, Modifiers, Shortcut, ShortcutEvent, ShortcutState,
+};
+
+use crate::{
+    hotkeys::{self, Bindings, Hotkey, Key},
+    trigger_hotkey, AppState,
+};
+
+/// W3C name of the physical key, which is what the plugin expects.
+fn code_name(key: &Key) -> String {
+    match key {
+        Key::Letter(c) => format!("Key{}", c),
+        Key::Digit(c) => format!("Digit{}", c),
+        Key::F(n) => format!("F{}", n),
+        Key::Up => "ArrowUp".to_string(),
+        Key::Down => "ArrowDown".to_string(),
+        Key::Left => "ArrowLeft".to_string(),
+        Key::Right => "ArrowRight".to_string(),
+        // The remaining variants are already named after their code
+        key => format!("{:?}", key),
+    }
+}
+
+fn to_shortcut(hotkey: &Hotkey) -> Option<Shortcut> {
+    let mut modifiers = Modifiers::empty();
+    modifiers.set(Modifiers::CONTROL, hotkey.modifiers.ctrl);
+    modifiers.set(Modifiers::SHIFT, hotkey.modifiers.shift);
+    modifiers.set(Modifiers::ALT, hotkey.modifiers.alt);
+    modifiers.set(Modifiers::SUPER, hotkey.modifiers.meta);
+    let code = Code::from_str(&code_name(&hotkey.key)).ok()?;
+    Some(Shortcut::new(Some(modifiers), code))
+}
+
+/// Inverse of `code_name`.
+fn key_from_code(code: Code) -> Option<Key> {
+    let key = match code {
+        Code::Quote => Key::Quote,
+        Code::Backquote => Key::Backquote,
+        Code::Minus => Key::Minus,
+        Code::Equal => Key::Equal,
+        Code::BracketLeft => Key::BracketLeft,
+        Code::BracketRight => Key::BracketRight,
+        Code::Backslash => Key::Backslash,
+        Code::Semicolon => Key::Semicolon,
+        Code::Comma => Key::Comma,
+        Code::Period => Key::Period,
+        Code::Slash => Key::Slash,
+        Code::Space => Key::Space,
+        Code::Tab => Key::Tab,
+        Code::Enter => Key::Enter,
+        Code::Escape => Key::Escape,
+        Code::Backspace => Key::Backspace,
+        Code::Delete => Key::Delete,
+        Code::Insert => Key::Insert,
+        Code::Home => Key::Home,
+        Code::End => Key::End,
+        Code::PageUp => Key::PageUp,
+        Code::PageDown => Key::PageDown,
+        Code::ArrowUp => Key::Up,
+        Code::ArrowDown => Key::Down,
+        Code::ArrowLeft => Key::Left,
+        Code::ArrowRight => Key::Right,
+        // "KeyA", "Digit1" and "F1" end with the name of the key
+        code => {
+            let name = code.to_string();
+            let name = name
+                .strip_prefix("Key")
+                .or_else(|| name.strip_prefix("Digit"))
+                .unwrap_or(&name);
+            match name.parse().ok()? {
+                key @ (Key::Letter(_) | Key::Digit(_) | Key::F(_)) => key,
+                _ => return None,
+            }
+        }
+    };
+    Some(key)
+}
+
+fn from_shortcut(shortcut: &Shortcut) -> Option<Hotkey> {
+    Some(Hotkey {
+        modifiers: hotkeys::Modifiers {
+            ctrl: shortcut.mods.contains(Modifiers::CONTROL),
+            shift: shortcut.mods.contains(Modifiers::SHIFT),
+            alt: shortcut.mods.contains(Modifiers::ALT),
+            meta: shortcut.mods.contains(Modifiers::SUPER),
+        },
+        key: key_from_code(shortcut.key)?,
+    })
+}
+
+fn on_shortcut(app: &AppHandle, shortcut: &Shortcut, event: ShortcutEvent) {
+    if event.state() != ShortcutState::Pressed {
+        return;
+    }
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let Some(hotkey) = from_shortcut(shortcut) else {
+        return;
+    };
+    let action = state.hotkeys.read().unwrap().action_for(&hotkey);
+    if let Some(action) = action {
+        trigger_hotkey(app, action);
+    }
+}
+
+/// Registers the global shortcut plugin. On Linux it grabs keys through X11,
+/// so hotkeys also reach games running under Wine or Proton through XWayland.
+pub fn start_listener(app: &AppHandle) -> tauri::Result<()> {
+    app.plugin(
+        tauri_plugin_global_shortcut::Builder::new()
+            .with_handler(on_shortcut)
+            .build(),
+    )
+}
+
+/// Replaces the registered shortcuts. A shortcut taken by another program is
+/// skipped without affecting the others.
+pub fn set_bindings(app: &AppHandle, bindings: &Bindings) {
+    let global_shortcut = app.global_shortcut();
+    if let Err(e) = global_shortcut.unregister_all() {
+        error!("Failed to unregister hotkeys: {}", e);
+    }
+    for (hotkey, action) in bindings.iter() {
+        let registered = to_shortcut(hotkey)
+            .ok_or_else(|| "key is not supported".to_string())
+            .and_then(|shortcut| {
+                global_shortcut
+                    .register(shortcut)
+                    .map_err(|e| e.to_string())
+            });
+        if let Err(e) = registered {
+            error!(
+                "Failed to register {} to {}: {}",
+                hotkey,
+                action.description(),
+                e
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_key_maps_back_from_its_shortcut() {
+        let bindings = [
+            "Ctrl+A",
+            "Ctrl+Shift+Z",
+            "Alt+0",
+            "Ctrl+9",
+            "Ctrl+F1",
+            "Meta+F24",
+            "Ctrl+'",
+            "Ctrl+`",
+            "Ctrl+-",
+            "Ctrl+=",
+            "Ctrl+[",
+            "Ctrl+]",
+            "Ctrl+\\",
+            "Ctrl+;",
+            "Ctrl+,",
+            "Ctrl+.",
+            "Ctrl+/",
+            "Ctrl+Space",
+            "Ctrl+Tab",
+            "Ctrl+Enter",
+            "Ctrl+Escape",
+            "Ctrl+Backspace",
+            "Ctrl+Delete",
+            "Ctrl+Insert",
+            "Ctrl+Home",
+            "Ctrl+End",
+            "Ctrl+PageUp",
+            "Ctrl+PageDown",
+            "Ctrl+Up",
+            "Ctrl+Down",
+            "Ctrl+Left",
+            "Ctrl+Right",
+        ];
+        for binding in bindings {
+            let hotkey = binding.parse::<Hotkey>().unwrap();
+            let shortcut = to_shortcut(&hotkey).unwrap();
+            assert_eq!(from_shortcut(&shortcut), Some(hotkey), "{}", binding);
+        }
+    }
+}